@@ -0,0 +1,34 @@
+use ckb_std::{env, high_level as hl};
+
+use crate::error::{Error, Result};
+
+pub fn main() -> Result<()> {
+    debug!("{} Starting ...", module_path!());
+
+    let argv = env::argv();
+    if argv.len() % 3 != 0 {
+        return Err(Error::InvalidArgumentsCount);
+    }
+
+    for change in argv.chunks(3) {
+        let kind = change[0].to_bytes();
+        let _key = hl::decode_hex(&change[1])?;
+        let payload = hl::decode_hex(&change[2])?;
+        debug!("validate change: {:?} {_key:?}", change[0]);
+        match kind {
+            b"insert" | b"modify" => {
+                if payload.is_empty() {
+                    return Err(Error::EmptyPayload);
+                }
+            }
+            b"remove" => {}
+            _ => {
+                return Err(Error::UnknownChangeKind);
+            }
+        }
+    }
+
+    debug!("{} DONE.", module_path!());
+
+    Ok(())
+}
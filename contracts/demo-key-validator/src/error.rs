@@ -0,0 +1,40 @@
+use core::result;
+
+use ckb_std::error::SysError;
+
+pub type Result<T> = result::Result<T, Error>;
+
+#[repr(i8)]
+pub enum Error {
+    // 0x01 ~ 0x0f: Errors from SDK, or other system errors.
+    IndexOutOfBound = 0x01,
+    ItemMissing,
+    LengthNotEnough,
+    Encoding,
+    Unknown,
+
+    // 0x10 ~ 0x1f: Errors when parse arguments.
+    InvalidArgumentsCount = 0x10,
+    UnknownChangeKind,
+
+    // 0x20 ~ 0x2f: Errors when validate changes.
+    EmptyPayload = 0x20,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        match err {
+            SysError::IndexOutOfBound => Self::IndexOutOfBound,
+            SysError::ItemMissing => Self::ItemMissing,
+            SysError::LengthNotEnough(_) => Self::LengthNotEnough,
+            SysError::Encoding => Self::Encoding,
+            SysError::Unknown(_) => Self::Unknown,
+        }
+    }
+}
+
+impl From<Error> for i8 {
+    fn from(err: Error) -> Self {
+        err as i8
+    }
+}
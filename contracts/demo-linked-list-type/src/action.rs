@@ -0,0 +1,200 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use ckb_registry_contract::{RegistryCell, RegistryItem};
+use ckb_std::{ckb_constants::Source, ckb_types::prelude::*, error::SysError, high_level as hl};
+use demo_linked_list_lib::{item::LATEST_VERSION, types};
+
+use crate::{
+    error::{InternalError, Result},
+    item::DemoItem,
+};
+
+// The parts of an item which should be kept as is, if the action doesn't
+// declare that they are changed.
+#[derive(PartialEq, Eq)]
+struct ItemState {
+    demo: Vec<u8>,
+    lock_hash: [u8; 32],
+    version: u8,
+    owner: Option<[u8; 32]>,
+}
+
+type ItemsMap = BTreeMap<Vec<u8>, ItemState>;
+type KeysSet = BTreeSet<Vec<u8>>;
+
+// Load the declared action from the `input_type` of the first witness of the
+// script group.
+//
+// The action is optional, so return `None` if the witness doesn't exist, or
+// it's not a `WitnessArgs`, or its `input_type` is empty.
+pub(crate) fn load_action() -> Result<Option<types::RegistryAction>> {
+    let witness_args = match hl::load_witness_args(0, Source::GroupInput) {
+        Ok(witness_args) => witness_args,
+        Err(SysError::IndexOutOfBound) | Err(SysError::Encoding) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    if let Some(input_type) = witness_args.input_type().to_opt() {
+        let action = types::RegistryAction::from_slice(input_type.as_reader().raw_data())
+            .map_err(|_| InternalError::ActionInvalidWitness)?;
+        Ok(Some(action))
+    } else {
+        Ok(None)
+    }
+}
+
+// Verify the exact semantics of the declared action.
+//
+// The range of the inputs and the outputs should be checked before calling
+// this function.
+pub(crate) fn verify(
+    action: types::RegistryActionReader<'_>,
+    inputs_cells: &[RegistryCell<DemoItem>],
+    outputs_cells: &[RegistryCell<DemoItem>],
+) -> Result<()> {
+    let inputs = collect_items(inputs_cells)?;
+    let outputs = collect_items(outputs_cells)?;
+    match action.to_enum() {
+        types::RegistryActionUnionReader::Insert(insert) => {
+            let keys = collect_keys(insert.keys())?;
+            debug!("verify action: insert {} items", keys.len());
+            let is_valid = outputs.len() == inputs.len() + keys.len()
+                && keys
+                    .iter()
+                    .all(|key| !inputs.contains_key(key) && outputs.contains_key(key))
+                && inputs
+                    .iter()
+                    .all(|(key, item)| outputs.get(key) == Some(item));
+            if !is_valid {
+                return Err(InternalError::ActionInvalidInsert.into());
+            }
+        }
+        types::RegistryActionUnionReader::Remove(remove) => {
+            let keys = collect_keys(remove.keys())?;
+            debug!("verify action: remove {} items", keys.len());
+            let is_valid = inputs.len() == outputs.len() + keys.len()
+                && keys
+                    .iter()
+                    .all(|key| inputs.contains_key(key) && !outputs.contains_key(key))
+                && outputs
+                    .iter()
+                    .all(|(key, item)| inputs.get(key) == Some(item));
+            if !is_valid {
+                return Err(InternalError::ActionInvalidRemove.into());
+            }
+        }
+        types::RegistryActionUnionReader::ModifyPayload(modify) => {
+            let keys = collect_keys(modify.keys())?;
+            debug!("verify action: modify payloads of {} items", keys.len());
+            let is_valid = check_same_keys(&inputs, &outputs, &keys)
+                && inputs.iter().all(|(key, item)| {
+                    outputs.get(key).is_some_and(|output| {
+                        output.lock_hash == item.lock_hash
+                            && output.version == item.version
+                            && output.owner == item.owner
+                            && (keys.contains(key) || output.demo == item.demo)
+                    })
+                });
+            if !is_valid {
+                return Err(InternalError::ActionInvalidModifyPayload.into());
+            }
+        }
+        types::RegistryActionUnionReader::Transfer(transfer) => {
+            let keys = collect_keys(transfer.keys())?;
+            debug!("verify action: transfer {} items", keys.len());
+            let is_valid = check_same_keys(&inputs, &outputs, &keys)
+                && inputs.iter().all(|(key, item)| {
+                    outputs.get(key).is_some_and(|output| {
+                        output.demo == item.demo
+                            && output.version == item.version
+                            && output.owner == item.owner
+                            && (keys.contains(key) || output.lock_hash == item.lock_hash)
+                    })
+                });
+            if !is_valid {
+                return Err(InternalError::ActionInvalidTransfer.into());
+            }
+        }
+        types::RegistryActionUnionReader::Migrate(migrate) => {
+            let keys = collect_keys(migrate.keys())?;
+            debug!("verify action: migrate {} items", keys.len());
+            let is_valid = check_same_keys(&inputs, &outputs, &keys)
+                && inputs.iter().all(|(key, item)| {
+                    outputs.get(key).is_some_and(|output| {
+                        if keys.contains(key) {
+                            item.version < LATEST_VERSION
+                                && output.version == LATEST_VERSION
+                                && output.demo == item.demo
+                                && output.lock_hash == item.lock_hash
+                                && output.owner == item.owner
+                        } else {
+                            output == item
+                        }
+                    })
+                });
+            if !is_valid {
+                return Err(InternalError::ActionInvalidMigrate.into());
+            }
+        }
+        types::RegistryActionUnionReader::TransferOwner(transfer_owner) => {
+            let key = transfer_owner.key().raw_data();
+            debug!("verify action: transfer the owner of 1 item");
+            // Only 1 item is changed, so its neighbours are not touched.
+            let is_valid = inputs.len() == 1
+                && outputs.len() == 1
+                && inputs.get(key).is_some_and(|item| {
+                    outputs.get(key).is_some_and(|output| {
+                        output.version == LATEST_VERSION
+                            && output.demo == item.demo
+                            && output.lock_hash == item.lock_hash
+                            && output.owner.is_some()
+                            && output.owner != item.owner
+                    })
+                });
+            if !is_valid {
+                return Err(InternalError::ActionInvalidTransferOwner.into());
+            }
+        }
+        types::RegistryActionUnionReader::Rebalance(_) => {
+            debug!("verify action: rebalance");
+            if inputs != outputs {
+                return Err(InternalError::ActionInvalidRebalance.into());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn collect_items(cells: &[RegistryCell<DemoItem>]) -> Result<ItemsMap> {
+    let mut items = BTreeMap::new();
+    for cell in cells {
+        let item = ItemState {
+            demo: cell.item.payload().to_vec(),
+            lock_hash: cell.lock_hash()?,
+            version: cell.item.version(),
+            owner: cell.item.owner(),
+        };
+        items.insert(cell.item.key().to_vec(), item);
+    }
+    Ok(items)
+}
+
+fn collect_keys(keys: types::BytesVecReader<'_>) -> Result<KeysSet> {
+    let mut set = BTreeSet::new();
+    for key in keys.iter() {
+        if !set.insert(key.raw_data().to_vec()) {
+            return Err(InternalError::ActionDuplicateKeys.into());
+        }
+    }
+    Ok(set)
+}
+
+// Both inputs and outputs have the same keys, and all affected keys are in
+// them.
+fn check_same_keys(inputs: &ItemsMap, outputs: &ItemsMap, keys: &KeysSet) -> bool {
+    inputs.len() == outputs.len()
+        && inputs.keys().all(|key| outputs.contains_key(key))
+        && keys.iter().all(|key| inputs.contains_key(key))
+}
@@ -0,0 +1,19 @@
+use ckb_std::ckb_types::prelude::*;
+use demo_linked_list_lib::types;
+
+use crate::error::{InternalError, Result};
+
+// The instance args is optional, the default one is used if it's omitted.
+pub(crate) fn parse_instance_args(slice: &[u8]) -> Result<types::InstanceArgs> {
+    if slice.is_empty() {
+        return Ok(Default::default());
+    }
+    let instance_args =
+        types::InstanceArgs::from_slice(slice).map_err(|_| InternalError::InvalidArgs)?;
+    if let Some(key_policy) = instance_args.as_reader().key_policy().to_opt() {
+        key_policy
+            .verify_rules()
+            .map_err(|_| InternalError::InvalidArgs)?;
+    }
+    Ok(instance_args)
+}
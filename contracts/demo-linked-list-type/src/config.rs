@@ -0,0 +1,138 @@
+use ckb_std::{ckb_constants::Source, ckb_types::prelude::*, high_level as hl, since::Since};
+use demo_linked_list_lib::types;
+
+use crate::{
+    error::{InternalError, Result},
+    owner,
+};
+
+// Load the config cell from cell deps, if the instance has one.
+pub(crate) fn load_config(
+    instance_args: &types::InstanceArgs,
+) -> Result<Option<types::RegistryConfig>> {
+    let config_type_hash = if let Some(hash) = instance_args.as_reader().config_type_hash().to_opt()
+    {
+        hash
+    } else {
+        return Ok(None);
+    };
+    let index = find_cell_by_type_hash(config_type_hash.raw_data(), Source::CellDep)
+        .ok_or(InternalError::ConfigNotFound)?;
+    debug!("found the config cell: cell_deps[{index}]");
+    let data = hl::load_cell_data(index, Source::CellDep)?;
+    let config =
+        types::RegistryConfig::from_slice(&data).map_err(|_| InternalError::ConfigInvalidData)?;
+    Ok(Some(config))
+}
+
+// The config cell should be created in the same transaction, if the instance
+// has one.
+//
+// The config cell is created by its own type script, which checks its type ID.
+pub(crate) fn load_created_config(
+    instance_args: &types::InstanceArgs,
+) -> Result<Option<types::RegistryConfig>> {
+    let config_type_hash = if let Some(hash) = instance_args.as_reader().config_type_hash().to_opt()
+    {
+        hash
+    } else {
+        return Ok(None);
+    };
+    if find_cell_by_type_hash(config_type_hash.raw_data(), Source::Input).is_some() {
+        return Err(InternalError::CreateMissingConfig.into());
+    }
+    let index = find_cell_by_type_hash(config_type_hash.raw_data(), Source::Output)
+        .ok_or(InternalError::CreateMissingConfig)?;
+    let data = hl::load_cell_data(index, Source::Output)?;
+    let config =
+        types::RegistryConfig::from_slice(&data).map_err(|_| InternalError::ConfigInvalidData)?;
+    Ok(Some(config))
+}
+
+// The config cell could be in cell deps or in inputs when destroy, or when the
+// items are moved between instances which share it, if the instance has one.
+// Return the config, and its index if it's in inputs.
+//
+// If the destroy is time locked, the config cell should be in inputs, so the
+// time since it's updated (when the destroy is scheduled) could be checked by
+// its `since`.
+pub(crate) fn load_shared_config(
+    instance_args: &types::InstanceArgs,
+) -> Result<Option<(types::RegistryConfig, Option<usize>)>> {
+    let config_type_hash = if let Some(hash) = instance_args.as_reader().config_type_hash().to_opt()
+    {
+        hash
+    } else {
+        return Ok(None);
+    };
+    let (index, source) =
+        if let Some(index) = find_cell_by_type_hash(config_type_hash.raw_data(), Source::CellDep) {
+            (index, Source::CellDep)
+        } else {
+            let index = find_cell_by_type_hash(config_type_hash.raw_data(), Source::Input)
+                .ok_or(InternalError::ConfigNotFound)?;
+            (index, Source::Input)
+        };
+    debug!("found the config cell: {source:?}[{index}]");
+    let data = hl::load_cell_data(index, source)?;
+    let config =
+        types::RegistryConfig::from_slice(&data).map_err(|_| InternalError::ConfigInvalidData)?;
+    let input_index_opt = (source == Source::Input).then_some(index);
+    Ok(Some((config, input_index_opt)))
+}
+
+// Only the admin could destroy the instance.
+//
+// If the config has a destroy delay, the destroy should be scheduled, and the
+// config cell should be in inputs, with a relative `since` in epochs, which is
+// not less than the delay. The delay is counted in whole epochs, the fraction
+// of the `since` is ignored.
+pub(crate) fn check_destroy(
+    config: &types::RegistryConfig,
+    input_index_opt: Option<usize>,
+) -> Result<()> {
+    let config = config.as_reader();
+    if !owner::is_authorized(&config.admin_lock_hash().to_array()) {
+        return Err(InternalError::DestroyUnauthorized.into());
+    }
+    if let Some(delay) = config.destroy_delay().to_opt() {
+        if !config.is_destroy_scheduled() {
+            return Err(InternalError::DestroyNotScheduled.into());
+        }
+        let index = input_index_opt.ok_or(InternalError::DestroyTimeLocked)?;
+        let since = Since::new(hl::load_input_since(index, Source::Input)?);
+        let epochs = since
+            .is_relative()
+            .then(|| since.extract_lock_value())
+            .flatten()
+            .and_then(|value| value.epoch())
+            .map(|epoch| epoch.number());
+        debug!(
+            "the destroy delay: {} epochs, waited: {epochs:?}",
+            delay.to_u64()
+        );
+        if !epochs.is_some_and(|epochs| epochs >= delay.to_u64()) {
+            return Err(InternalError::DestroyTimeLocked.into());
+        }
+    }
+    Ok(())
+}
+
+// The config cell is shared with the predecessor when the items are moved from
+// it, then the config cell is in cell deps when create, or in inputs if the
+// destroy of the predecessor is time locked.
+pub(crate) fn is_shared_config(instance_args: &types::InstanceArgs) -> bool {
+    instance_args
+        .as_reader()
+        .config_type_hash()
+        .to_opt()
+        .is_some_and(|hash| {
+            find_cell_by_type_hash(hash.raw_data(), Source::CellDep).is_some()
+                || find_cell_by_type_hash(hash.raw_data(), Source::Input).is_some()
+        })
+}
+
+fn find_cell_by_type_hash(type_hash: &[u8], source: Source) -> Option<usize> {
+    hl::QueryIter::new(hl::load_cell_type_hash, source)
+        .position(|type_hash_opt| type_hash_opt.is_some_and(|hash| hash[..] == type_hash[..]))
+}
@@ -1,6 +1,9 @@
 use core::result;
 
 use ckb_linked_list_tool::error::Error as ToolError;
+use ckb_registry_contract::error::{
+    Error as RegistryError, InternalError as RegistryInternalError,
+};
 use ckb_std::error::SysError;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -17,19 +20,62 @@ pub enum InternalError {
     // 0x10 ~ 0x1f: Errors before doing operations.
     UnknownEnvironment = 0x10,
     UnknownOperation,
+    InvalidArgs,
+    ConfigNotFound,
+    ConfigInvalidData,
+    ValidatorInvalidHashType,
+    ValidatorFailedToExec,
+    OutputInvalidWitness,
+    ReservedMissingProof,
+    ReservedInvalidProof,
+    ReservedKeyNotAllowed,
+    EventsMissing,
+    EventsMismatch,
 
     // 0x20 ~ 0x2f: Errors when create.
     CreateInvalidArgsLength = 0x20,
     CreateIncorrectUniqueId,
     CreateInvalidOutputData,
     CreateIncompleteList,
+    CreateMissingSentinel,
+    CreateBeyondMaxSentinel,
+    CreateInvalidKey,
+    CreateMissingConfig,
+    CreateMismatchPredecessor,
+    CreateMissingParent,
+    CreateUnauthorizedParent,
+    CreateMissingPredecessor,
 
     // 0x30 ~ 0x3f: Errors when destory.
     DestroyInvalidInputData = 0x30,
     DestroyIncompleteList,
+    DestroyNotOnlySentinels,
+    DestroyMismatchSuccessor,
+    DestroyUnauthorized,
+    DestroyNotScheduled,
+    DestroyTimeLocked,
 
     // 0x40 ~ 0x4f: Errors when update.
     UpdateMismatchRange = 0x40,
+    UpdateRemoveSentinel,
+    UpdateBeyondMaxSentinel,
+    UpdateInvalidKey,
+    UpdateInvalidData,
+    UpdateDowngradeVersion,
+    UpdateUnauthorizedOwner,
+    UpdateUnauthorizedRemove,
+
+    // 0x50 ~ 0x5f: Errors when verify the declared action.
+    ActionInvalidWitness = 0x50,
+    ActionMismatchOperation,
+    ActionDuplicateKeys,
+    ActionInvalidInsert,
+    ActionInvalidRemove,
+    ActionInvalidModifyPayload,
+    ActionInvalidTransfer,
+    ActionInvalidRebalance,
+    ActionInvalidMigrate,
+    ActionInvalidTransferOwner,
 
     // This is not an error, just make sure the error code is less than 0x60.
     Unreachable = 0x60,
@@ -54,6 +100,31 @@ impl From<SysError> for InternalError {
     }
 }
 
+impl From<RegistryInternalError> for InternalError {
+    fn from(err: RegistryInternalError) -> Self {
+        match err {
+            RegistryInternalError::IndexOutOfBound => Self::IndexOutOfBound,
+            RegistryInternalError::ItemMissing => Self::ItemMissing,
+            RegistryInternalError::LengthNotEnough => Self::LengthNotEnough,
+            RegistryInternalError::Encoding => Self::Encoding,
+            RegistryInternalError::Unknown => Self::Unknown,
+            RegistryInternalError::UnknownOperation => Self::UnknownOperation,
+            RegistryInternalError::InvalidArgs => Self::InvalidArgs,
+            RegistryInternalError::CreateInvalidArgsLength => Self::CreateInvalidArgsLength,
+            RegistryInternalError::CreateIncorrectUniqueId => Self::CreateIncorrectUniqueId,
+            RegistryInternalError::CreateInvalidOutputData => Self::CreateInvalidOutputData,
+            RegistryInternalError::CreateIncompleteList => Self::CreateIncompleteList,
+            RegistryInternalError::CreateMismatchPredecessor => Self::CreateMismatchPredecessor,
+            RegistryInternalError::DestroyInvalidInputData => Self::DestroyInvalidInputData,
+            RegistryInternalError::DestroyIncompleteList => Self::DestroyIncompleteList,
+            RegistryInternalError::DestroyMismatchSuccessor => Self::DestroyMismatchSuccessor,
+            RegistryInternalError::UpdateInvalidData => Self::UpdateInvalidData,
+            RegistryInternalError::UpdateMismatchRange => Self::UpdateMismatchRange,
+            RegistryInternalError::Unreachable => Self::Unreachable,
+        }
+    }
+}
+
 impl From<SysError> for Error {
     fn from(err: SysError) -> Self {
         Into::<InternalError>::into(err).into()
@@ -66,6 +137,15 @@ impl From<InternalError> for Error {
     }
 }
 
+impl From<RegistryError> for Error {
+    fn from(err: RegistryError) -> Self {
+        match err {
+            RegistryError::Internal(e) => Self::Internal(e.into()),
+            RegistryError::Tool(e) => Self::Tool(e),
+        }
+    }
+}
+
 impl From<ToolError> for Error {
     fn from(err: ToolError) -> Self {
         Self::Tool(err)
@@ -0,0 +1,63 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use ckb_registry_contract::{RegistryCell, RegistryItem};
+use ckb_std::ckb_types::prelude::*;
+use demo_linked_list_lib::types;
+
+use crate::{
+    error::{InternalError, Result},
+    item::DemoItem,
+    validator::ChangeKind,
+};
+
+type KeyEvents = BTreeMap<Vec<u8>, u32>;
+
+// The actual changed keys, and the indexes of their cells.
+#[derive(Default)]
+pub(crate) struct Events {
+    inserted: KeyEvents,
+    removed: KeyEvents,
+    modified: KeyEvents,
+}
+
+impl Events {
+    // For removed items, the index is in inputs; otherwise, it's in outputs.
+    pub(crate) fn push(&mut self, kind: ChangeKind, cell: &RegistryCell<DemoItem>) {
+        let events = match kind {
+            ChangeKind::Insert => &mut self.inserted,
+            ChangeKind::Remove => &mut self.removed,
+            ChangeKind::Modify => &mut self.modified,
+        };
+        events.insert(cell.item.key().to_vec(), cell.index as u32);
+    }
+
+    // The declared events should be the same as the actual changes, and each
+    // list should be sorted by keys.
+    pub(crate) fn verify(&self, declared: &types::RegistryEvents) -> Result<()> {
+        let expected = types::RegistryEvents::new_builder()
+            .inserted(build_key_events(&self.inserted))
+            .removed(build_key_events(&self.removed))
+            .modified(build_key_events(&self.modified))
+            .build();
+        debug!(
+            "verify events: {} inserted, {} removed, {} modified",
+            self.inserted.len(),
+            self.removed.len(),
+            self.modified.len()
+        );
+        if expected.as_slice() != declared.as_slice() {
+            return Err(InternalError::EventsMismatch.into());
+        }
+        Ok(())
+    }
+}
+
+fn build_key_events(events: &KeyEvents) -> types::KeyEventVec {
+    types::KeyEventVec::new_builder()
+        .extend(
+            events
+                .iter()
+                .map(|(key, index)| types::KeyEvent::new_from_raw_parts(key, *index)),
+        )
+        .build()
+}
@@ -0,0 +1,56 @@
+use ckb_registry_contract::RegistryItem;
+use ckb_std::ckb_types::prelude::*;
+use demo_linked_list_lib::{item::DemoItemReader, types};
+
+// An item of the demo registry, the payload is the `demo` field.
+//
+// Items in different versions could be in the same list, during the migration.
+pub(crate) enum DemoItem {
+    V1(types::DemoData),
+    V2(types::DemoDataV2),
+}
+
+impl DemoItem {
+    pub(crate) fn reader(&self) -> DemoItemReader<'_> {
+        match self {
+            Self::V1(data) => DemoItemReader::V1(data.as_reader()),
+            Self::V2(data) => DemoItemReader::V2(data.as_reader()),
+        }
+    }
+
+    pub(crate) fn version(&self) -> u8 {
+        self.reader().version()
+    }
+
+    pub(crate) fn owner(&self) -> Option<[u8; 32]> {
+        self.reader().owner().map(|owner| owner.to_array())
+    }
+}
+
+impl RegistryItem for DemoItem {
+    fn from_cell_data(data: &[u8]) -> Option<Self> {
+        match DemoItemReader::from_cell_data(data).ok()? {
+            DemoItemReader::V1(reader) => Some(Self::V1(reader.to_entity())),
+            DemoItemReader::V2(reader) => Some(Self::V2(reader.to_entity())),
+        }
+    }
+
+    fn key(&self) -> &[u8] {
+        self.reader().curr().raw_data()
+    }
+
+    fn next_key(&self) -> &[u8] {
+        self.reader().next().raw_data()
+    }
+
+    fn payload(&self) -> &[u8] {
+        self.reader().demo().raw_data()
+    }
+
+    // The version and the owner are changed by `Migrate` and `TransferOwner`.
+    fn is_modified_from(&self, old: &Self) -> bool {
+        self.payload() != old.payload()
+            || self.version() != old.version()
+            || self.owner() != old.owner()
+    }
+}
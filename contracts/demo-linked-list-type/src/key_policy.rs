@@ -0,0 +1,23 @@
+use demo_linked_list_lib::types;
+
+use crate::sentinel;
+
+// Check a key against the key policy.
+//
+// The sentinel items are not real items, so they are skipped.
+pub(crate) fn check_key(
+    policy: &types::KeyPolicyReader<'_>,
+    key: &[u8],
+    has_sentinels: bool,
+) -> bool {
+    if has_sentinels && sentinel::is_sentinel_key(key) {
+        return true;
+    }
+    match policy.check_key(key) {
+        Ok(()) => true,
+        Err(_err) => {
+            debug!("key {key:?} is rejected by the key policy: {_err}");
+            false
+        }
+    }
+}
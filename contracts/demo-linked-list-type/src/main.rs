@@ -21,15 +21,37 @@ macro_rules! debug {
 }
 
 #[cfg(target_arch = "riscv64")]
-mod entry;
+mod action;
+#[cfg(target_arch = "riscv64")]
+mod args;
+#[cfg(target_arch = "riscv64")]
+mod config;
 #[cfg(target_arch = "riscv64")]
 mod error;
 #[cfg(target_arch = "riscv64")]
-mod operations;
+mod events;
+#[cfg(target_arch = "riscv64")]
+mod item;
+#[cfg(target_arch = "riscv64")]
+mod key_policy;
+#[cfg(target_arch = "riscv64")]
+mod output;
+#[cfg(target_arch = "riscv64")]
+mod owner;
+#[cfg(target_arch = "riscv64")]
+mod parent;
+#[cfg(target_arch = "riscv64")]
+mod policy;
+#[cfg(target_arch = "riscv64")]
+mod reserved;
+#[cfg(target_arch = "riscv64")]
+mod sentinel;
+#[cfg(target_arch = "riscv64")]
+mod validator;
 
 #[cfg(target_arch = "riscv64")]
 pub fn program_entry() -> i8 {
-    match entry::main() {
+    match ckb_registry_contract::entry::<policy::DemoPolicy>() {
         Ok(_) => 0,
         Err(err) => err.into(),
     }
@@ -1,7 +0,0 @@
-mod create;
-mod destroy;
-mod update;
-
-pub(crate) use self::create::create;
-pub(crate) use self::destroy::destroy;
-pub(crate) use self::update::update;
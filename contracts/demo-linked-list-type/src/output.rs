@@ -0,0 +1,24 @@
+use ckb_std::{ckb_constants::Source, ckb_types::prelude::*, error::SysError, high_level as hl};
+use demo_linked_list_lib::types;
+
+use crate::error::{InternalError, Result};
+
+// Load the `RegistryOutput` from the `output_type` of the first witness of the
+// script group.
+//
+// It's optional, so return `None` if the witness doesn't exist, or it's not a
+// `WitnessArgs`, or its `output_type` is empty.
+pub(crate) fn load_registry_output() -> Result<Option<types::RegistryOutput>> {
+    let witness_args = match hl::load_witness_args(0, Source::GroupOutput) {
+        Ok(witness_args) => witness_args,
+        Err(SysError::IndexOutOfBound) | Err(SysError::Encoding) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    if let Some(output_type) = witness_args.output_type().to_opt() {
+        let output = types::RegistryOutput::from_slice(output_type.as_reader().raw_data())
+            .map_err(|_| InternalError::OutputInvalidWitness)?;
+        Ok(Some(output))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,7 @@
+use ckb_std::{ckb_constants::Source, high_level as hl};
+
+// The owner authorizes the transaction by putting a cell which uses the owner
+// lock in inputs.
+pub(crate) fn is_authorized(owner: &[u8; 32]) -> bool {
+    hl::QueryIter::new(hl::load_cell_lock_hash, Source::Input).any(|hash| &hash == owner)
+}
@@ -0,0 +1,41 @@
+use ckb_std::{ckb_constants::Source, high_level as hl};
+use demo_linked_list_lib::{item::DemoItemReader, types};
+
+use crate::{
+    error::{InternalError, Result},
+    owner,
+};
+
+// The parent item should be in cell deps, and its owner should authorize the
+// transaction. If the parent item has no owner, it's owned by its lock.
+//
+// Since the parent registry is checked in the same way when it's created, a
+// registration of any depth could be verified by walking the chain of parents.
+pub(crate) fn check_parent(parent: types::ParentRefReader<'_>) -> Result<()> {
+    let type_hash = parent.type_hash().raw_data();
+    let key = parent.key().raw_data();
+    for (index, type_hash_opt) in
+        hl::QueryIter::new(hl::load_cell_type_hash, Source::CellDep).enumerate()
+    {
+        if !type_hash_opt.is_some_and(|hash| hash[..] == type_hash[..]) {
+            continue;
+        }
+        let data = hl::load_cell_data(index, Source::CellDep)?;
+        let Ok(item) = DemoItemReader::from_cell_data(&data) else {
+            continue;
+        };
+        if item.curr().raw_data() != key {
+            continue;
+        }
+        debug!("found the parent item in cell deps at {}", index);
+        let owner = match item.owner() {
+            Some(owner) => owner.to_array(),
+            None => hl::load_cell_lock_hash(index, Source::CellDep)?,
+        };
+        if !owner::is_authorized(&owner) {
+            return Err(InternalError::CreateUnauthorizedParent.into());
+        }
+        return Ok(());
+    }
+    Err(InternalError::CreateMissingParent.into())
+}
@@ -0,0 +1,270 @@
+use alloc::collections::BTreeMap;
+
+use ckb_registry_contract::{Operation, RegistryCell, RegistryItem, RegistryPolicy};
+use ckb_std::high_level as hl;
+use demo_linked_list_lib::types;
+
+use crate::{
+    action, args, config,
+    error::{Error, InternalError, Result},
+    events::Events,
+    item::DemoItem,
+    key_policy, output, owner, parent,
+    reserved::ReservedKeys,
+    sentinel,
+    validator::{self, ChangeKind, Changes},
+};
+
+// The rules of the demo registry, see the README for details.
+pub(crate) struct DemoPolicy {
+    operation: Operation,
+    script_hash: [u8; 32],
+    instance_args: types::InstanceArgs,
+    config_opt: Option<types::RegistryConfig>,
+    // The config cell is consumed when destroy or move.
+    config_input_index_opt: Option<usize>,
+    // The config cell is not created together when create.
+    is_shared_config: bool,
+    action_opt: Option<types::RegistryAction>,
+    reserved_keys_opt: Option<ReservedKeys>,
+    declared_events_opt: Option<types::RegistryEvents>,
+    changes: Changes,
+    events: Events,
+}
+
+impl DemoPolicy {
+    fn has_sentinels(&self) -> bool {
+        self.instance_args.as_reader().has_sentinels()
+    }
+
+    fn migration(&self) -> Option<types::MigrationReader<'_>> {
+        self.config_opt
+            .as_ref()
+            .and_then(|config| config.as_reader().migration().to_opt())
+    }
+
+    // The sentinel items are not real items, so they are not passed to the
+    // validator, but they are still in the events.
+    fn record_change(&mut self, kind: ChangeKind, cell: &RegistryCell<DemoItem>) {
+        if !(self.has_sentinels() && sentinel::is_sentinel_key(cell.item.key())) {
+            self.changes.push(kind, cell);
+        }
+        self.events.push(kind, cell);
+    }
+}
+
+impl RegistryPolicy for DemoPolicy {
+    type Item = DemoItem;
+    type Error = Error;
+
+    fn load(operation: Operation, args: &[u8]) -> Result<Self> {
+        let instance_args = args::parse_instance_args(args)?;
+        let is_shared_config =
+            operation == Operation::Create && config::is_shared_config(&instance_args);
+        let (config_opt, config_input_index_opt) = match operation {
+            Operation::Create if !is_shared_config => {
+                // The config cell should be created together.
+                (config::load_created_config(&instance_args)?, None)
+            }
+            Operation::Create | Operation::Destroy => {
+                // The config cell could be consumed by the admin together.
+                match config::load_shared_config(&instance_args)? {
+                    Some((config, input_index_opt)) => (Some(config), input_index_opt),
+                    None => (None, None),
+                }
+            }
+            _ => {
+                // The config cell should be in cell deps.
+                (config::load_config(&instance_args)?, None)
+            }
+        };
+        // The declared action is optional, if it's omitted, the operation is
+        // inferred from the count of cells.
+        let action_opt = action::load_action()?;
+        if operation == Operation::Destroy && action_opt.is_some() {
+            return Err(InternalError::ActionMismatchOperation.into());
+        }
+        // The events are optional, unless the instance requires them. A
+        // destroy has no outputs, so it has no events.
+        let output_opt = output::load_registry_output()?;
+        let reserved_keys_opt = ReservedKeys::load(config_opt.as_ref(), output_opt.as_ref())?;
+        let declared_events_opt = output_opt.and_then(|output| output.events().to_opt());
+        if operation != Operation::Destroy
+            && instance_args.as_reader().requires_events()
+            && declared_events_opt.is_none()
+        {
+            return Err(InternalError::EventsMissing.into());
+        }
+        Ok(Self {
+            operation,
+            script_hash: hl::load_script_hash()?,
+            instance_args,
+            config_opt,
+            config_input_index_opt,
+            is_shared_config,
+            action_opt,
+            reserved_keys_opt,
+            declared_events_opt,
+            changes: Default::default(),
+            events: Default::default(),
+        })
+    }
+
+    // The items could be moved to the successor which is set in the config.
+    fn successor(&self) -> Option<[u8; 32]> {
+        self.migration()
+            .filter(|migration| migration.predecessor().raw_data() == self.script_hash)
+            .map(|migration| migration.successor().to_array())
+    }
+
+    // The items could be moved from the predecessor which is set in the
+    // config.
+    fn predecessor(&self) -> Option<[u8; 32]> {
+        self.migration()
+            .filter(|migration| migration.successor().raw_data() == self.script_hash)
+            .map(|migration| migration.predecessor().to_array())
+    }
+
+    // The config cell should be created together, the owner of the parent
+    // item should authorize the transaction, both sentinel items should be
+    // created, and no item could be larger than the maximum sentinel item.
+    //
+    // A shared config cell in cell deps is only for the items which are moved
+    // from the predecessor.
+    fn on_create(&mut self, items: &[RegistryCell<DemoItem>]) -> Result<()> {
+        if self.is_shared_config {
+            return Err(InternalError::CreateMissingPredecessor.into());
+        }
+        if let Some(parent) = self.instance_args.as_reader().parent().to_opt() {
+            parent::check_parent(parent)?;
+        }
+        if self.has_sentinels() {
+            let state = sentinel::scan_cells(items);
+            if !state.has_both() {
+                return Err(InternalError::CreateMissingSentinel.into());
+            }
+            if state.beyond_max {
+                return Err(InternalError::CreateBeyondMaxSentinel.into());
+            }
+        }
+        Ok(())
+    }
+
+    // Only the admin could destroy the instance, after the time lock if there
+    // is one, and only the sentinel items are allowed to be left. Without a
+    // config cell, the instance opts in to be destroyed by anyone.
+    fn on_destroy(&mut self, items: &[RegistryCell<DemoItem>]) -> Result<()> {
+        if let Some(config) = &self.config_opt {
+            config::check_destroy(config, self.config_input_index_opt)?;
+        }
+        if self.has_sentinels() {
+            if items.len() != 2 {
+                return Err(InternalError::DestroyNotOnlySentinels.into());
+            }
+            if !sentinel::scan_cells(items).has_both() {
+                return Err(InternalError::DestroyNotOnlySentinels.into());
+            }
+        }
+        Ok(())
+    }
+
+    // The predecessor loses the items, so the moves are gated as a destroy,
+    // users get the same advance notice.
+    fn on_move_out(&mut self, _items: &[RegistryCell<DemoItem>]) -> Result<()> {
+        if let Some(config) = &self.config_opt {
+            config::check_destroy(config, self.config_input_index_opt)?;
+        }
+        Ok(())
+    }
+
+    // No item could be larger than the maximum sentinel item, no item could
+    // be downgraded to an older version, the owner of an item could be changed
+    // by the current owner only, and the declared action should be matched.
+    fn on_update(
+        &mut self,
+        inputs: &[RegistryCell<DemoItem>],
+        outputs: &[RegistryCell<DemoItem>],
+    ) -> Result<()> {
+        if self.has_sentinels() && sentinel::scan_cells(outputs).beyond_max {
+            return Err(InternalError::UpdateBeyondMaxSentinel.into());
+        }
+        let items = inputs
+            .iter()
+            .map(|cell| (cell.item.key(), &cell.item))
+            .collect::<BTreeMap<_, _>>();
+        for cell in outputs {
+            let Some(item) = items.get(cell.item.key()) else {
+                continue;
+            };
+            if cell.item.version() < item.version() {
+                return Err(InternalError::UpdateDowngradeVersion.into());
+            }
+            if let Some(owner) = item.owner() {
+                if cell.item.owner() != Some(owner) && !owner::is_authorized(&owner) {
+                    return Err(InternalError::UpdateUnauthorizedOwner.into());
+                }
+            }
+        }
+        if let Some(action) = &self.action_opt {
+            action::verify(action.as_reader(), inputs, outputs)?;
+        }
+        Ok(())
+    }
+
+    // Inserted keys should follow the key policy, and should not be reserved.
+    fn on_insert(&mut self, item: &RegistryCell<DemoItem>) -> Result<()> {
+        if let Some(policy) = self.instance_args.as_reader().key_policy().to_opt() {
+            if !key_policy::check_key(&policy, item.item.key(), self.has_sentinels()) {
+                let err = if self.operation == Operation::Create {
+                    InternalError::CreateInvalidKey
+                } else {
+                    InternalError::UpdateInvalidKey
+                };
+                return Err(err.into());
+            }
+        }
+        if let Some(reserved_keys) = &self.reserved_keys_opt {
+            if !(self.has_sentinels() && sentinel::is_sentinel_key(item.item.key())) {
+                reserved_keys.check_key(item.item.key())?;
+            }
+        }
+        self.record_change(ChangeKind::Insert, item);
+        Ok(())
+    }
+
+    // Sentinel items could not be removed, except the instance is destroyed.
+    // An owned item could be removed by its owner only, otherwise it could be
+    // removed then inserted again to get around `TransferOwner`.
+    fn on_remove(&mut self, item: &RegistryCell<DemoItem>) -> Result<()> {
+        if self.operation == Operation::Update {
+            if self.has_sentinels() && sentinel::is_sentinel_key(item.item.key()) {
+                return Err(InternalError::UpdateRemoveSentinel.into());
+            }
+            if let Some(owner) = item.item.owner() {
+                if !owner::is_authorized(&owner) {
+                    return Err(InternalError::UpdateUnauthorizedRemove.into());
+                }
+            }
+        }
+        self.record_change(ChangeKind::Remove, item);
+        Ok(())
+    }
+
+    fn on_modify(
+        &mut self,
+        _old: &RegistryCell<DemoItem>,
+        new: &RegistryCell<DemoItem>,
+    ) -> Result<()> {
+        self.record_change(ChangeKind::Modify, new);
+        Ok(())
+    }
+
+    // The declared events should match the changes, and the validator should
+    // be executed at last, since it replaces the current script.
+    fn finish(self) -> Result<()> {
+        if let Some(declared_events) = &self.declared_events_opt {
+            self.events.verify(declared_events)?;
+        }
+        validator::exec_validator(self.config_opt.as_ref(), &self.changes)
+    }
+}
@@ -0,0 +1,56 @@
+use demo_linked_list_lib::{merkle::Membership, types};
+
+use crate::{
+    error::{InternalError, Result},
+    owner,
+};
+
+// The reserved keys in the config, and the proofs of the inserted keys.
+pub(crate) struct ReservedKeys {
+    set: types::MerkleSet,
+    proofs: types::MerkleKeyProofVec,
+}
+
+impl ReservedKeys {
+    // Load the reserved keys from the config.
+    //
+    // The admin could insert any keys, so return `None` if the admin
+    // authorizes the transaction, or there are no reserved keys.
+    pub(crate) fn load(
+        config_opt: Option<&types::RegistryConfig>,
+        output_opt: Option<&types::RegistryOutput>,
+    ) -> Result<Option<Self>> {
+        let Some(config) = config_opt else {
+            return Ok(None);
+        };
+        let Some(set) = config.reserved_keys().to_opt() else {
+            return Ok(None);
+        };
+        if owner::is_authorized(&config.as_reader().admin_lock_hash().to_array()) {
+            debug!("the admin could insert reserved keys");
+            return Ok(None);
+        }
+        let proofs = output_opt
+            .map(|output| output.reserved_key_proofs())
+            .unwrap_or_default();
+        Ok(Some(Self { set, proofs }))
+    }
+
+    // The inserted key should be proven not reserved.
+    pub(crate) fn check_key(&self, key: &[u8]) -> Result<()> {
+        let proofs = self.proofs.as_reader();
+        let proof = proofs
+            .iter()
+            .find(|proof| proof.key().raw_data() == key)
+            .ok_or(InternalError::ReservedMissingProof)?;
+        let membership = self
+            .set
+            .as_reader()
+            .verify_key(&proof)
+            .map_err(|_| InternalError::ReservedInvalidProof)?;
+        if membership == Membership::Member {
+            return Err(InternalError::ReservedKeyNotAllowed.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use ckb_registry_contract::{RegistryCell, RegistryItem};
+use demo_linked_list_lib::constants::{SENTINEL_MAX_KEY, SENTINEL_MIN_KEY};
+
+use crate::item::DemoItem;
+
+// What sentinel items are found in a set of items.
+#[derive(Default)]
+pub(crate) struct SentinelsState {
+    pub(crate) has_min: bool,
+    pub(crate) has_max: bool,
+    // The maximum sentinel item doesn't point back to the minimum sentinel
+    // item, which means that there is an item larger than it.
+    pub(crate) beyond_max: bool,
+}
+
+impl SentinelsState {
+    fn scan(&mut self, curr: &[u8], next: &[u8]) {
+        if curr == SENTINEL_MIN_KEY {
+            self.has_min = true;
+        } else if curr == SENTINEL_MAX_KEY {
+            self.has_max = true;
+            if next != SENTINEL_MIN_KEY {
+                self.beyond_max = true;
+            }
+        }
+    }
+
+    pub(crate) fn has_both(&self) -> bool {
+        self.has_min && self.has_max
+    }
+}
+
+pub(crate) fn is_sentinel_key(key: &[u8]) -> bool {
+    key == SENTINEL_MIN_KEY || key == SENTINEL_MAX_KEY
+}
+
+pub(crate) fn scan_cells(cells: &[RegistryCell<DemoItem>]) -> SentinelsState {
+    let mut state = SentinelsState::default();
+    for cell in cells {
+        state.scan(cell.item.key(), cell.item.next_key());
+    }
+    state
+}
@@ -0,0 +1,100 @@
+use alloc::{ffi::CString, vec::Vec};
+use core::ffi::CStr;
+
+use ckb_registry_contract::{RegistryCell, RegistryItem};
+use ckb_std::{ckb_types::core::ScriptHashType, high_level as hl};
+use demo_linked_list_lib::types;
+
+use crate::{
+    error::{InternalError, Result},
+    item::DemoItem,
+};
+
+// How a key is changed, it's passed to the validator as the first argument of
+// each change.
+#[derive(Clone, Copy)]
+pub(crate) enum ChangeKind {
+    Insert,
+    Remove,
+    Modify,
+}
+
+struct KeyChange {
+    kind: ChangeKind,
+    key: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+// The changed keys, which are passed to the validator.
+#[derive(Default)]
+pub(crate) struct Changes(Vec<KeyChange>);
+
+impl ChangeKind {
+    fn as_c_str(self) -> &'static CStr {
+        match self {
+            Self::Insert => c"insert",
+            Self::Remove => c"remove",
+            Self::Modify => c"modify",
+        }
+    }
+}
+
+impl Changes {
+    // For removed items, the payload is the one before removed; otherwise,
+    // it's the new payload.
+    pub(crate) fn push(&mut self, kind: ChangeKind, cell: &RegistryCell<DemoItem>) {
+        self.0.push(KeyChange {
+            kind,
+            key: cell.item.key().to_vec(),
+            payload: cell.item.payload().to_vec(),
+        });
+    }
+}
+
+// Execute the validator, which is set in the config, with the changed keys.
+//
+// The validator replaces the current script, so its exit code is the final
+// verdict, this function only returns if there is nothing to validate or the
+// validator could not be executed.
+//
+// Each change is passed as 3 arguments: the kind, the hex-encoded key and the
+// hex-encoded payload.
+pub(crate) fn exec_validator(
+    config_opt: Option<&types::RegistryConfig>,
+    changes: &Changes,
+) -> Result<()> {
+    let validator = if let Some(validator) =
+        config_opt.and_then(|config| config.as_reader().validator().to_opt())
+    {
+        validator
+    } else {
+        return Ok(());
+    };
+    if changes.0.is_empty() {
+        return Ok(());
+    }
+    let hash_type = match u8::from(validator.hash_type()) {
+        0 => ScriptHashType::Data,
+        1 => ScriptHashType::Type,
+        2 => ScriptHashType::Data1,
+        4 => ScriptHashType::Data2,
+        _ => return Err(InternalError::ValidatorInvalidHashType.into()),
+    };
+    let encoded = changes
+        .0
+        .iter()
+        .map(|change| (hl::encode_hex(&change.key), hl::encode_hex(&change.payload)))
+        .collect::<Vec<(CString, CString)>>();
+    let argv = changes
+        .0
+        .iter()
+        .zip(encoded.iter())
+        .flat_map(|(change, (key, payload))| {
+            [change.kind.as_c_str(), key.as_c_str(), payload.as_c_str()]
+        })
+        .collect::<Vec<_>>();
+    debug!("execute the validator with {} changes", changes.0.len());
+    let _err = hl::exec_cell(validator.code_hash().raw_data(), hash_type, &argv).unwrap_err();
+    debug!("failed to execute the validator: {_err:?}");
+    Err(InternalError::ValidatorFailedToExec.into())
+}
@@ -0,0 +1,107 @@
+use ckb_hash::{new_blake2b, BLAKE2B_LEN};
+use ckb_std::{ckb_constants::Source, ckb_types::prelude::*, high_level as hl};
+use demo_linked_list_lib::types;
+
+use crate::error::{Error, Result};
+
+// The script args is a 32-byte type ID.
+const TYPE_ID_SIZE: usize = 32;
+
+pub fn main() -> Result<()> {
+    debug!("{} Starting ...", module_path!());
+
+    let script = hl::load_script()?;
+    let script_args = script.args();
+    let script_args_slice = script_args.as_reader().raw_data();
+    if script_args_slice.len() != TYPE_ID_SIZE {
+        return Err(Error::InvalidArgsLength);
+    }
+
+    // At most 1 config cell with the same type ID, in inputs or outputs.
+    let inputs_count = hl::QueryIter::new(hl::load_cell_capacity, Source::GroupInput).count();
+    let outputs_count = hl::QueryIter::new(hl::load_cell_capacity, Source::GroupOutput).count();
+    debug!("config cells: {inputs_count} in inputs, {outputs_count} in outputs");
+
+    match (inputs_count, outputs_count) {
+        (0, 1) => {
+            debug!("create a config cell");
+            let output_index = find_output_index(&hl::load_script_hash()?)?;
+            let type_id = load_then_calculate_type_id(output_index)?;
+            if type_id[..] != script_args_slice[..] {
+                return Err(Error::CreateIncorrectTypeId);
+            }
+            load_config(0, Source::GroupOutput)?;
+        }
+        (1, 0) => {
+            // The instance can't be updated or destroyed without its config
+            // cell, so the config cell lives as long as the instance.
+            debug!("destroy a config cell");
+            return Err(Error::DestroyNotAllowed);
+        }
+        (1, 1) => {
+            debug!("update a config cell");
+            let input_config = load_config(0, Source::GroupInput)?;
+            let output_config = load_config(0, Source::GroupOutput)?;
+            if !is_authorized_by(&input_config) {
+                return Err(Error::UpdateNotAuthorized);
+            }
+            let input_version = input_config.as_reader().version().to_u32();
+            let output_version = output_config.as_reader().version().to_u32();
+            if input_version.checked_add(1) != Some(output_version) {
+                return Err(Error::UpdateIncorrectVersion);
+            }
+            // The reserved keys are fixed when the config cell is created.
+            if input_config.as_reader().reserved_keys().as_slice()
+                != output_config.as_reader().reserved_keys().as_slice()
+            {
+                return Err(Error::UpdateChangedReservedKeys);
+            }
+            // The destroy delay is fixed when the config cell is created, so
+            // users always get the advance notice before the destroy.
+            if input_config.as_reader().destroy_delay().as_slice()
+                != output_config.as_reader().destroy_delay().as_slice()
+            {
+                return Err(Error::UpdateChangedDestroyDelay);
+            }
+        }
+        (_, _) => {
+            return Err(Error::UnknownOperation);
+        }
+    }
+
+    debug!("{} DONE.", module_path!());
+
+    Ok(())
+}
+
+fn load_config(index: usize, source: Source) -> Result<types::RegistryConfig> {
+    let data = hl::load_cell_data(index, source)?;
+    types::RegistryConfig::from_slice(&data).map_err(|_| Error::InvalidConfigData)
+}
+
+// The admin authorizes a transaction by putting a cell, which uses the admin
+// lock, into the inputs.
+fn is_authorized_by(config: &types::RegistryConfig) -> bool {
+    let admin_lock_hash = config.as_reader().admin_lock_hash();
+    hl::QueryIter::new(hl::load_cell_lock_hash, Source::Input)
+        .any(|lock_hash| lock_hash[..] == admin_lock_hash.raw_data()[..])
+}
+
+// Find the index of the first output which uses current script.
+fn find_output_index(script_hash: &[u8; 32]) -> Result<usize> {
+    hl::QueryIter::new(hl::load_cell_type_hash, Source::Output)
+        .position(|type_hash_opt| type_hash_opt.as_ref() == Some(script_hash))
+        .ok_or(Error::ItemMissing)
+}
+
+// Same as the unique ID of the registry instance: calculate a type ID with the
+// first input and the index of the output.
+fn load_then_calculate_type_id(output_index: usize) -> Result<[u8; BLAKE2B_LEN]> {
+    let input = hl::load_input(0, Source::Input)?;
+    let mut blake2b = new_blake2b();
+    blake2b.update(input.as_slice());
+    blake2b.update(&(output_index as u64).to_le_bytes());
+    let mut ret = [0; BLAKE2B_LEN];
+    blake2b.finalize(&mut ret);
+    Ok(ret)
+}
@@ -0,0 +1,50 @@
+use core::result;
+
+use ckb_std::error::SysError;
+
+pub type Result<T> = result::Result<T, Error>;
+
+#[repr(i8)]
+pub enum Error {
+    // 0x01 ~ 0x0f: Errors from SDK, or other system errors.
+    IndexOutOfBound = 0x01,
+    ItemMissing,
+    LengthNotEnough,
+    Encoding,
+    Unknown,
+
+    // 0x10 ~ 0x1f: Errors before doing operations.
+    UnknownOperation = 0x10,
+    InvalidArgsLength,
+    InvalidConfigData,
+
+    // 0x20 ~ 0x2f: Errors when create.
+    CreateIncorrectTypeId = 0x20,
+
+    // 0x30 ~ 0x3f: Errors when destory.
+    DestroyNotAllowed = 0x30,
+
+    // 0x40 ~ 0x4f: Errors when update.
+    UpdateNotAuthorized = 0x40,
+    UpdateIncorrectVersion,
+    UpdateChangedReservedKeys,
+    UpdateChangedDestroyDelay,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        match err {
+            SysError::IndexOutOfBound => Self::IndexOutOfBound,
+            SysError::ItemMissing => Self::ItemMissing,
+            SysError::LengthNotEnough(_) => Self::LengthNotEnough,
+            SysError::Encoding => Self::Encoding,
+            SysError::Unknown(_) => Self::Unknown,
+        }
+    }
+}
+
+impl From<Error> for i8 {
+    fn from(err: Error) -> Self {
+        err as i8
+    }
+}
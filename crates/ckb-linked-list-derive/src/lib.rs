@@ -0,0 +1,158 @@
+//! Derive macros for the [CKB linked list tool].
+//!
+//! [CKB linked list tool]: https://github.com/cryptape/ckb-global-registry
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Error, Fields, Ident, LitStr, Type};
+
+/// Implements `LinkedListItem` for a wrapper of a molecule table.
+///
+/// The wrapper should be a tuple struct with only the molecule entity, or
+/// set the entity by the attribute `entity`.
+///
+/// The attribute `linked_list` has the following arguments:
+///
+/// - `field` (required): the molecule type of the `curr` and `next` fields.
+/// - `curr` (default: `"curr"`): the name of the field of the current data.
+/// - `next` (default: `"next"`): the name of the field of the next data.
+/// - `entity` (optional): the molecule type of the item.
+///
+/// The readers are the molecule types with suffix `Reader`, as the molecule
+/// code generator does.
+///
+/// ```ignore
+/// #[derive(LinkedListItem)]
+/// #[linked_list(field = "types::Bytes", curr = "curr", next = "next")]
+/// struct DemoItem(types::DemoData);
+/// ```
+#[proc_macro_derive(LinkedListItem, attributes(linked_list))]
+pub fn derive_linked_list_item(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct Arguments {
+    entity: Option<Type>,
+    field: Option<Type>,
+    curr: Ident,
+    next: Ident,
+}
+
+impl Arguments {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut args = Self {
+            entity: None,
+            field: None,
+            curr: Ident::new("curr", Span::call_site()),
+            next: Ident::new("next", Span::call_site()),
+        };
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("linked_list"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let value: LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("entity") {
+                    args.entity = Some(value.parse()?);
+                } else if meta.path.is_ident("field") {
+                    args.field = Some(value.parse()?);
+                } else if meta.path.is_ident("curr") {
+                    args.curr = value.parse()?;
+                } else if meta.path.is_ident("next") {
+                    args.next = value.parse()?;
+                } else {
+                    return Err(meta.error("unsupported argument"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(args)
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let args = Arguments::parse(&input)?;
+    let entity = match args.entity {
+        Some(entity) => entity,
+        None => wrapped_type(&input)?,
+    };
+    let field = args.field.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "the argument `field` is required, e.g. #[linked_list(field = \"types::Bytes\")]",
+        )
+    })?;
+    let reader = reader_of(&entity)?;
+    let field_reader = reader_of(&field)?;
+    let (curr, next) = (&args.curr, &args.next);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ckb_linked_list_tool::types::LinkedListItem
+            for #name #ty_generics #where_clause
+        {
+            type Entity = #entity;
+            type Reader<'r> = #reader<'r>;
+            type Field = #field;
+            type FieldReader<'r> = #field_reader<'r>;
+
+            fn parse_entity<E>(
+                entity: Self::Entity,
+            ) -> ::core::result::Result<::ckb_linked_list_tool::types::ListItem<Self::Field>, E>
+            {
+                ::core::result::Result::Ok(::ckb_linked_list_tool::types::ListItem::new(
+                    entity.#curr(),
+                    entity.#next(),
+                ))
+            }
+
+            fn parse_reader<'r, E>(
+                reader: Self::Reader<'r>,
+            ) -> ::core::result::Result<
+                ::ckb_linked_list_tool::types::ListItem<Self::FieldReader<'r>>,
+                E,
+            > {
+                ::core::result::Result::Ok(::ckb_linked_list_tool::types::ListItem::new(
+                    reader.#curr(),
+                    reader.#next(),
+                ))
+            }
+        }
+    })
+}
+
+// The type in a tuple struct which has only one field.
+fn wrapped_type(input: &DeriveInput) -> syn::Result<Type> {
+    if let syn::Data::Struct(data) = &input.data {
+        if let Fields::Unnamed(fields) = &data.fields {
+            if fields.unnamed.len() == 1 {
+                return Ok(fields.unnamed[0].ty.clone());
+            }
+        }
+    }
+    Err(Error::new(
+        input.ident.span(),
+        "expect a tuple struct with only one field, or set the argument `entity`",
+    ))
+}
+
+// The reader type of a molecule type: the same path with suffix `Reader`.
+fn reader_of(ty: &Type) -> syn::Result<syn::Path> {
+    if let Type::Path(type_path) = ty {
+        if type_path.qself.is_none() {
+            let mut path = type_path.path.clone();
+            if let Some(last) = path.segments.last_mut() {
+                if last.arguments.is_empty() {
+                    last.ident = Ident::new(&format!("{}Reader", last.ident), last.ident.span());
+                    return Ok(path);
+                }
+            }
+        }
+    }
+    Err(Error::new(ty.span(), "expect a path of a molecule type"))
+}
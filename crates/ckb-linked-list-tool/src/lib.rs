@@ -11,6 +11,9 @@ use core::cmp::{Ord, Ordering};
 pub mod error;
 pub mod types;
 
+#[cfg(feature = "derive")]
+pub use ckb_linked_list_derive::LinkedListItem;
+
 use crate::{
     error::Error,
     types::{DataParseFunc, ListItem, ListItemsSummary},
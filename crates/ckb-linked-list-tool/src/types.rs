@@ -11,6 +11,34 @@ pub struct ListItem<Field> {
     pub next: Field,
 }
 
+/// A molecule table which could be parsed as an item of the linked list.
+///
+/// It could be implemented by the derive macro `LinkedListItem`, with the
+/// feature `derive`.
+///
+/// Both parse functions could be used as the `parse_func` of
+/// [`check_linked_list_with_ordered_items`] and
+/// [`check_linked_list_with_unordered_items`].
+///
+/// [`check_linked_list_with_ordered_items`]: crate::check_linked_list_with_ordered_items
+/// [`check_linked_list_with_unordered_items`]: crate::check_linked_list_with_unordered_items
+pub trait LinkedListItem {
+    /// The entity of the item.
+    type Entity;
+    /// The reader of the item.
+    type Reader<'r>;
+    /// The entity of the current data and the next data.
+    type Field;
+    /// The reader of the current data and the next data.
+    type FieldReader<'r>;
+
+    /// Parses a list item from an entity.
+    fn parse_entity<E>(entity: Self::Entity) -> Result<ListItem<Self::Field>, E>;
+
+    /// Parses a list item from a reader.
+    fn parse_reader<'r, E>(reader: Self::Reader<'r>) -> Result<ListItem<Self::FieldReader<'r>>, E>;
+}
+
 /// A summary of one or more than one continuous linked list items.
 pub struct ListItemsSummary<Field> {
     /// Current data of the first item.
@@ -0,0 +1,115 @@
+//! Parse the command-line arguments.
+
+use std::collections::BTreeMap;
+
+use ckb_types::bytes::Bytes;
+
+use crate::error::{Error, Result};
+
+/// A subcommand with its options, all options are `--name value`.
+#[derive(Debug, Clone)]
+pub struct Args {
+    /// The subcommand.
+    pub command: String,
+    options: BTreeMap<String, String>,
+}
+
+impl Args {
+    /// Parses the arguments, without the program name.
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut iter = args.iter();
+        let command = iter
+            .next()
+            .ok_or_else(|| Error::Usage("no command".to_owned()))?
+            .clone();
+        let mut options = BTreeMap::new();
+        while let Some(arg) = iter.next() {
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| Error::Usage(format!("unexpected argument {arg}")))?;
+            let value = iter
+                .next()
+                .ok_or_else(|| Error::Usage(format!("no value for --{name}")))?;
+            if options.insert(name.to_owned(), value.clone()).is_some() {
+                return Err(Error::Usage(format!("duplicated option --{name}")));
+            }
+        }
+        Ok(Self { command, options })
+    }
+
+    /// Checks that all options are allowed by the command.
+    pub fn allow(&self, names: &[&str]) -> Result<()> {
+        match self
+            .options
+            .keys()
+            .find(|name| !names.contains(&name.as_str()))
+        {
+            Some(name) => Err(Error::Usage(format!(
+                "unknown option --{name} for {}",
+                self.command
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// The value of an optional option.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// The value of a required option.
+    pub fn require(&self, name: &str) -> Result<&str> {
+        self.get(name)
+            .ok_or_else(|| Error::Usage(format!("--{name} is required for {}", self.command)))
+    }
+
+    /// An optional number.
+    pub fn get_u64(&self, name: &str) -> Result<Option<u64>> {
+        self.get(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| Error::Usage(format!("--{name} should be a number")))
+            })
+            .transpose()
+    }
+}
+
+/// Parses a key or a payload, it's hex with the prefix `0x`, otherwise it's
+/// UTF-8 text.
+pub fn parse_bytes(value: &str) -> Result<Bytes> {
+    match value.strip_prefix("0x") {
+        Some(hex) => decode_hex(hex).map(Bytes::from),
+        None => Ok(Bytes::copy_from_slice(value.as_bytes())),
+    }
+}
+
+/// Decodes hex without the prefix `0x`.
+pub fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return Err(Error::Hex(hex.to_owned()));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| {
+            hex.get(index..index + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| Error::Hex(hex.to_owned()))
+        })
+        .collect()
+}
+
+/// Formats bytes as text if they are printable, otherwise as hex.
+pub fn display_bytes(bytes: &[u8]) -> String {
+    let is_text = !bytes.is_empty() && bytes.iter().all(|byte| byte.is_ascii_graphic());
+    if is_text && !bytes.starts_with(b"0x") {
+        String::from_utf8_lossy(bytes).into_owned()
+    } else {
+        format!("0x{}", encode_hex(bytes))
+    }
+}
+
+/// Encodes bytes as hex without the prefix `0x`.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
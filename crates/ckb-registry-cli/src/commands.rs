@@ -0,0 +1,242 @@
+//! The subcommands.
+
+use std::{fmt::Write, fs};
+
+use ckb_registry_client::{create, CreateRequest, ItemCell, Registry, UnsignedTx};
+use ckb_types::{bytes::Bytes, prelude::*, H256};
+use demo_linked_list_lib::types;
+
+use crate::{
+    args::{display_bytes, parse_bytes, Args},
+    error::{Error, Result},
+    export::{check_digest, Format, ItemsSnapshot},
+    mock_tx, plan,
+    state::{format_args, parse_args, write_json, State},
+};
+
+/// The default fee of transactions, in shannons.
+pub const DEFAULT_FEE: u64 = 100_000;
+
+// The options to load the state.
+const STATE_OPTIONS: [&str; 3] = ["snapshot", "rpc", "deployment"];
+// The options of the commands which write transactions.
+const TX_OPTIONS: [&str; 2] = ["fee", "output"];
+
+pub fn create_instance(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["items", "instance-args"], true))?;
+    let state = State::load(args)?;
+    let items = parse_items(args.require("items")?)?;
+    let instance_args = match args.get("instance-args") {
+        Some(value) => types::InstanceArgs::from_slice(&parse_args(value)?)
+            .map_err(|err| Error::Usage(format!("invalid --instance-args: {err}")))?,
+        None => types::InstanceArgs::default(),
+    };
+    let tx = state.build_with_funding(fee(args)?, |funding| {
+        let request = CreateRequest {
+            contract: state.contract(),
+            instance_args: instance_args.clone(),
+            items: items.clone(),
+            lock: state.lock(),
+            config: None,
+        };
+        create(request, funding)
+    })?;
+    let output = tx.tx.output(0).expect("item");
+    let type_script = output.type_().to_opt().expect("type script");
+    plan::check_tx(&type_script, &[], &tx)?;
+    let mut summary = format!(
+        "instance: {}\n",
+        format_args(&type_script.args().raw_data())
+    );
+    summary.push_str(&write_tx(args, &state, &tx)?);
+    Ok(summary)
+}
+
+pub fn insert(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["args", "key", "payload"], true))?;
+    let state = State::load(args)?;
+    let registry = state.registry(args)?;
+    let key = parse_bytes(args.require("key")?)?;
+    let payload = parse_bytes(args.get("payload").unwrap_or_default())?;
+    let tx = state.build_with_funding(fee(args)?, |funding| {
+        registry.insert(&key, &payload, state.lock(), funding)
+    })?;
+    check_and_write(args, &state, &registry, &tx)
+}
+
+pub fn remove(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["args", "key"], true))?;
+    let state = State::load(args)?;
+    let registry = state.registry(args)?;
+    let key = parse_bytes(args.require("key")?)?;
+    let tx = state.build_with_funding(fee(args)?, |funding| registry.remove(&key, funding))?;
+    check_and_write(args, &state, &registry, &tx)
+}
+
+pub fn destroy(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["args"], true))?;
+    let state = State::load(args)?;
+    let registry = state.registry(args)?;
+    let tx = state.build_with_funding(fee(args)?, |funding| registry.destroy(funding))?;
+    check_and_write(args, &state, &registry, &tx)
+}
+
+pub fn list(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["args"], false))?;
+    let state = State::load(args)?;
+    let registry = state.registry(args)?;
+    let mut output = String::new();
+    for cell in registry.items() {
+        output.push_str(&format_item(cell));
+    }
+    Ok(output)
+}
+
+pub fn lookup(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["args", "key"], false))?;
+    let state = State::load(args)?;
+    let registry = state.registry(args)?;
+    let key = parse_bytes(args.require("key")?)?;
+    if let Some(cell) = registry.get(&key) {
+        return Ok(format_item(cell));
+    }
+    // The key is absent, show the item which covers it, that's the item to
+    // update when it's inserted.
+    Ok(format!(
+        "not found, covered by:\n{}",
+        format_item(registry.predecessor(&key))
+    ))
+}
+
+pub fn verify(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["args"], false))?;
+    let state = State::load(args)?;
+    let instances = match args.get("args") {
+        Some(_) => vec![state.instance(args)?],
+        None => state.instances().into_iter().collect(),
+    };
+    let mut output = String::new();
+    let mut failures = 0;
+    for (type_args, cells) in instances {
+        let result = match plan::check_ring(&cells) {
+            Ok(count) => format!("ok, {count} items"),
+            Err(err) => {
+                failures += 1;
+                format!("{err}")
+            }
+        };
+        writeln!(output, "{}: {result}", format_args(&type_args)).expect("write");
+    }
+    if failures > 0 {
+        return Err(Error::InvalidInstances(output));
+    }
+    Ok(output)
+}
+
+pub fn export(args: &Args) -> Result<String> {
+    args.allow(&with_options(&["args", "format", "output"], false))?;
+    let state = State::load(args)?;
+    let (type_args, cells) = state.instance(args)?;
+    let format = Format::parse(args.get("format").unwrap_or("json"))?;
+    let snapshot = ItemsSnapshot::export(&state.type_script(type_args), &cells)?;
+    let content = snapshot.to_text(format)?;
+    match args.get("output") {
+        Some(path) => {
+            fs::write(path, content).map_err(|source| Error::Io {
+                path: path.to_owned(),
+                source,
+            })?;
+            Ok(format!(
+                "items: {}\ndigest: {:#x}\nwritten to {path}\n",
+                snapshot.items.len(),
+                snapshot.digest()
+            ))
+        }
+        None => Ok(content),
+    }
+}
+
+pub fn audit(args: &Args) -> Result<String> {
+    args.allow(&["file", "digest"])?;
+    let path = args.require("file")?;
+    let content = fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })?;
+    let snapshot = ItemsSnapshot::import(&content)?;
+    let digest = snapshot.audit()?;
+    if let Some(expected) = args.get("digest") {
+        let expected = parse_args(expected)?;
+        let expected = H256::from_slice(&expected)
+            .map_err(|_| Error::Usage("--digest should be 32 bytes".to_owned()))?;
+        check_digest(&expected, &digest)?;
+    }
+    Ok(format!(
+        "ok, {} items in a complete ring\ndigest: {digest:#x}\n",
+        snapshot.items.len()
+    ))
+}
+
+fn with_options(names: &[&'static str], writes_tx: bool) -> Vec<&'static str> {
+    let mut options = names.to_vec();
+    options.extend(STATE_OPTIONS);
+    if writes_tx {
+        options.extend(TX_OPTIONS);
+    }
+    options
+}
+
+fn fee(args: &Args) -> Result<u64> {
+    Ok(args.get_u64("fee")?.unwrap_or(DEFAULT_FEE))
+}
+
+// Parses `key=payload,key=payload`, the payload could be omitted.
+fn parse_items(value: &str) -> Result<Vec<(Bytes, Bytes)>> {
+    value
+        .split(',')
+        .map(|item| {
+            let (key, payload) = item.split_once('=').unwrap_or((item, ""));
+            Ok((parse_bytes(key)?, parse_bytes(payload)?))
+        })
+        .collect()
+}
+
+fn format_item(cell: &ItemCell) -> String {
+    let out_point = &cell.cell.out_point;
+    let tx_hash = out_point.tx_hash();
+    let index: u32 = out_point.index().unpack();
+    format!(
+        "{} -> {}\tpayload: {}\tout point: {}#{index}\n",
+        display_bytes(&cell.item.key),
+        display_bytes(&cell.item.next),
+        display_bytes(&cell.item.payload),
+        format_args(tx_hash.as_slice()),
+    )
+}
+
+fn check_and_write(
+    args: &Args,
+    state: &State,
+    registry: &Registry,
+    tx: &UnsignedTx,
+) -> Result<String> {
+    let inputs = mock_tx::resolve_inputs(state, tx)?;
+    plan::check_tx(registry.type_script(), &inputs, tx)?;
+    write_tx(args, state, tx)
+}
+
+// Writes the mock transaction to `--output`, or returns it.
+fn write_tx(args: &Args, state: &State, tx: &UnsignedTx) -> Result<String> {
+    let mock_tx = mock_tx::to_mock_tx(state, tx)?;
+    match args.get("output") {
+        Some(path) => {
+            write_json(path, &mock_tx)?;
+            Ok(format!(
+                "transaction: {}\nchange: {}\nwritten to {path}\n",
+                format_args(tx.tx.hash().as_slice()),
+                tx.change
+            ))
+        }
+        None => Ok(serde_json::to_string_pretty(&mock_tx)? + "\n"),
+    }
+}
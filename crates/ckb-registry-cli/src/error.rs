@@ -0,0 +1,48 @@
+//! Errors.
+
+use thiserror::Error;
+
+/// The result of a command.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The reasons why a command failed.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}\n\n{usage}", usage = crate::USAGE)]
+    Usage(String),
+    #[error("failed to read or write {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("invalid json: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid hex: {0}")]
+    Hex(String),
+    #[error("instance not found: {0}")]
+    InstanceNotFound(String),
+    #[error("some instances are invalid:\n{0}")]
+    InvalidInstances(String),
+    #[error("the linked list is invalid: {0}")]
+    InvalidList(&'static str),
+    #[error("invalid items snapshot: {0}")]
+    InvalidSnapshot(String),
+    #[error("the digest is {actual}, but {expected} is expected")]
+    DigestMismatch { expected: String, actual: String },
+    #[error(transparent)]
+    Client(#[from] ckb_registry_client::Error),
+}
+
+impl From<ckb_linked_list_tool::error::Error> for Error {
+    fn from(err: ckb_linked_list_tool::error::Error) -> Self {
+        use ckb_linked_list_tool::error::Error as ListError;
+        let reason = match err {
+            ListError::EmptyList => "the list is empty",
+            ListError::Discontinuous => "the items are discontinuous",
+            ListError::ReachLastTwice => "the last item is reached twice",
+            ListError::NextIsSelfItem => "an item points to itself",
+            ListError::Unreachable => "unreachable",
+        };
+        Self::InvalidList(reason)
+    }
+}
@@ -0,0 +1,232 @@
+//! Export the items of an instance to a canonical snapshot, and audit it
+//! offline.
+//!
+//! The items are sorted by keys, then by out points if a key is duplicated,
+//! so the same live cells always give the same snapshot, in either JSON or
+//! CSV. The digest covers the items only, so it's the same for both formats.
+
+use ckb_hash::new_blake2b;
+use ckb_jsonrpc_types as rpc;
+use ckb_linked_list_tool::{check_linked_list_with_unordered_items, types::ListItem};
+use ckb_registry_client::{Item, LiveCell};
+use ckb_types::{bytes::Bytes, packed, prelude::*, H256};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    args::{decode_hex, encode_hex},
+    error::{Error, Result},
+};
+
+/// The header of the CSV format.
+pub const CSV_HEADER: &str = "key,next,payload,tx_hash,index,capacity";
+
+/// An exported item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedItem {
+    pub key: rpc::JsonBytes,
+    pub next: rpc::JsonBytes,
+    pub payload: rpc::JsonBytes,
+    pub out_point: rpc::OutPoint,
+    pub capacity: rpc::Capacity,
+}
+
+/// The exported items of an instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemsSnapshot {
+    /// The type script of the instance, it's absent in CSV.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_script: Option<rpc::Script>,
+    pub items: Vec<ExportedItem>,
+    /// The digest when the snapshot is exported, it's absent in CSV.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<H256>,
+}
+
+/// The format of a snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Parses `json` or `csv`.
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(Error::Usage(format!("unknown format {value}"))),
+        }
+    }
+}
+
+impl ItemsSnapshot {
+    /// Exports the live cells of an instance, all cells should have valid
+    /// items.
+    pub fn export(type_script: &packed::Script, cells: &[LiveCell]) -> Result<Self> {
+        let mut items = cells
+            .iter()
+            .map(|cell| {
+                let item = Item::from_cell_data(&cell.data)?;
+                Ok(ExportedItem {
+                    key: rpc::JsonBytes::from_bytes(item.key),
+                    next: rpc::JsonBytes::from_bytes(item.next),
+                    payload: rpc::JsonBytes::from_bytes(item.payload),
+                    out_point: cell.out_point.clone().into(),
+                    capacity: cell.capacity().into(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        items.sort_by(|a, b| {
+            let out_point = |item: &ExportedItem| packed::OutPoint::from(item.out_point.clone());
+            (a.key.as_bytes(), out_point(a).as_slice())
+                .cmp(&(b.key.as_bytes(), out_point(b).as_slice()))
+        });
+        let mut snapshot = Self {
+            type_script: Some(type_script.clone().into()),
+            items,
+            digest: None,
+        };
+        snapshot.digest = Some(snapshot.digest());
+        Ok(snapshot)
+    }
+
+    /// Reads a snapshot in JSON or CSV, the format is detected by the
+    /// content.
+    pub fn import(content: &str) -> Result<Self> {
+        if content.trim_start().starts_with('{') {
+            Ok(serde_json::from_str(content)?)
+        } else {
+            Self::from_csv(content)
+        }
+    }
+
+    /// Writes the snapshot in the format.
+    pub fn to_text(&self, format: Format) -> Result<String> {
+        match format {
+            Format::Json => Ok(serde_json::to_string_pretty(self)? + "\n"),
+            Format::Csv => Ok(self.to_csv()),
+        }
+    }
+
+    /// The digest of the items.
+    ///
+    /// It's the blake2b hash of every item in the order of the snapshot: the
+    /// key, the next key and the payload with their lengths in u32, the out
+    /// point in molecule, and the capacity in u64, all integers are little
+    /// endian.
+    pub fn digest(&self) -> H256 {
+        let mut blake2b = new_blake2b();
+        for item in &self.items {
+            for bytes in [&item.key, &item.next, &item.payload] {
+                let bytes = bytes.as_bytes();
+                blake2b.update(&(bytes.len() as u32).to_le_bytes());
+                blake2b.update(bytes);
+            }
+            let out_point: packed::OutPoint = item.out_point.clone().into();
+            blake2b.update(out_point.as_slice());
+            blake2b.update(&item.capacity.value().to_le_bytes());
+        }
+        let mut digest = [0u8; 32];
+        blake2b.finalize(&mut digest);
+        digest.into()
+    }
+
+    /// Audits the snapshot: the items form exactly one complete ring, and
+    /// the recorded digest, if any, matches the items.
+    ///
+    /// Returns the digest.
+    pub fn audit(&self) -> Result<H256> {
+        let digest = self.digest();
+        if let Some(expected) = &self.digest {
+            check_digest(expected, &digest)?;
+        }
+        let items = self.items.iter().map(|item| {
+            (
+                item.key.clone().into_bytes(),
+                item.next.clone().into_bytes(),
+            )
+        });
+        let summary = check_linked_list_with_unordered_items(items, parse_item)?;
+        if !summary.is_complete() {
+            return Err(Error::InvalidList("the ring is not closed"));
+        }
+        Ok(digest)
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = format!("{CSV_HEADER}\n");
+        for item in &self.items {
+            let out_point: packed::OutPoint = item.out_point.clone().into();
+            let index: u32 = out_point.index().unpack();
+            csv.push_str(&format!(
+                "0x{},0x{},0x{},0x{},{index},{}\n",
+                encode_hex(item.key.as_bytes()),
+                encode_hex(item.next.as_bytes()),
+                encode_hex(item.payload.as_bytes()),
+                encode_hex(out_point.tx_hash().as_slice()),
+                item.capacity.value(),
+            ));
+        }
+        csv
+    }
+
+    fn from_csv(content: &str) -> Result<Self> {
+        let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+        if lines.next().map(str::trim) != Some(CSV_HEADER) {
+            return Err(Error::InvalidSnapshot(format!(
+                "the header should be {CSV_HEADER}"
+            )));
+        }
+        let items = lines
+            .enumerate()
+            .map(|(index, line)| {
+                parse_csv_row(line.trim()).ok_or_else(|| {
+                    Error::InvalidSnapshot(format!("invalid row {}: {line}", index + 1))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            type_script: None,
+            items,
+            digest: None,
+        })
+    }
+}
+
+/// Checks that the digest is the expected one.
+pub fn check_digest(expected: &H256, actual: &H256) -> Result<()> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Error::DigestMismatch {
+            expected: format!("{expected:#x}"),
+            actual: format!("{actual:#x}"),
+        })
+    }
+}
+
+fn parse_csv_row(line: &str) -> Option<ExportedItem> {
+    let fields = line.split(',').collect::<Vec<_>>();
+    let [key, next, payload, tx_hash, index, capacity] = fields[..] else {
+        return None;
+    };
+    let bytes = |value: &str| {
+        let hex = value.strip_prefix("0x")?;
+        decode_hex(hex).ok().map(Bytes::from)
+    };
+    let tx_hash = packed::Byte32::from_slice(&bytes(tx_hash)?).ok()?;
+    let index: u32 = index.parse().ok()?;
+    let capacity: u64 = capacity.parse().ok()?;
+    Some(ExportedItem {
+        key: rpc::JsonBytes::from_bytes(bytes(key)?),
+        next: rpc::JsonBytes::from_bytes(bytes(next)?),
+        payload: rpc::JsonBytes::from_bytes(bytes(payload)?),
+        out_point: packed::OutPoint::new(tx_hash, index).into(),
+        capacity: capacity.into(),
+    })
+}
+
+fn parse_item((curr, next): (Bytes, Bytes)) -> Result<ListItem<Bytes>> {
+    Ok(ListItem::new(curr, next))
+}
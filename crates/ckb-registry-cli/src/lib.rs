@@ -0,0 +1,74 @@
+//! A command-line tool to operate the instances of the [demo registry
+//! contract].
+//!
+//! The registry state is read from a JSON snapshot or a local node, the
+//! operations are planned by [`ckb_registry_client`] and checked with the
+//! linked list logic in [`ckb_linked_list_tool`], then the unsigned
+//! transactions are written in the mock transaction format.
+//!
+//! The items of an instance could be exported to a canonical snapshot, which
+//! is audited offline and compared by its digest, see [`export`].
+//!
+//! [demo registry contract]: ../../contracts/demo-linked-list-type
+
+mod args;
+mod commands;
+pub mod error;
+pub mod export;
+mod mock_tx;
+mod plan;
+pub mod state;
+
+pub use error::{Error, Result};
+pub use export::{ExportedItem, Format, ItemsSnapshot};
+pub use state::{Deployment, JsonLiveCell, Snapshot};
+
+use args::Args;
+
+/// The usage of the tool.
+pub const USAGE: &str = "\
+Usage: ckb-registry <COMMAND> [OPTIONS]
+
+Commands:
+  create    Create an instance         --items <KEY=PAYLOAD,..> [--instance-args <HEX>]
+  insert    Insert an item             --key <KEY> [--payload <PAYLOAD>]
+  remove    Remove an item             --key <KEY>
+  destroy   Destroy an instance
+  list      List the items of an instance
+  lookup    Look up a key              --key <KEY>
+  verify    Verify the rings of instances
+  export    Export the items           [--format <json|csv>] [--output <FILE>]
+  audit     Audit an exported snapshot --file <FILE> [--digest <HEX>]
+
+State options:
+  --snapshot <FILE>      Read the state from a JSON snapshot
+  --rpc <URL>            Read the state from a local node, with --deployment
+  --deployment <FILE>    The deployment of the registry contract in JSON
+  --args <HEX>           The type script args of the instance, it could be
+                         omitted if there is only one instance
+
+Transaction options:
+  --fee <SHANNONS>       The transaction fee, 100000 by default
+  --output <FILE>        Write the mock transaction to the file, instead of
+                         the standard output
+
+Keys and payloads are hex with the prefix 0x, otherwise UTF-8 text.
+";
+
+/// Runs a command, returns its output.
+pub fn run(args: &[String]) -> Result<String> {
+    let args = Args::parse(args)?;
+    match args.command.as_str() {
+        "create" => commands::create_instance(&args),
+        "insert" => commands::insert(&args),
+        "remove" => commands::remove(&args),
+        "destroy" => commands::destroy(&args),
+        "list" => commands::list(&args),
+        "lookup" => commands::lookup(&args),
+        "verify" => commands::verify(&args),
+        "export" => commands::export(&args),
+        "audit" => commands::audit(&args),
+        "help" | "--help" | "-h" => Ok(USAGE.to_owned()),
+        command => Err(Error::Usage(format!("unknown command {command}"))),
+    }
+}
@@ -0,0 +1,12 @@
+use std::{env, process};
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match ckb_registry_cli::run(&args) {
+        Ok(output) => print!("{output}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Write unsigned transactions in the mock transaction format, with all
+//! their inputs and cell deps resolved.
+
+use ckb_mock_tx_types::{MockInfo, MockInput, MockTransaction, ReprMockTransaction};
+use ckb_registry_client::{LiveCell, UnsignedTx};
+
+use crate::{
+    error::{Error, Result},
+    state::State,
+};
+
+/// The resolved inputs of a transaction.
+pub fn resolve_inputs(state: &State, tx: &UnsignedTx) -> Result<Vec<LiveCell>> {
+    tx.tx
+        .input_pts_iter()
+        .map(|out_point| {
+            state
+                .find_cell(&out_point)
+                .cloned()
+                .ok_or_else(|| Error::Usage(format!("the input {out_point} is not in the state")))
+        })
+        .collect()
+}
+
+/// Converts a transaction into a mock transaction.
+pub fn to_mock_tx(state: &State, tx: &UnsignedTx) -> Result<ReprMockTransaction> {
+    let inputs = resolve_inputs(state, tx)?
+        .into_iter()
+        .zip(tx.tx.inputs())
+        .map(|(cell, input)| MockInput {
+            input,
+            output: cell.output,
+            data: cell.data,
+            header: None,
+        })
+        .collect();
+    let mock_tx = MockTransaction {
+        mock_info: MockInfo {
+            inputs,
+            cell_deps: state.mock_cell_deps(),
+            header_deps: Vec::new(),
+            extensions: Vec::new(),
+        },
+        tx: tx.tx.data(),
+    };
+    Ok(mock_tx.into())
+}
@@ -0,0 +1,69 @@
+//! Check the planned transactions with the linked list logic, the same as the
+//! contract does.
+
+use ckb_linked_list_tool::{
+    check_linked_list_with_unordered_items,
+    types::{ListItem, ListItemsSummary},
+};
+use ckb_registry_client::{Item, LiveCell, UnsignedTx};
+use ckb_types::{bytes::Bytes, packed};
+
+use crate::error::{Error, Result};
+
+/// Checks that the cells of an instance form a complete ring, returns the
+/// count of the items.
+pub fn check_ring(cells: &[LiveCell]) -> Result<usize> {
+    let summary = summarize(cells.iter().map(|cell| cell.data.clone()))?
+        .ok_or(Error::InvalidList("the list is empty"))?;
+    if !summary.is_complete() {
+        return Err(Error::InvalidList("the ring is not closed"));
+    }
+    Ok(cells.len())
+}
+
+/// Checks that a transaction keeps the ring of the instance: the consumed
+/// items and the created items cover the same part of the ring, or a whole
+/// ring is created or destroyed.
+pub fn check_tx(type_script: &packed::Script, inputs: &[LiveCell], tx: &UnsignedTx) -> Result<()> {
+    let is_registry =
+        |output: &packed::CellOutput| output.type_().to_opt().as_ref() == Some(type_script);
+    let consumed = summarize(
+        inputs
+            .iter()
+            .filter(|cell| is_registry(&cell.output))
+            .map(|cell| cell.data.clone()),
+    )?;
+    let created = summarize(
+        tx.tx
+            .outputs_with_data_iter()
+            .filter(|(output, _)| is_registry(output))
+            .map(|(_, data)| data),
+    )?;
+    let is_kept = match (consumed, created) {
+        (Some(consumed), Some(created)) => consumed == created,
+        (None, Some(summary)) | (Some(summary), None) => summary.is_complete(),
+        (None, None) => false,
+    };
+    if is_kept {
+        Ok(())
+    } else {
+        Err(Error::InvalidList("the ring is changed"))
+    }
+}
+
+// Returns `None` if there are no items.
+fn summarize<I>(data: I) -> Result<Option<ListItemsSummary<Bytes>>>
+where
+    I: IntoIterator<Item = Bytes>,
+{
+    let mut data = data.into_iter().peekable();
+    if data.peek().is_none() {
+        return Ok(None);
+    }
+    check_linked_list_with_unordered_items(data, parse_item).map(Some)
+}
+
+fn parse_item(data: Bytes) -> Result<ListItem<Bytes>> {
+    let item = Item::from_cell_data(&data)?;
+    Ok(ListItem::new(item.key, item.next))
+}
@@ -0,0 +1,279 @@
+//! The registry state, loaded from a JSON snapshot or a local node.
+
+use std::{collections::BTreeMap, fs};
+
+use ckb_jsonrpc_types as rpc;
+use ckb_mock_tx_types::{MockCellDep, ReprMockCellDep};
+use ckb_registry_client::{
+    rpc::IndexerRpcClient, ContractRef, Error as ClientError, Funding, LiveCell, Registry,
+    UnsignedTx,
+};
+use ckb_types::{bytes::Bytes, packed, H256};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    args::{decode_hex, encode_hex, Args},
+    error::{Error, Result},
+};
+
+/// The deployed registry contract, and the lock of the operator.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Deployment {
+    /// The code hash of the registry contract.
+    pub code_hash: H256,
+    /// The hash type of the registry contract.
+    pub hash_type: rpc::ScriptHashType,
+    /// The cell deps with their cells, for the registry contract, the lock,
+    /// and the config cells if any.
+    pub cell_deps: Vec<ReprMockCellDep>,
+    /// The lock of new items and the change.
+    pub lock: rpc::Script,
+}
+
+/// A live cell in JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonLiveCell {
+    pub out_point: rpc::OutPoint,
+    pub output: rpc::CellOutput,
+    pub data: rpc::JsonBytes,
+}
+
+/// A snapshot of the registry state.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub deployment: Deployment,
+    /// The live cells of the registry instances.
+    pub cells: Vec<JsonLiveCell>,
+    /// The live cells to pay for transactions.
+    #[serde(default)]
+    pub funding: Vec<JsonLiveCell>,
+}
+
+/// The loaded registry state.
+#[derive(Clone)]
+pub struct State {
+    pub deployment: Deployment,
+    pub cells: Vec<LiveCell>,
+    pub funding: Vec<LiveCell>,
+}
+
+impl From<JsonLiveCell> for LiveCell {
+    fn from(cell: JsonLiveCell) -> Self {
+        Self {
+            out_point: cell.out_point.into(),
+            output: cell.output.into(),
+            data: cell.data.into_bytes(),
+        }
+    }
+}
+
+impl From<LiveCell> for JsonLiveCell {
+    fn from(cell: LiveCell) -> Self {
+        Self {
+            out_point: cell.out_point.into(),
+            output: cell.output.into(),
+            data: rpc::JsonBytes::from_bytes(cell.data),
+        }
+    }
+}
+
+impl Deployment {
+    /// The type script of an instance.
+    pub fn type_script(&self, args: Bytes) -> packed::Script {
+        rpc::Script {
+            code_hash: self.code_hash.clone(),
+            hash_type: self.hash_type.clone(),
+            args: rpc::JsonBytes::from_bytes(args),
+        }
+        .into()
+    }
+}
+
+impl State {
+    /// Loads the state from `--snapshot <file>`, or from `--rpc <url>` with
+    /// `--deployment <file>`.
+    pub fn load(args: &Args) -> Result<Self> {
+        match (args.get("snapshot"), args.get("rpc")) {
+            (Some(path), None) => {
+                let snapshot: Snapshot = read_json(path)?;
+                Ok(Self {
+                    deployment: snapshot.deployment,
+                    cells: snapshot.cells.into_iter().map(Into::into).collect(),
+                    funding: snapshot.funding.into_iter().map(Into::into).collect(),
+                })
+            }
+            (None, Some(url)) => {
+                let deployment: Deployment = read_json(args.require("deployment")?)?;
+                let client = IndexerRpcClient::new(url)?;
+                let args_prefix = args.get("args").map(parse_args).transpose()?;
+                let script = deployment.type_script(args_prefix.unwrap_or_default());
+                let cells = client
+                    .get_registry_cells(
+                        &script.code_hash(),
+                        script.hash_type(),
+                        &script.args().raw_data(),
+                    )?
+                    .into_iter()
+                    .map(|cell| cell.cell)
+                    .collect();
+                let lock = deployment.lock.clone().into();
+                let funding = client
+                    .get_free_cells(&lock)?
+                    .into_iter()
+                    .map(|cell| cell.cell)
+                    .collect();
+                Ok(Self {
+                    deployment,
+                    cells,
+                    funding,
+                })
+            }
+            _ => Err(Error::Usage(
+                "either --snapshot or --rpc is required".to_owned(),
+            )),
+        }
+    }
+
+    /// The registry contract.
+    pub fn contract(&self) -> ContractRef {
+        let script = self.type_script(Bytes::new());
+        ContractRef {
+            code_hash: script.code_hash(),
+            hash_type: script.hash_type(),
+            cell_deps: self.cell_deps(),
+        }
+    }
+
+    /// The cell deps of all transactions.
+    pub fn cell_deps(&self) -> Vec<packed::CellDep> {
+        self.deployment
+            .cell_deps
+            .iter()
+            .map(|dep| dep.cell_dep.clone().into())
+            .collect()
+    }
+
+    /// The lock of new items and the change.
+    pub fn lock(&self) -> packed::Script {
+        self.deployment.lock.clone().into()
+    }
+
+    /// The type script of an instance.
+    pub fn type_script(&self, args: Bytes) -> packed::Script {
+        self.deployment.type_script(args)
+    }
+
+    /// The live cells of all instances, by their script args.
+    pub fn instances(&self) -> BTreeMap<Bytes, Vec<LiveCell>> {
+        let contract = self.type_script(Bytes::new());
+        let mut instances = BTreeMap::<_, Vec<_>>::new();
+        for cell in &self.cells {
+            if let Some(script) = cell.output.type_().to_opt() {
+                if script.code_hash() == contract.code_hash()
+                    && script.hash_type() == contract.hash_type()
+                {
+                    let args = script.args().raw_data();
+                    instances.entry(args).or_default().push(cell.clone());
+                }
+            }
+        }
+        instances
+    }
+
+    /// The instance selected by `--args`, which could be omitted if there is
+    /// only one instance.
+    pub fn instance(&self, args: &Args) -> Result<(Bytes, Vec<LiveCell>)> {
+        let mut instances = self.instances();
+        match args.get("args") {
+            Some(value) => {
+                let type_args = parse_args(value)?;
+                let cells = instances
+                    .remove(&type_args)
+                    .ok_or_else(|| Error::InstanceNotFound(value.to_owned()))?;
+                Ok((type_args, cells))
+            }
+            None if instances.len() == 1 => Ok(instances.pop_first().expect("checked")),
+            None => Err(Error::Usage(format!(
+                "--args is required, since there are {} instances",
+                instances.len()
+            ))),
+        }
+    }
+
+    /// The registry selected by `--args`.
+    pub fn registry(&self, args: &Args) -> Result<Registry> {
+        let (type_args, cells) = self.instance(args)?;
+        let registry = Registry::new(self.type_script(type_args), self.cell_deps(), cells)?;
+        Ok(registry)
+    }
+
+    /// Builds a transaction with as few funding cells as possible.
+    pub fn build_with_funding<F>(&self, fee: u64, build: F) -> Result<UnsignedTx>
+    where
+        F: Fn(&Funding) -> ckb_registry_client::Result<UnsignedTx>,
+    {
+        let mut last_err = ClientError::NoInputs;
+        for count in 0..=self.funding.len() {
+            let funding = Funding {
+                cells: self.funding[..count].to_vec(),
+                change_lock: self.lock(),
+                fee,
+            };
+            match build(&funding) {
+                Ok(tx) => return Ok(tx),
+                Err(err @ (ClientError::NoInputs | ClientError::InsufficientCapacity { .. })) => {
+                    last_err = err;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Err(last_err.into())
+    }
+
+    /// Finds a live cell, in the registry cells or the funding cells.
+    pub fn find_cell(&self, out_point: &packed::OutPoint) -> Option<&LiveCell> {
+        self.cells
+            .iter()
+            .chain(self.funding.iter())
+            .find(|cell| &cell.out_point == out_point)
+    }
+
+    /// The cell deps with their cells.
+    pub fn mock_cell_deps(&self) -> Vec<MockCellDep> {
+        self.deployment
+            .cell_deps
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect()
+    }
+}
+
+/// Parses the script args of an instance, in hex.
+pub fn parse_args(value: &str) -> Result<Bytes> {
+    let hex = value.strip_prefix("0x").unwrap_or(value);
+    decode_hex(hex).map(Bytes::from)
+}
+
+/// Formats the script args of an instance.
+pub fn format_args(args: &[u8]) -> String {
+    format!("0x{}", encode_hex(args))
+}
+
+/// Reads a JSON file.
+pub fn read_json<T: serde::de::DeserializeOwned>(path: &str) -> Result<T> {
+    let json = fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Writes a JSON file.
+pub fn write_json<T: Serialize>(path: &str, value: &T) -> Result<()> {
+    let json = serde_json::to_string_pretty(value)?;
+    fs::write(path, json).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
@@ -0,0 +1,186 @@
+//! Benchmarks the batch scheduler on synthetic key distributions.
+//!
+//! Run with `cargo bench -p ckb-registry-client --bench schedule`. For each
+//! distribution, it reports the time to schedule and build the transactions,
+//! and how many keys could land in the first block, compared with one
+//! transaction per key.
+
+use std::time::{Duration, Instant};
+
+use ckb_registry_client::{Funding, Item, LiveCell, Registry, Schedule};
+use ckb_types::{bytes::Bytes, core::Capacity, packed, prelude::*};
+
+const RING_SIZE: u64 = 10_000;
+const REQUESTS: usize = 500;
+const MAX_KEYS_PER_TX: usize = 16;
+const ITERATIONS: u32 = 20;
+
+// A xorshift generator, so the distributions are the same in every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+// The keys of the ring are spread evenly in the u64 space.
+fn ring_key(index: u64) -> Bytes {
+    key(index * (u64::MAX / RING_SIZE))
+}
+
+fn key(value: u64) -> Bytes {
+    Bytes::copy_from_slice(&value.to_be_bytes())
+}
+
+fn build_registry() -> Registry {
+    let type_script = packed::Script::new_builder()
+        .args(Bytes::from_static(b"bench").pack())
+        .build();
+    let cells = (0..RING_SIZE)
+        .map(|index| {
+            let item = Item::new(&ring_key(index), &ring_key((index + 1) % RING_SIZE), b"");
+            let output = packed::CellOutput::new_builder()
+                .capacity(Capacity::shannons(1_000 * 100_000_000).pack())
+                .type_(Some(type_script.clone()).pack())
+                .build();
+            LiveCell {
+                out_point: packed::OutPoint::new(Default::default(), index as u32),
+                output,
+                data: item.to_cell_data(),
+            }
+        })
+        .collect();
+    Registry::new(type_script, Vec::new(), cells).expect("registry")
+}
+
+fn funding() -> Funding {
+    let output = packed::CellOutput::new_builder()
+        .capacity(Capacity::shannons(100_000 * 100_000_000).pack())
+        .build();
+    Funding {
+        cells: vec![LiveCell {
+            out_point: packed::OutPoint::new(Default::default(), u32::MAX),
+            output,
+            data: Bytes::new(),
+        }],
+        change_lock: packed::Script::default(),
+        fee: 1_000,
+    }
+}
+
+fn uniform(rng: &mut Rng) -> Vec<Bytes> {
+    (0..REQUESTS).map(|_| key(rng.next())).collect()
+}
+
+// Most keys fall into a few gaps of the ring.
+fn hot_spots(rng: &mut Rng) -> Vec<Bytes> {
+    let spots = (0..4).map(|_| rng.below(RING_SIZE)).collect::<Vec<_>>();
+    let gap = u64::MAX / RING_SIZE;
+    (0..REQUESTS)
+        .map(|_| {
+            if rng.below(10) < 8 {
+                let spot = spots[rng.below(spots.len() as u64) as usize];
+                key(spot * gap + 1 + rng.below(gap - 1))
+            } else {
+                key(rng.next())
+            }
+        })
+        .collect()
+}
+
+// The keys share a long prefix, like names under the same domain.
+fn shared_prefix(rng: &mut Rng) -> Vec<Bytes> {
+    (0..REQUESTS)
+        .map(|_| {
+            let mut bytes = b"\x80org.example.".to_vec();
+            bytes.extend_from_slice(&rng.below(1 << 20).to_be_bytes());
+            Bytes::from(bytes)
+        })
+        .collect()
+}
+
+// All keys are greater than the last key, so they share its item.
+fn after_the_last(rng: &mut Rng) -> Vec<Bytes> {
+    (0..REQUESTS)
+        .map(|_| {
+            let mut bytes = vec![0xff; 9];
+            bytes.extend_from_slice(&rng.next().to_be_bytes());
+            Bytes::from(bytes)
+        })
+        .collect()
+}
+
+fn bench(name: &str, registry: &Registry, keys: Vec<Bytes>) {
+    let requests = keys
+        .into_iter()
+        .map(|key| (key, Bytes::from_static(b"payload")))
+        .collect::<Vec<_>>();
+    let funding = funding();
+    let lock = packed::Script::default();
+
+    let mut schedule = Schedule::default();
+    let mut scheduling = Duration::ZERO;
+    let mut building = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        schedule = Schedule::new(registry, requests.clone(), MAX_KEYS_PER_TX);
+        scheduling += start.elapsed();
+        // Only the first round is built against the current ring, the later
+        // rounds need the items created by it.
+        let start = Instant::now();
+        for batch in &schedule.rounds[0] {
+            registry
+                .insert_batch(&batch.items, lock.clone(), &funding)
+                .expect("insert");
+        }
+        building += start.elapsed();
+    }
+
+    // With one transaction per key, only one of the keys sharing a
+    // predecessor could land in the first block.
+    let mut predecessors = requests
+        .iter()
+        .filter(|(key, _)| registry.get(key).is_none())
+        .map(|(key, _)| registry.predecessor(key).item.key.clone())
+        .collect::<Vec<_>>();
+    predecessors.sort_unstable();
+    predecessors.dedup();
+
+    println!(
+        "{name:<16} {:>6} {:>5} {:>6} {:>9} {:>9} {:>11.1?} {:>11.1?}",
+        schedule.keys_count(),
+        schedule.tx_count(),
+        schedule.rounds.len(),
+        schedule.rounds[0]
+            .iter()
+            .map(|batch| batch.items.len())
+            .sum::<usize>(),
+        predecessors.len(),
+        scheduling / ITERATIONS,
+        building / ITERATIONS,
+    );
+}
+
+fn main() {
+    let registry = build_registry();
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    println!(
+        "ring: {RING_SIZE} items, {REQUESTS} requests, at most {MAX_KEYS_PER_TX} keys in a tx\n"
+    );
+    println!(
+        "{:<16} {:>6} {:>5} {:>6} {:>9} {:>9} {:>11} {:>11}",
+        "distribution", "keys", "txs", "rounds", "1st block", "naive", "schedule", "build"
+    );
+    bench("uniform", &registry, uniform(&mut rng));
+    bench("hot spots", &registry, hot_spots(&mut rng));
+    bench("shared prefix", &registry, shared_prefix(&mut rng));
+    bench("after the last", &registry, after_the_last(&mut rng));
+}
@@ -0,0 +1,509 @@
+use std::collections::HashSet;
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, TransactionBuilder},
+    packed,
+    prelude::*,
+};
+use demo_linked_list_lib::types;
+
+use crate::{
+    error::{Error, Result},
+    types::{Funding, Item, ItemCell, LiveCell, UnsignedTx},
+    utils::calculate_unique_id,
+};
+
+/// A deployed contract.
+#[derive(Debug, Clone)]
+pub struct ContractRef {
+    /// The code hash of the contract.
+    pub code_hash: packed::Byte32,
+    /// The hash type of the contract.
+    pub hash_type: packed::Byte,
+    /// The cell deps to run the contract.
+    pub cell_deps: Vec<packed::CellDep>,
+}
+
+/// The config cell to create with a registry instance.
+#[derive(Debug, Clone)]
+pub struct ConfigRequest {
+    /// The config contract.
+    pub contract: ContractRef,
+    /// The lock of the config cell.
+    pub lock: packed::Script,
+    /// The config.
+    pub config: types::RegistryConfig,
+}
+
+/// A registry instance to create.
+#[derive(Debug, Clone)]
+pub struct CreateRequest {
+    /// The registry contract.
+    pub contract: ContractRef,
+    /// The instance args, the config type hash is overwritten if a config cell
+    /// is created together.
+    pub instance_args: types::InstanceArgs,
+    /// The keys and payloads of the initial items, at least 2 items.
+    pub items: Vec<(Bytes, Bytes)>,
+    /// The lock of the item cells.
+    pub lock: packed::Script,
+    /// The config cell to create, if any.
+    pub config: Option<ConfigRequest>,
+}
+
+/// A live registry instance, the items are sorted by keys.
+#[derive(Debug, Clone)]
+pub struct Registry {
+    type_script: packed::Script,
+    cell_deps: Vec<packed::CellDep>,
+    items: Vec<ItemCell>,
+}
+
+/// Builds a transaction to create a registry instance.
+///
+/// The unique ID is calculated from the first funding cell, the item cells are
+/// the first outputs, then the config cell if any.
+pub fn create(request: CreateRequest, funding: &Funding) -> Result<UnsignedTx> {
+    let first_input = funding.cells.first().ok_or(Error::NoInputs)?.to_input();
+    let CreateRequest {
+        contract,
+        mut instance_args,
+        mut items,
+        lock,
+        config,
+    } = request;
+    if items.len() < 2 {
+        return Err(Error::TooFewItems);
+    }
+    items.sort_by(|a, b| a.0.cmp(&b.0));
+    if items.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return Err(Error::KeyExists);
+    }
+
+    let mut cell_deps = contract.cell_deps.clone();
+    let mut extra_outputs = Vec::new();
+    if let Some(config) = config {
+        let type_id = calculate_unique_id(&first_input, items.len());
+        let config_type = build_script(&config.contract, Bytes::copy_from_slice(&type_id));
+        instance_args =
+            instance_args.with_config_type_hash(config_type.calc_script_hash().unpack().0);
+        let output = packed::CellOutput::new_builder()
+            .lock(config.lock)
+            .type_(Some(config_type).pack())
+            .build();
+        extra_outputs.push(with_occupied_capacity(output, config.config.as_bytes())?);
+        cell_deps.extend(config.contract.cell_deps);
+    }
+
+    let unique_id = calculate_unique_id(&first_input, 0);
+    let mut args = unique_id.to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    let type_script = build_script(&contract, Bytes::from(args));
+
+    let mut outputs = Vec::with_capacity(items.len() + extra_outputs.len());
+    let mut inserted = Vec::with_capacity(items.len());
+    for (index, (key, payload)) in items.iter().enumerate() {
+        let next = &items[(index + 1) % items.len()].0;
+        let item = Item::new(key, next, payload);
+        let output = packed::CellOutput::new_builder()
+            .lock(lock.clone())
+            .type_(Some(type_script.clone()).pack())
+            .build();
+        outputs.push(with_occupied_capacity(output, item.to_cell_data())?);
+        inserted.push((key.clone(), index));
+    }
+    outputs.extend(extra_outputs);
+
+    let witness = build_witness(None, Some(build_events(&inserted, &[], &[])));
+    build_tx(&[], outputs, witness, cell_deps, funding)
+}
+
+impl Registry {
+    /// Collects the live items of an instance, the cells which don't use the
+    /// type script are ignored.
+    ///
+    /// The cell deps should include the registry contract, and the config cell
+    /// if the instance has one.
+    pub fn new(
+        type_script: packed::Script,
+        cell_deps: Vec<packed::CellDep>,
+        cells: Vec<LiveCell>,
+    ) -> Result<Self> {
+        let mut items = Vec::new();
+        for cell in cells {
+            if cell.output.type_().to_opt().as_ref() == Some(&type_script) {
+                items.push(ItemCell::from_live_cell(cell)?);
+            }
+        }
+        items.sort_by(|a, b| a.item.key.cmp(&b.item.key));
+        let is_complete = items.len() >= 2
+            && items
+                .iter()
+                .enumerate()
+                .all(|(index, cell)| cell.item.next == items[(index + 1) % items.len()].item.key);
+        if !is_complete {
+            return Err(Error::IncompleteList);
+        }
+        Ok(Self {
+            type_script,
+            cell_deps,
+            items,
+        })
+    }
+
+    /// The type script of the instance.
+    pub fn type_script(&self) -> &packed::Script {
+        &self.type_script
+    }
+
+    /// The items, sorted by keys.
+    pub fn items(&self) -> &[ItemCell] {
+        &self.items
+    }
+
+    /// Finds an item by its key.
+    pub fn get(&self, key: &[u8]) -> Option<&ItemCell> {
+        self.position(key).map(|index| &self.items[index])
+    }
+
+    /// The item which covers the key: the one whose key is the largest one
+    /// less than the key, or the last item if the key is less than all keys.
+    ///
+    /// That's the item to update when the key is inserted.
+    pub fn predecessor(&self, key: &[u8]) -> &ItemCell {
+        let index = self
+            .items
+            .partition_point(|cell| cell.item.key.as_ref() < key);
+        &self.items[(index + self.items.len() - 1) % self.items.len()]
+    }
+
+    /// Applies a transaction, the consumed items are dropped and the created
+    /// items are added.
+    pub fn apply(&mut self, tx: &UnsignedTx) -> Result<()> {
+        let consumed = tx.tx.input_pts_iter().collect::<HashSet<_>>();
+        let mut cells = self
+            .items
+            .drain(..)
+            .filter(|cell| !consumed.contains(&cell.cell.out_point))
+            .map(|cell| cell.cell)
+            .collect::<Vec<_>>();
+        cells.extend(tx.live_cells());
+        *self = Self::new(self.type_script.clone(), self.cell_deps.clone(), cells)?;
+        Ok(())
+    }
+
+    /// Builds a transaction to insert an item with the lock.
+    ///
+    /// Only the predecessor is consumed, and its `next` is updated.
+    pub fn insert(
+        &self,
+        key: &[u8],
+        payload: &[u8],
+        lock: packed::Script,
+        funding: &Funding,
+    ) -> Result<UnsignedTx> {
+        let items = [(Bytes::copy_from_slice(key), Bytes::copy_from_slice(payload))];
+        self.insert_batch(&items, lock, funding)
+    }
+
+    /// Builds a transaction to insert items with the lock, all keys should
+    /// share the same predecessor.
+    ///
+    /// Only the predecessor is consumed, the new items follow it in the order
+    /// of the ring.
+    pub fn insert_batch(
+        &self,
+        items: &[(Bytes, Bytes)],
+        lock: packed::Script,
+        funding: &Funding,
+    ) -> Result<UnsignedTx> {
+        let first_key = &items.first().ok_or(Error::EmptyBatch)?.0;
+        let prev = self.predecessor(first_key);
+        let mut sorted = Vec::with_capacity(items.len());
+        for (key, payload) in items {
+            if self.position(key).is_some() {
+                return Err(Error::KeyExists);
+            }
+            if self.predecessor(key).cell.out_point != prev.cell.out_point {
+                return Err(Error::MixedPredecessors);
+            }
+            sorted.push((key.clone(), payload.clone()));
+        }
+        // The keys after the predecessor go first, then the wrapped keys
+        // which are less than the first key of the ring.
+        sorted.sort_by(|a, b| (a.0 < prev.item.key, &a.0).cmp(&(b.0 < prev.item.key, &b.0)));
+        if sorted.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(Error::KeyExists);
+        }
+
+        let mut prev_item = prev.item.clone();
+        let mut outputs = Vec::with_capacity(sorted.len() + 1);
+        let mut inserted = Vec::with_capacity(sorted.len());
+        let mut new_items = Vec::with_capacity(sorted.len());
+        for (index, (key, payload)) in sorted.iter().enumerate() {
+            let next = match sorted.get(index + 1) {
+                Some((next, _)) => next,
+                None => &prev_item.next,
+            };
+            new_items.push(Item::new(key, next, payload));
+            inserted.push((key.clone(), index + 1));
+        }
+        prev_item.next = sorted[0].0.clone();
+        outputs.push(self.rebuild_output(prev, &prev_item)?);
+        for item in &new_items {
+            outputs.push(self.build_output(lock.clone(), item)?);
+        }
+        let keys = sorted.into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+        let action = types::RegistryActionUnion::Insert(
+            types::Insert::new_builder().keys(build_keys(&keys)).build(),
+        );
+        let events = build_events(&inserted, &[], &[]);
+        let witness = build_witness(Some(action), Some(events));
+        build_tx(
+            &[&prev.cell],
+            outputs,
+            witness,
+            self.cell_deps.clone(),
+            funding,
+        )
+    }
+
+    /// Builds a transaction to remove an item, its capacity goes to the
+    /// change.
+    ///
+    /// The item and its predecessor are consumed.
+    pub fn remove(&self, key: &[u8], funding: &Funding) -> Result<UnsignedTx> {
+        let index = self.position(key).ok_or(Error::KeyNotFound)?;
+        if self.items.len() <= 2 {
+            return Err(Error::TooFewItems);
+        }
+        let removed = &self.items[index];
+        let prev = self.predecessor(key);
+        let mut prev_item = prev.item.clone();
+        prev_item.next = removed.item.next.clone();
+
+        let outputs = vec![self.rebuild_output(prev, &prev_item)?];
+        let keys = [removed.item.key.clone()];
+        let action = types::RegistryActionUnion::Remove(
+            types::Remove::new_builder().keys(build_keys(&keys)).build(),
+        );
+        let events = build_events(&[], &[(removed.item.key.clone(), 1)], &[]);
+        let witness = build_witness(Some(action), Some(events));
+        build_tx(
+            &[&prev.cell, &removed.cell],
+            outputs,
+            witness,
+            self.cell_deps.clone(),
+            funding,
+        )
+    }
+
+    /// Builds a transaction to modify the payload of an item.
+    pub fn modify(&self, key: &[u8], payload: &[u8], funding: &Funding) -> Result<UnsignedTx> {
+        let index = self.position(key).ok_or(Error::KeyNotFound)?;
+        let cell = &self.items[index];
+        let mut item = cell.item.clone();
+        item.payload = Bytes::copy_from_slice(payload);
+
+        let outputs = vec![self.rebuild_output(cell, &item)?];
+        let keys = [item.key.clone()];
+        let action = types::RegistryActionUnion::ModifyPayload(
+            types::ModifyPayload::new_builder()
+                .keys(build_keys(&keys))
+                .build(),
+        );
+        let events = build_events(&[], &[], &[(item.key, 0)]);
+        let witness = build_witness(Some(action), Some(events));
+        build_tx(
+            &[&cell.cell],
+            outputs,
+            witness,
+            self.cell_deps.clone(),
+            funding,
+        )
+    }
+
+    /// Builds a transaction to destroy the instance, all items are consumed.
+    ///
+    /// If the destroy is time locked, the config cell should be consumed with
+    /// a `since` and updated, which is not supported here.
+    pub fn destroy(&self, funding: &Funding) -> Result<UnsignedTx> {
+        let inputs = self.items.iter().map(|cell| &cell.cell).collect::<Vec<_>>();
+        build_tx(&inputs, Vec::new(), None, self.cell_deps.clone(), funding)
+    }
+
+    fn position(&self, key: &[u8]) -> Option<usize> {
+        self.items
+            .binary_search_by(|cell| cell.item.key.as_ref().cmp(key))
+            .ok()
+    }
+
+    fn build_output(
+        &self,
+        lock: packed::Script,
+        item: &Item,
+    ) -> Result<(packed::CellOutput, Bytes)> {
+        let output = packed::CellOutput::new_builder()
+            .lock(lock)
+            .type_(Some(self.type_script.clone()).pack())
+            .build();
+        with_occupied_capacity(output, item.to_cell_data())
+    }
+
+    // Keep the lock, and the capacity if it's still enough.
+    fn rebuild_output(&self, cell: &ItemCell, item: &Item) -> Result<(packed::CellOutput, Bytes)> {
+        let (output, data) = self.build_output(cell.cell.output.lock(), item)?;
+        let capacity = cell.cell.capacity().max(output.capacity().unpack());
+        let output = output.as_builder().capacity(capacity.pack()).build();
+        Ok((output, data))
+    }
+}
+
+fn build_script(contract: &ContractRef, args: Bytes) -> packed::Script {
+    packed::Script::new_builder()
+        .code_hash(contract.code_hash.clone())
+        .hash_type(contract.hash_type)
+        .args(args.pack())
+        .build()
+}
+
+fn with_occupied_capacity(
+    output: packed::CellOutput,
+    data: Bytes,
+) -> Result<(packed::CellOutput, Bytes)> {
+    let data_capacity = Capacity::bytes(data.len()).map_err(|_| Error::CapacityOverflow)?;
+    let capacity = output
+        .occupied_capacity(data_capacity)
+        .map_err(|_| Error::CapacityOverflow)?;
+    let output = output.as_builder().capacity(capacity.pack()).build();
+    Ok((output, data))
+}
+
+fn build_keys(keys: &[Bytes]) -> types::BytesVec {
+    let slices = keys.iter().map(|key| key.as_ref()).collect::<Vec<_>>();
+    types::BytesVec::new_from_raw_slices(&slices)
+}
+
+// The events are sorted by keys, as the contract requires.
+fn build_events(
+    inserted: &[(Bytes, usize)],
+    removed: &[(Bytes, usize)],
+    modified: &[(Bytes, usize)],
+) -> types::RegistryEvents {
+    let build_key_events = |events: &[(Bytes, usize)]| {
+        let mut events = events.to_vec();
+        events.sort_by(|a, b| a.0.cmp(&b.0));
+        types::KeyEventVec::new_builder()
+            .extend(
+                events
+                    .iter()
+                    .map(|(key, index)| types::KeyEvent::new_from_raw_parts(key, *index as u32)),
+            )
+            .build()
+    };
+    types::RegistryEvents::new_builder()
+        .inserted(build_key_events(inserted))
+        .removed(build_key_events(removed))
+        .modified(build_key_events(modified))
+        .build()
+}
+
+// The action is in the `input_type`, and the events are in the `output_type`,
+// of the first witness, since the registry cells are the first cells.
+fn build_witness(
+    action: Option<types::RegistryActionUnion>,
+    events: Option<types::RegistryEvents>,
+) -> Option<packed::WitnessArgs> {
+    if action.is_none() && events.is_none() {
+        return None;
+    }
+    let input_type = action.map(|action| {
+        types::RegistryAction::new_builder()
+            .set(action)
+            .build()
+            .as_bytes()
+    });
+    let output_type = events.map(|events| {
+        types::RegistryOutput::new_builder()
+            .events(
+                types::RegistryEventsOpt::new_builder()
+                    .set(Some(events))
+                    .build(),
+            )
+            .build()
+            .as_bytes()
+    });
+    let witness = packed::WitnessArgs::new_builder()
+        .input_type(input_type.pack())
+        .output_type(output_type.pack())
+        .build();
+    Some(witness)
+}
+
+// The registry cells are put first, then the funding cells, and the change
+// cell is the last output.
+fn build_tx(
+    inputs: &[&LiveCell],
+    outputs: Vec<(packed::CellOutput, Bytes)>,
+    witness: Option<packed::WitnessArgs>,
+    cell_deps: Vec<packed::CellDep>,
+    funding: &Funding,
+) -> Result<UnsignedTx> {
+    let all_inputs = inputs
+        .iter()
+        .copied()
+        .chain(funding.cells.iter())
+        .collect::<Vec<_>>();
+    if all_inputs.is_empty() {
+        return Err(Error::NoInputs);
+    }
+    let available = sum_capacity(all_inputs.iter().map(|cell| cell.capacity()))?;
+    let outputs_capacity =
+        sum_capacity(outputs.iter().map(|(output, _)| output.capacity().unpack()))?;
+    let required = outputs_capacity
+        .checked_add(funding.fee)
+        .ok_or(Error::CapacityOverflow)?;
+    let change = available
+        .checked_sub(required)
+        .ok_or(Error::InsufficientCapacity {
+            required,
+            available,
+        })?;
+
+    let mut builder = TransactionBuilder::default()
+        .cell_deps(cell_deps)
+        .inputs(all_inputs.iter().map(|cell| cell.to_input()))
+        .outputs(outputs.iter().map(|(output, _)| output.clone()))
+        .outputs_data(outputs.iter().map(|(_, data)| data.pack()));
+    if change > 0 {
+        let (change_output, _) = with_occupied_capacity(
+            packed::CellOutput::new_builder()
+                .lock(funding.change_lock.clone())
+                .build(),
+            Bytes::new(),
+        )?;
+        let occupied: u64 = change_output.capacity().unpack();
+        if change < occupied {
+            return Err(Error::InsufficientCapacity {
+                required: required + occupied,
+                available,
+            });
+        }
+        builder = builder
+            .output(change_output.as_builder().capacity(change.pack()).build())
+            .output_data(Bytes::new().pack());
+    }
+    let witnesses = (0..all_inputs.len()).map(|index| match (index, &witness) {
+        (0, Some(witness)) => witness.as_bytes().pack(),
+        _ => Bytes::new().pack(),
+    });
+    let tx = builder.witnesses(witnesses).build();
+    Ok(UnsignedTx { tx, change })
+}
+
+fn sum_capacity(mut capacities: impl Iterator<Item = u64>) -> Result<u64> {
+    capacities.try_fold(0u64, |sum, capacity| {
+        sum.checked_add(capacity).ok_or(Error::CapacityOverflow)
+    })
+}
@@ -0,0 +1,39 @@
+//! Errors.
+
+use thiserror::Error;
+
+/// The result of building a transaction.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The reasons why a transaction could not be built.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("the data of the cell is not a valid item")]
+    InvalidItemData,
+    #[error("the cells don't form a complete list")]
+    IncompleteList,
+    #[error("the key already exists")]
+    KeyExists,
+    #[error("the key doesn't exist")]
+    KeyNotFound,
+    #[error("the list should have at least 2 items")]
+    TooFewItems,
+    #[error("at least 1 input is required to calculate the unique ID")]
+    NoInputs,
+    #[error("the capacity is not enough, requires {required} but only has {available}")]
+    InsufficientCapacity { required: u64, available: u64 },
+    #[error("the capacity overflows")]
+    CapacityOverflow,
+    #[error("the transaction doesn't insert an item")]
+    NotInsert,
+    #[error("no items to insert")]
+    EmptyBatch,
+    #[error("the keys don't share the same predecessor")]
+    MixedPredecessors,
+    #[error("the input {0} is spent by a pending transaction")]
+    SpentByPending(ckb_types::packed::OutPoint),
+    #[error("the pending transaction is not found")]
+    PendingTxNotFound,
+    #[error("the rpc request failed: {0}")]
+    Rpc(String),
+}
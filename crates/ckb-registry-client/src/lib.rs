@@ -0,0 +1,39 @@
+//! Build unsigned transactions for the operations on the instances of the
+//! [demo registry contract], from a set of live cells.
+//!
+//! - [`create`]: create an instance with items, and its config cell if any.
+//! - [`Registry::insert`], [`Registry::insert_batch`], [`Registry::remove`]
+//!   and [`Registry::modify`]: update the items, only the affected items and
+//!   their predecessors are consumed.
+//! - [`Registry::destroy`]: destroy an instance with all its items.
+//!
+//! The transactions are not signed, and the change capacity is returned with
+//! them.
+//!
+//! The live cells could be fetched from the CKB indexer by [`rpc`].
+//!
+//! An insert which conflicts with another one could be planned again by
+//! [`PendingInsert::rebase`], and many inserts could be scheduled into
+//! disjoint transactions by [`Schedule`].
+//!
+//! The transactions could be chained on top of the outputs of pending ones by
+//! [`MempoolOverlay`], which evicts the dependent transactions when an
+//! ancestor is rejected or replaced.
+//!
+//! [demo registry contract]: ../../contracts/demo-linked-list-type
+
+mod builder;
+pub mod error;
+mod overlay;
+mod rebase;
+pub mod rpc;
+mod schedule;
+pub mod types;
+pub mod utils;
+
+pub use builder::{create, ConfigRequest, ContractRef, CreateRequest, Registry};
+pub use error::{Error, Result};
+pub use overlay::MempoolOverlay;
+pub use rebase::{Conflict, PendingInsert, Rebased};
+pub use schedule::{Batch, Schedule};
+pub use types::{Funding, Item, ItemCell, LiveCell, UnsignedTx};
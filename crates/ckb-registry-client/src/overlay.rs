@@ -0,0 +1,148 @@
+//! Plan transactions on top of the outputs of pending transactions.
+//!
+//! A batching service may insert a key whose predecessor is created by another
+//! insert, which is still in the tx-pool. The overlay applies the pending
+//! transactions to the committed items, so the next transaction is planned
+//! against the latest view. When a pending transaction is rejected or
+//! replaced, the transactions which spend its outputs could never be
+//! committed, so they are evicted too.
+
+use std::{collections::HashSet, mem};
+
+use ckb_types::packed;
+
+use crate::{
+    builder::Registry,
+    error::{Error, Result},
+    types::UnsignedTx,
+};
+
+/// The committed items of an instance, with the transactions in the tx-pool.
+#[derive(Debug, Clone)]
+pub struct MempoolOverlay {
+    committed: Registry,
+    // The committed items with all pending transactions applied.
+    view: Registry,
+    // The pending transactions, a transaction is always after its parents.
+    pending: Vec<UnsignedTx>,
+}
+
+impl MempoolOverlay {
+    /// Creates an overlay without pending transactions.
+    pub fn new(committed: Registry) -> Self {
+        Self {
+            view: committed.clone(),
+            committed,
+            pending: Vec::new(),
+        }
+    }
+
+    /// The committed items.
+    pub fn committed(&self) -> &Registry {
+        &self.committed
+    }
+
+    /// The items after all pending transactions are committed, the new
+    /// transactions should be planned against it.
+    pub fn view(&self) -> &Registry {
+        &self.view
+    }
+
+    /// The pending transactions, in the order they are added.
+    pub fn pending(&self) -> &[UnsignedTx] {
+        &self.pending
+    }
+
+    /// Adds a transaction which is accepted by the tx-pool, its parents
+    /// should be added before it.
+    pub fn add(&mut self, tx: UnsignedTx) -> Result<()> {
+        for out_point in tx.tx.input_pts_iter() {
+            if self.spender(&out_point).is_some() {
+                return Err(Error::SpentByPending(out_point));
+            }
+        }
+        let mut view = self.view.clone();
+        view.apply(&tx)?;
+        self.view = view;
+        self.pending.push(tx);
+        Ok(())
+    }
+
+    /// Removes a transaction rejected by the tx-pool, with all its
+    /// descendants.
+    ///
+    /// Returns the evicted transactions, the rejected one first.
+    pub fn reject(&mut self, tx_hash: &packed::Byte32) -> Result<Vec<UnsignedTx>> {
+        if !self.pending.iter().any(|tx| &tx.tx.hash() == tx_hash) {
+            return Err(Error::PendingTxNotFound);
+        }
+        Ok(self.evict(|tx| &tx.tx.hash() == tx_hash))
+    }
+
+    /// Replaces a pending transaction, such as with a higher fee.
+    ///
+    /// The hash of the transaction is changed, so its descendants are
+    /// evicted and returned, they should be planned again.
+    pub fn replace(&mut self, tx_hash: &packed::Byte32, tx: UnsignedTx) -> Result<Vec<UnsignedTx>> {
+        let mut overlay = self.clone();
+        let mut evicted = overlay.reject(tx_hash)?;
+        evicted.remove(0);
+        overlay.add(tx)?;
+        *self = overlay;
+        Ok(evicted)
+    }
+
+    /// Applies a committed transaction, which is either pending or sent by
+    /// others.
+    ///
+    /// The pending transactions which spend the same inputs conflict with it,
+    /// they are evicted and returned with their descendants.
+    pub fn commit(&mut self, tx: &UnsignedTx) -> Result<Vec<UnsignedTx>> {
+        let mut committed = self.committed.clone();
+        committed.apply(tx)?;
+        self.committed = committed;
+        let tx_hash = tx.tx.hash();
+        self.pending.retain(|pending| pending.tx.hash() != tx_hash);
+        let spent = tx.tx.input_pts_iter().collect::<HashSet<_>>();
+        Ok(self.evict(|pending| {
+            pending
+                .tx
+                .input_pts_iter()
+                .any(|out_point| spent.contains(&out_point))
+        }))
+    }
+
+    // The pending transaction which spends the cell.
+    fn spender(&self, out_point: &packed::OutPoint) -> Option<&UnsignedTx> {
+        self.pending
+            .iter()
+            .find(|tx| tx.tx.input_pts_iter().any(|input| &input == out_point))
+    }
+
+    // Evicts the matched transactions and their descendants, then applies the
+    // others to the committed items again.
+    fn evict<F>(&mut self, is_evicted: F) -> Vec<UnsignedTx>
+    where
+        F: Fn(&UnsignedTx) -> bool,
+    {
+        let mut evicted = Vec::new();
+        let mut evicted_hashes = HashSet::new();
+        let mut view = self.committed.clone();
+        for tx in mem::take(&mut self.pending) {
+            let is_descendant = tx
+                .tx
+                .input_pts_iter()
+                .any(|out_point| evicted_hashes.contains(&out_point.tx_hash()));
+            let mut next = view.clone();
+            if !is_evicted(&tx) && !is_descendant && next.apply(&tx).is_ok() {
+                view = next;
+                self.pending.push(tx);
+            } else {
+                evicted_hashes.insert(tx.tx.hash());
+                evicted.push(tx);
+            }
+        }
+        self.view = view;
+        evicted
+    }
+}
@@ -0,0 +1,167 @@
+//! Rebase the insert transactions which conflict with others.
+//!
+//! An insert consumes the predecessor of the new key, so when two users insert
+//! near the same key, the later transaction is rejected since the predecessor
+//! is already spent. The conflict is detected from the rejection of the
+//! tx-pool, or from the latest live cells, then the insert is planned again
+//! against the new predecessor.
+
+use ckb_types::{bytes::Bytes, packed, prelude::*};
+use demo_linked_list_lib::types;
+
+use crate::{
+    builder::Registry,
+    error::{Error, Result},
+    types::{Funding, Item, UnsignedTx},
+};
+
+/// Why a pending transaction could not be committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conflict {
+    /// The input is already spent.
+    DeadInput(packed::OutPoint),
+    /// The input is unknown, it may be spent and pruned, or not committed.
+    UnknownInput(packed::OutPoint),
+}
+
+/// An insert transaction which is not committed yet.
+#[derive(Debug, Clone)]
+pub struct PendingInsert {
+    /// The inserted keys and payloads, in the order of the outputs.
+    pub items: Vec<(Bytes, Bytes)>,
+    /// The lock of the inserted items.
+    pub lock: packed::Script,
+    /// The transaction.
+    pub tx: UnsignedTx,
+}
+
+/// An insert transaction which is built again.
+#[derive(Debug, Clone)]
+pub struct Rebased {
+    /// The new transaction.
+    pub tx: UnsignedTx,
+    /// The indexes of the inputs which are not the same as the old
+    /// transaction.
+    ///
+    /// The transaction hash is changed, so all lock groups have to sign
+    /// again, but only the locks of these inputs are new to the signers.
+    pub changed_inputs: Vec<usize>,
+}
+
+impl Conflict {
+    /// Finds the conflict in a rejection of the tx-pool, like
+    /// `Resolve failed Dead(OutPoint(0x...))`.
+    pub fn from_rejection(message: &str) -> Option<Self> {
+        let parse = |prefix: &str| {
+            let start = message.find(prefix)? + prefix.len();
+            let hex = message.get(start..start + packed::OutPoint::TOTAL_SIZE * 2)?;
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|index| {
+                    let digits = hex.get(index..index + 2)?;
+                    if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                        return None;
+                    }
+                    u8::from_str_radix(digits, 16).ok()
+                })
+                .collect::<Option<Vec<_>>>()?;
+            packed::OutPoint::from_slice(&bytes).ok()
+        };
+        parse("Dead(OutPoint(0x")
+            .map(Self::DeadInput)
+            .or_else(|| parse("Unknown(OutPoint(0x").map(Self::UnknownInput))
+    }
+
+    /// The out point of the input.
+    pub fn out_point(&self) -> &packed::OutPoint {
+        match self {
+            Self::DeadInput(out_point) | Self::UnknownInput(out_point) => out_point,
+        }
+    }
+}
+
+impl PendingInsert {
+    /// Reads the keys, the payloads and the lock of the inserted items from a
+    /// transaction built by [`Registry::insert`] or [`Registry::insert_batch`].
+    pub fn from_tx(tx: UnsignedTx) -> Result<Self> {
+        let indexes = inserted_indexes(&tx).ok_or(Error::NotInsert)?;
+        let mut items = Vec::with_capacity(indexes.len());
+        let mut lock_opt: Option<packed::Script> = None;
+        for index in indexes {
+            let (output, data) = tx.tx.output_with_data(index).ok_or(Error::NotInsert)?;
+            // A batch shares the same lock, see `Registry::insert_batch`.
+            match &lock_opt {
+                Some(lock) if lock != &output.lock() => {
+                    return Err(Error::NotInsert);
+                }
+                Some(_) => {}
+                None => lock_opt = Some(output.lock()),
+            }
+            let item = Item::from_cell_data(&data)?;
+            items.push((item.key, item.payload));
+        }
+        let lock = lock_opt.ok_or(Error::NotInsert)?;
+        Ok(Self { items, lock, tx })
+    }
+
+    /// The predecessor consumed by the transaction.
+    pub fn predecessor(&self) -> Option<packed::OutPoint> {
+        self.tx.tx.input_pts_iter().next()
+    }
+
+    /// Checks the transaction against the latest items and the funding cells,
+    /// every input should still be one of them.
+    pub fn conflict(&self, registry: &Registry, funding: &Funding) -> Option<Conflict> {
+        self.tx
+            .tx
+            .input_pts_iter()
+            .find(|out_point| {
+                let is_item = registry
+                    .items()
+                    .iter()
+                    .any(|cell| &cell.cell.out_point == out_point);
+                let is_funding = funding
+                    .cells
+                    .iter()
+                    .any(|cell| &cell.out_point == out_point);
+                !is_item && !is_funding
+            })
+            .map(Conflict::DeadInput)
+    }
+
+    /// Plans the insert again against the latest items, with the same keys,
+    /// payloads and lock.
+    ///
+    /// A batch fails with [`Error::MixedPredecessors`] if its keys don't share
+    /// the same predecessor any more.
+    pub fn rebase(&self, registry: &Registry, funding: &Funding) -> Result<Rebased> {
+        let tx = registry.insert_batch(&self.items, self.lock.clone(), funding)?;
+        let old_inputs = self.tx.tx.input_pts_iter().collect::<Vec<_>>();
+        let changed_inputs = tx
+            .tx
+            .input_pts_iter()
+            .enumerate()
+            .filter(|(index, out_point)| old_inputs.get(*index) != Some(out_point))
+            .map(|(index, _)| index)
+            .collect();
+        Ok(Rebased { tx, changed_inputs })
+    }
+}
+
+// The indexes of the inserted items, from the events in the first witness.
+fn inserted_indexes(tx: &UnsignedTx) -> Option<Vec<usize>> {
+    let witness = tx.tx.witnesses().get(0)?;
+    let witness = packed::WitnessArgs::from_slice(&witness.raw_data()).ok()?;
+    let output_type: Bytes = witness.output_type().to_opt()?.unpack();
+    let output = types::RegistryOutput::from_slice(&output_type).ok()?;
+    let events = output.events().to_opt()?;
+    let inserted = events.inserted();
+    if inserted.is_empty() || !events.removed().is_empty() {
+        return None;
+    }
+    let indexes = inserted
+        .into_iter()
+        .map(|event| event.index().as_reader().to_u32() as usize)
+        .collect();
+    Some(indexes)
+}
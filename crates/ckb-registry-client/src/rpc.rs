@@ -0,0 +1,370 @@
+//! Fetch live registry cells through the `get_cells` RPC of the CKB indexer.
+//!
+//! Only plain HTTP is supported, the responses could be either sized or
+//! chunked.
+
+use std::{
+    cell::Cell,
+    io::{ErrorKind, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::{Duration, Instant},
+};
+
+use ckb_jsonrpc_types as rpc;
+use ckb_linked_list_tool::types::ListItem;
+use ckb_types::{bytes::Bytes, packed, prelude::*};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    error::{Error, Result},
+    types::{Item, LiveCell},
+};
+
+/// The default count of cells in a page.
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// The default timeout of a request, from connecting to reading the whole
+/// response.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A client of the CKB indexer RPC.
+#[derive(Debug)]
+pub struct IndexerRpcClient {
+    // The address to connect, `host:port`.
+    addr: String,
+    path: String,
+    page_size: u32,
+    timeout: Duration,
+    next_id: Cell<u64>,
+}
+
+/// A live cell returned by the indexer.
+#[derive(Debug, Clone)]
+pub struct IndexedCell {
+    /// The live cell.
+    pub cell: LiveCell,
+    /// The number of the block which creates the cell.
+    pub block_number: u64,
+    /// The index of the transaction in the block.
+    pub tx_index: u32,
+}
+
+#[derive(Serialize)]
+struct SearchKey {
+    script: rpc::Script,
+    script_type: &'static str,
+    script_search_mode: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<SearchKeyFilter>,
+    with_data: bool,
+}
+
+#[derive(Serialize)]
+struct SearchKeyFilter {
+    script_len_range: [rpc::Uint64; 2],
+    output_data_len_range: [rpc::Uint64; 2],
+}
+
+#[derive(Deserialize)]
+struct Pagination {
+    objects: Vec<RpcCell>,
+    last_cursor: rpc::JsonBytes,
+}
+
+#[derive(Deserialize)]
+struct RpcCell {
+    output: rpc::CellOutput,
+    output_data: Option<rpc::JsonBytes>,
+    out_point: rpc::OutPoint,
+    block_number: rpc::BlockNumber,
+    tx_index: rpc::Uint32,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Option<Value>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl IndexedCell {
+    /// The list item of the cell, its current key and next key.
+    pub fn to_list_item(&self) -> Result<ListItem<Bytes>> {
+        let item = Item::from_cell_data(&self.cell.data)?;
+        Ok(ListItem::new(item.key, item.next))
+    }
+}
+
+impl IndexerRpcClient {
+    /// Creates a client with the URL of the indexer, like
+    /// `http://127.0.0.1:8114`.
+    pub fn new(url: &str) -> Result<Self> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| Error::Rpc(format!("unsupported url {url}")))?;
+        let (addr, path) = match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        };
+        Ok(Self {
+            addr: addr.to_owned(),
+            path: path.to_owned(),
+            page_size: DEFAULT_PAGE_SIZE,
+            timeout: DEFAULT_TIMEOUT,
+            next_id: Cell::new(0),
+        })
+    }
+
+    /// Sets the count of cells in a page, it should be greater than 0.
+    pub fn with_page_size(mut self, page_size: u32) -> Result<Self> {
+        if page_size == 0 {
+            return Err(Error::Rpc(
+                "the page size should be greater than 0".to_owned(),
+            ));
+        }
+        self.page_size = page_size;
+        Ok(self)
+    }
+
+    /// Sets the timeout of a request, from connecting to reading the whole
+    /// response, so a stalled or trickling node doesn't block the caller
+    /// forever.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Fetches all live cells whose type script has the code hash and the
+    /// hash type, and whose args start with the prefix.
+    ///
+    /// Use the full script args as the prefix to fetch the cells of an
+    /// instance, or an empty prefix for all instances.
+    pub fn get_registry_cells(
+        &self,
+        code_hash: &packed::Byte32,
+        hash_type: packed::Byte,
+        args_prefix: &[u8],
+    ) -> Result<Vec<IndexedCell>> {
+        let script = packed::Script::new_builder()
+            .code_hash(code_hash.clone())
+            .hash_type(hash_type)
+            .args(args_prefix.pack())
+            .build();
+        let search_key = SearchKey {
+            script: script.into(),
+            script_type: "type",
+            script_search_mode: "prefix",
+            filter: None,
+            with_data: true,
+        };
+        self.get_cells(search_key)
+    }
+
+    /// Fetches all live cells which use the lock, and have no type script and
+    /// no data, so they could be used to pay for transactions.
+    pub fn get_free_cells(&self, lock: &packed::Script) -> Result<Vec<IndexedCell>> {
+        let search_key = SearchKey {
+            script: lock.clone().into(),
+            script_type: "lock",
+            script_search_mode: "exact",
+            filter: Some(SearchKeyFilter {
+                script_len_range: [0.into(), 1.into()],
+                output_data_len_range: [0.into(), 1.into()],
+            }),
+            with_data: true,
+        };
+        self.get_cells(search_key)
+    }
+
+    fn get_cells(&self, search_key: SearchKey) -> Result<Vec<IndexedCell>> {
+        let mut cells = Vec::new();
+        let mut cursor: Option<rpc::JsonBytes> = None;
+        loop {
+            let params = json!([search_key, "asc", rpc::Uint32::from(self.page_size), cursor]);
+            let page: Pagination = self.call("get_cells", params)?;
+            let count = page.objects.len();
+            for cell in page.objects {
+                cells.push(cell.into());
+            }
+            if count < self.page_size as usize {
+                break;
+            }
+            cursor = Some(page.last_cursor);
+        }
+        Ok(cells)
+    }
+
+    /// Fetches the list items of an instance, sorted by keys.
+    pub fn get_list_items(
+        &self,
+        type_script: &packed::Script,
+    ) -> Result<Vec<(ListItem<Bytes>, IndexedCell)>> {
+        let args = type_script.args().raw_data();
+        let mut items = self
+            .get_registry_cells(&type_script.code_hash(), type_script.hash_type(), &args)?
+            .into_iter()
+            // The args are matched by prefix, so drop the cells of other
+            // instances whose args start with the same bytes.
+            .filter(|cell| cell.cell.output.type_().to_opt().as_ref() == Some(type_script))
+            .map(|cell| Ok((cell.to_list_item()?, cell)))
+            .collect::<Result<Vec<_>>>()?;
+        items.sort_by(|a, b| a.0.curr.cmp(&b.0.curr));
+        Ok(items)
+    }
+
+    /// Calls a JSON-RPC method.
+    pub fn call<R: DeserializeOwned>(&self, method: &str, params: Value) -> Result<R> {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        let request = json!({
+            "id": id,
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params,
+        });
+        let body = self.post(&request.to_string())?;
+        let response: RpcResponse = serde_json::from_slice(&body).map_err(rpc_error)?;
+        if let Some(err) = response.error {
+            return Err(Error::Rpc(format!("{} ({})", err.message, err.code)));
+        }
+        let result = response
+            .result
+            .ok_or_else(|| Error::Rpc("no result".to_owned()))?;
+        serde_json::from_value(result).map_err(rpc_error)
+    }
+
+    fn post(&self, body: &str) -> Result<Vec<u8>> {
+        let deadline = Instant::now() + self.timeout;
+        let mut stream = self.connect(deadline)?;
+        let request = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.path,
+            self.addr,
+            body.len(),
+            body
+        );
+        stream.write_all(request.as_bytes()).map_err(rpc_error)?;
+        // The socket timeout only limits a single read, so shrink it to the
+        // time left, otherwise a node which sends a byte at a time is never
+        // timed out.
+        let mut response = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::Rpc("request timed out".to_owned()));
+            }
+            stream
+                .set_read_timeout(Some(remaining))
+                .map_err(rpc_error)?;
+            match stream.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => response.extend_from_slice(&buf[..n]),
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(rpc_error(err)),
+            }
+        }
+        parse_http_response(&response)
+    }
+
+    // Try all resolved addresses, like `TcpStream::connect`, but before the
+    // deadline.
+    fn connect(&self, deadline: Instant) -> Result<TcpStream> {
+        let mut last_err = None;
+        for addr in self.addr.to_socket_addrs().map_err(rpc_error)? {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::Rpc("request timed out".to_owned()));
+            }
+            match TcpStream::connect_timeout(&addr, remaining) {
+                Ok(stream) => {
+                    stream
+                        .set_write_timeout(Some(remaining))
+                        .map_err(rpc_error)?;
+                    return Ok(stream);
+                }
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.map_or_else(
+            || Error::Rpc(format!("unresolved address {}", self.addr)),
+            rpc_error,
+        ))
+    }
+}
+
+impl From<RpcCell> for IndexedCell {
+    fn from(cell: RpcCell) -> Self {
+        Self {
+            cell: LiveCell {
+                out_point: cell.out_point.into(),
+                output: cell.output.into(),
+                data: cell
+                    .output_data
+                    .map(|data| data.into_bytes())
+                    .unwrap_or_default(),
+            },
+            block_number: cell.block_number.value(),
+            tx_index: cell.tx_index.value(),
+        }
+    }
+}
+
+fn rpc_error<E: ToString>(err: E) -> Error {
+    Error::Rpc(err.to_string())
+}
+
+// Returns the body of a successful response.
+fn parse_http_response(response: &[u8]) -> Result<Vec<u8>> {
+    let separator = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| Error::Rpc("incomplete http response".to_owned()))?;
+    let head = String::from_utf8_lossy(&response[..separator]);
+    let body = &response[separator + 4..];
+    let mut lines = head.lines();
+    let status = lines.next().unwrap_or_default();
+    if status.split_whitespace().nth(1) != Some("200") {
+        return Err(Error::Rpc(format!("http status: {status}")));
+    }
+    let is_chunked = lines.any(|line| {
+        let line = line.to_ascii_lowercase();
+        line.starts_with("transfer-encoding:") && line.contains("chunked")
+    });
+    if is_chunked {
+        decode_chunked(body)
+    } else {
+        Ok(body.to_vec())
+    }
+}
+
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>> {
+    let invalid = || Error::Rpc("invalid chunked body".to_owned());
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or_else(invalid)?;
+        let size_str = String::from_utf8_lossy(&body[..line_end]);
+        let size_str = size_str.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size_str, 16).map_err(|_| invalid())?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Ok(decoded);
+        }
+        let end = size
+            .checked_add(2)
+            .filter(|end| body.len() >= *end)
+            .ok_or_else(invalid)?;
+        decoded.extend_from_slice(&body[..size]);
+        body = &body[end..];
+    }
+}
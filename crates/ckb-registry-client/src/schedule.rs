@@ -0,0 +1,91 @@
+//! Schedule many inserts into transactions which could land in the same
+//! block.
+//!
+//! An insert consumes the predecessor of the new key, so two transactions
+//! conflict if they consume the same predecessor. The keys which share a
+//! predecessor are inserted together by [`Registry::insert_batch`], and the
+//! transactions of different predecessors are disjoint.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use ckb_types::bytes::Bytes;
+
+use crate::builder::Registry;
+
+/// The keys to insert after the same predecessor, in one transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Batch {
+    /// The key of the predecessor.
+    pub predecessor: Bytes,
+    /// The keys and the payloads, in the order of the ring.
+    pub items: Vec<(Bytes, Bytes)>,
+}
+
+/// An ordered plan of inserts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schedule {
+    /// The batches in rounds.
+    ///
+    /// The batches in a round consume different predecessors, so they could
+    /// land in the same block. A batch in a later round consumes an item
+    /// created by the previous round, which happens only if a predecessor has
+    /// more keys than a transaction could take.
+    pub rounds: Vec<Vec<Batch>>,
+    /// The keys which are not scheduled, since they exist already, or they
+    /// are requested more than once.
+    pub skipped: Vec<Bytes>,
+}
+
+impl Schedule {
+    /// Schedules the requested keys and payloads against the current items,
+    /// at most `max_keys_per_tx` keys in a transaction.
+    ///
+    /// If a key is requested more than once, the first payload is used.
+    pub fn new(registry: &Registry, requests: Vec<(Bytes, Bytes)>, max_keys_per_tx: usize) -> Self {
+        let max_keys_per_tx = max_keys_per_tx.max(1);
+        let mut skipped = Vec::new();
+        let mut requested = BTreeSet::new();
+        // The requests by the keys of their predecessors.
+        let mut groups = BTreeMap::<Bytes, Vec<(Bytes, Bytes)>>::new();
+        for (key, payload) in requests {
+            if registry.get(&key).is_some() || !requested.insert(key.clone()) {
+                skipped.push(key);
+                continue;
+            }
+            let prev_key = registry.predecessor(&key).item.key.clone();
+            groups.entry(prev_key).or_default().push((key, payload));
+        }
+
+        let mut rounds = Vec::<Vec<Batch>>::new();
+        for (prev_key, mut group) in groups {
+            group.sort_by(|a, b| (a.0 < prev_key, &a.0).cmp(&(b.0 < prev_key, &b.0)));
+            let mut predecessor = prev_key;
+            for (round, chunk) in group.chunks(max_keys_per_tx).enumerate() {
+                if rounds.len() == round {
+                    rounds.push(Vec::new());
+                }
+                rounds[round].push(Batch {
+                    predecessor,
+                    items: chunk.to_vec(),
+                });
+                predecessor = chunk[chunk.len() - 1].0.clone();
+            }
+        }
+        Self { rounds, skipped }
+    }
+
+    /// All batches in the order to build.
+    pub fn batches(&self) -> impl Iterator<Item = &Batch> {
+        self.rounds.iter().flatten()
+    }
+
+    /// The count of transactions.
+    pub fn tx_count(&self) -> usize {
+        self.rounds.iter().map(Vec::len).sum()
+    }
+
+    /// The count of scheduled keys.
+    pub fn keys_count(&self) -> usize {
+        self.batches().map(|batch| batch.items.len()).sum()
+    }
+}
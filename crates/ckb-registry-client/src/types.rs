@@ -0,0 +1,155 @@
+//! Types.
+
+use ckb_types::{bytes::Bytes, core::TransactionView, packed, prelude::*};
+use demo_linked_list_lib::{
+    item::{DemoItemReader, VERSION_1},
+    types,
+};
+
+use crate::error::{Error, Result};
+
+/// A live cell.
+#[derive(Debug, Clone)]
+pub struct LiveCell {
+    /// The out point of the cell.
+    pub out_point: packed::OutPoint,
+    /// The cell output.
+    pub output: packed::CellOutput,
+    /// The cell data.
+    pub data: Bytes,
+}
+
+/// An item of a registry instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    /// The key of the item.
+    pub key: Bytes,
+    /// The key of the next item.
+    pub next: Bytes,
+    /// The payload, the `demo` field.
+    pub payload: Bytes,
+    /// The version of the item data.
+    pub version: u8,
+    /// The lock hash of the owner.
+    pub owner: Option<[u8; 32]>,
+}
+
+/// A live cell which stores an item.
+#[derive(Debug, Clone)]
+pub struct ItemCell {
+    /// The live cell.
+    pub cell: LiveCell,
+    /// The parsed item.
+    pub item: Item,
+}
+
+/// The cells to pay for a transaction, and where the change goes.
+#[derive(Debug, Clone)]
+pub struct Funding {
+    /// All cells are consumed, they should not use any type script.
+    pub cells: Vec<LiveCell>,
+    /// The lock of the change cell.
+    pub change_lock: packed::Script,
+    /// The fee in shannons.
+    pub fee: u64,
+}
+
+/// An unsigned transaction.
+#[derive(Debug, Clone)]
+pub struct UnsignedTx {
+    /// The transaction, the cells of the registry instance are put first in
+    /// both inputs and outputs.
+    pub tx: TransactionView,
+    /// The capacity of the change cell, which is the last output; it's zero
+    /// if there is no change cell.
+    pub change: u64,
+}
+
+impl LiveCell {
+    /// The capacity of the cell.
+    pub fn capacity(&self) -> u64 {
+        self.output.capacity().unpack()
+    }
+
+    /// The cell input to consume the cell.
+    pub fn to_input(&self) -> packed::CellInput {
+        packed::CellInput::new_builder()
+            .previous_output(self.out_point.clone())
+            .build()
+    }
+}
+
+impl UnsignedTx {
+    /// The cells which will be created by the transaction.
+    ///
+    /// The transaction hash doesn't depend on witnesses, so the out points
+    /// are still valid after the transaction is signed.
+    pub fn live_cells(&self) -> Vec<LiveCell> {
+        let tx_hash = self.tx.hash();
+        self.tx
+            .outputs_with_data_iter()
+            .enumerate()
+            .map(|(index, (output, data))| LiveCell {
+                out_point: packed::OutPoint::new(tx_hash.clone(), index as u32),
+                output,
+                data,
+            })
+            .collect()
+    }
+
+    /// The change cell, it could pay for the next transaction before this
+    /// one is committed.
+    pub fn change_cell(&self) -> Option<LiveCell> {
+        if self.change == 0 {
+            return None;
+        }
+        self.live_cells().pop()
+    }
+}
+
+impl Item {
+    /// Creates an item in the latest version, without any owner.
+    pub fn new(key: &[u8], next: &[u8], payload: &[u8]) -> Self {
+        Self {
+            key: Bytes::copy_from_slice(key),
+            next: Bytes::copy_from_slice(next),
+            payload: Bytes::copy_from_slice(payload),
+            version: demo_linked_list_lib::item::LATEST_VERSION,
+            owner: None,
+        }
+    }
+
+    /// Parses the item data in any version.
+    pub fn from_cell_data(data: &[u8]) -> Result<Self> {
+        let reader = DemoItemReader::from_cell_data(data).map_err(|_| Error::InvalidItemData)?;
+        Ok(Self {
+            key: Bytes::copy_from_slice(reader.curr().raw_data()),
+            next: Bytes::copy_from_slice(reader.next().raw_data()),
+            payload: Bytes::copy_from_slice(reader.demo().raw_data()),
+            version: reader.version(),
+            owner: reader.owner().map(|owner| owner.to_array()),
+        })
+    }
+
+    /// The item data in its own version.
+    pub fn to_cell_data(&self) -> Bytes {
+        if self.version == VERSION_1 {
+            types::DemoData::new_from_raw_slices(&self.payload, &self.key, &self.next).as_bytes()
+        } else {
+            let data = types::DemoDataV2::new_from_raw_slices(&self.payload, &self.key, &self.next);
+            match self.owner {
+                Some(owner) => data.with_owner(owner),
+                None => data,
+            }
+            .to_cell_data()
+        }
+    }
+}
+
+impl ItemCell {
+    /// Parses the item in a live cell.
+    pub fn from_live_cell(cell: LiveCell) -> Result<Self> {
+        let item = Item::from_cell_data(&cell.data)?;
+        Ok(Self { cell, item })
+    }
+}
@@ -0,0 +1,17 @@
+//! Utilities.
+
+use ckb_hash::{new_blake2b, BLAKE2B_LEN};
+use ckb_types::{packed, prelude::*};
+
+/// Calculates the unique ID of a registry instance, or the type ID of its
+/// config cell, from the first input and the index of the output.
+///
+/// It's the same as the calculation in the registry contract.
+pub fn calculate_unique_id(input: &packed::CellInput, output_index: usize) -> [u8; BLAKE2B_LEN] {
+    let mut blake2b = new_blake2b();
+    blake2b.update(input.as_slice());
+    blake2b.update(&(output_index as u64).to_le_bytes());
+    let mut ret = [0; BLAKE2B_LEN];
+    blake2b.finalize(&mut ret);
+    ret
+}
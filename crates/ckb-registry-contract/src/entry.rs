@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+
+use ckb_hash::{new_blake2b, BLAKE2B_LEN};
+#[cfg(debug_assertions)]
+use ckb_std::ckb_types::prelude::*;
+use ckb_std::{ckb_constants::Source, ckb_types::prelude::Entity, high_level as hl};
+
+use crate::{
+    error::{Error, InternalError, Result},
+    operations,
+    traits::RegistryPolicy,
+    types::Operation,
+};
+
+/// The script args starts with a 32-byte unique ID.
+pub const UNIQUE_ID_SIZE: usize = 32;
+
+/// The entry of a registry contract.
+///
+/// It infers the operation from the count of cells, checks the common rules,
+/// and calls the hooks of the policy `P`.
+pub fn entry<P: RegistryPolicy>() -> core::result::Result<(), P::Error> {
+    debug!("{} Starting ...", module_path!());
+
+    let script_hash = hl::load_script_hash().map_err(Error::from)?;
+    debug!("script hash = {:#x}", script_hash.pack());
+
+    // Find all cells which use current script.
+    let indexes_of_inputs = find_cells(&script_hash, Source::Input);
+    let indexes_of_outputs = find_cells(&script_hash, Source::Output);
+
+    debug!("cells in  inputs: {indexes_of_inputs:?}");
+    debug!("cells in outputs: {indexes_of_outputs:?}");
+
+    let operation = match (indexes_of_inputs.len(), indexes_of_outputs.len()) {
+        // The current data is NOT allowed to be the same as the next data.
+        // So, the length of outputs should be always greater than 1, except
+        // the items are moved from another instance.
+        (0, n) if n > 0 => {
+            debug!("create a new global-registry instance with {n} items");
+            Operation::Create
+        }
+        (n, 0) if n > 0 => {
+            debug!("destroy the global-registry instance with {n} items");
+            Operation::Destroy
+        }
+        (_m, _n) if _m > 0 && _n > 0 => {
+            debug!("update items in the global-registry: {_m} -> {_n}");
+            Operation::Update
+        }
+        (_m, _n) => {
+            debug!("unknown operation: {_m} inputs and {_n} outputs");
+            return Err(Error::from(InternalError::UnknownOperation).into());
+        }
+    };
+
+    let script = hl::load_script().map_err(Error::from)?;
+    let script_args = script.args();
+    let script_args_slice = script_args.as_reader().raw_data();
+    check_args_length(operation, script_args_slice)?;
+    let policy = P::load(operation, &script_args_slice[UNIQUE_ID_SIZE..])?;
+
+    match operation {
+        Operation::Create => {
+            let indexes_of_predecessor = policy
+                .predecessor()
+                .map(|type_hash| find_cells(&type_hash, Source::Input))
+                .unwrap_or_default();
+            if indexes_of_predecessor.is_empty() {
+                check_unique_id(script_args_slice, indexes_of_outputs[0])?;
+                operations::create(policy, &indexes_of_outputs)?
+            } else {
+                debug!("move items from the predecessor: {indexes_of_predecessor:?}");
+                operations::move_in(policy, &indexes_of_predecessor, &indexes_of_outputs)?
+            }
+        }
+        Operation::Destroy => {
+            let indexes_of_successor = policy
+                .successor()
+                .map(|type_hash| find_cells(&type_hash, Source::Output))
+                .unwrap_or_default();
+            if indexes_of_successor.is_empty() {
+                operations::destroy(policy, &indexes_of_inputs)?
+            } else {
+                debug!("move items to the successor: {indexes_of_successor:?}");
+                operations::move_out(policy, &indexes_of_inputs, &indexes_of_successor)?
+            }
+        }
+        Operation::Update => operations::update(policy, &indexes_of_inputs, &indexes_of_outputs)?,
+    }
+
+    debug!("{} DONE.", module_path!());
+
+    Ok(())
+}
+
+fn find_cells(script_hash: &[u8; 32], source: Source) -> Vec<usize> {
+    hl::QueryIter::new(hl::load_cell_type_hash, source)
+        .enumerate()
+        .filter_map(|(index, type_hash_opt)| {
+            (type_hash_opt.as_ref() == Some(script_hash)).then_some(index)
+        })
+        .collect()
+}
+
+// The script args should start with the unique ID.
+fn check_args_length(operation: Operation, script_args: &[u8]) -> Result<()> {
+    if script_args.len() < UNIQUE_ID_SIZE {
+        let err = if operation == Operation::Create {
+            InternalError::CreateInvalidArgsLength
+        } else {
+            InternalError::InvalidArgs
+        };
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+// The unique ID should be checked when the instance is created.
+fn check_unique_id(script_args: &[u8], output_index: usize) -> Result<()> {
+    let unique_id = load_then_calculate_unique_id(output_index)?;
+    if unique_id[..] != script_args[..UNIQUE_ID_SIZE] {
+        return Err(InternalError::CreateIncorrectUniqueId.into());
+    }
+    Ok(())
+}
+
+// Load the first input and the index of the first output which uses current
+// script, then calculate an unique ID with them.
+fn load_then_calculate_unique_id(output_index: usize) -> Result<[u8; BLAKE2B_LEN]> {
+    let input = hl::load_input(0, Source::Input)?;
+    let mut blake2b = new_blake2b();
+    blake2b.update(input.as_slice());
+    blake2b.update(&(output_index as u64).to_le_bytes());
+    let mut ret = [0; BLAKE2B_LEN];
+    blake2b.finalize(&mut ret);
+    Ok(ret)
+}
@@ -0,0 +1,94 @@
+//! Errors.
+
+use core::result;
+
+use ckb_linked_list_tool::error::Error as ToolError;
+use ckb_std::error::SysError;
+
+/// The result of the framework.
+pub type Result<T> = result::Result<T, Error>;
+
+/// The errors which are found by the framework.
+///
+/// A contract could return them directly, or convert them into its own
+/// errors.
+#[derive(Clone, Copy)]
+#[repr(i8)]
+pub enum InternalError {
+    // 0x01 ~ 0x0f: Errors from SDK, or other system errors.
+    IndexOutOfBound = 0x01,
+    ItemMissing,
+    LengthNotEnough,
+    Encoding,
+    Unknown,
+
+    // 0x10 ~ 0x1f: Errors before doing operations.
+    UnknownOperation = 0x10,
+    InvalidArgs,
+
+    // 0x20 ~ 0x2f: Errors when create.
+    CreateInvalidArgsLength = 0x20,
+    CreateIncorrectUniqueId,
+    CreateInvalidOutputData,
+    CreateIncompleteList,
+    CreateMismatchPredecessor,
+
+    // 0x30 ~ 0x3f: Errors when destory.
+    DestroyInvalidInputData = 0x30,
+    DestroyIncompleteList,
+    DestroyMismatchSuccessor,
+
+    // 0x40 ~ 0x4f: Errors when update.
+    UpdateInvalidData = 0x40,
+    UpdateMismatchRange,
+
+    // This is not an error, just make sure the error code is less than 0x60.
+    Unreachable = 0x60,
+}
+
+/// The errors of the framework.
+pub enum Error {
+    /// 0x01 ~ 0x5f: Errors that not from external crates.
+    Internal(InternalError),
+    /// 0x60 ~ 0x7f: Errors from the linked list tool.
+    Tool(ToolError),
+}
+
+impl From<SysError> for InternalError {
+    fn from(err: SysError) -> Self {
+        match err {
+            SysError::IndexOutOfBound => Self::IndexOutOfBound,
+            SysError::ItemMissing => Self::ItemMissing,
+            SysError::LengthNotEnough(_) => Self::LengthNotEnough,
+            SysError::Encoding => Self::Encoding,
+            SysError::Unknown(_) => Self::Unknown,
+        }
+    }
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        Into::<InternalError>::into(err).into()
+    }
+}
+
+impl From<InternalError> for Error {
+    fn from(err: InternalError) -> Self {
+        Self::Internal(err)
+    }
+}
+
+impl From<ToolError> for Error {
+    fn from(err: ToolError) -> Self {
+        Self::Tool(err)
+    }
+}
+
+impl From<Error> for i8 {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Internal(e) => e as i8,
+            Error::Tool(e) => 0x60 + e as i8,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! A framework to build global registry contracts based on linked lists on
+//! CKB.
+//!
+//! The framework does the common checks of a registry instance:
+//!
+//! - Infers the operation from the count of cells.
+//! - Checks the unique ID in the script args when an instance is created.
+//! - Checks the linked list of the items, by the [CKB linked list tool].
+//! - Finds out the inserted, removed and modified items when update.
+//!
+//! A registry contract only has to define its item by [`RegistryItem`] and its
+//! own rules by [`RegistryPolicy`], then calls [`entry`] in its entry point.
+//!
+//! [CKB linked list tool]: ckb_linked_list_tool
+
+#![no_std]
+
+extern crate alloc;
+
+// TODO ref: issue nervosnetwork/ckb-std#84
+macro_rules! debug {
+    ($fmt:literal $(,$args:expr)* $(,)?) => {
+        #[cfg(debug_assertions)]
+        ckb_std::syscalls::debug(alloc::format!($fmt $(,$args)*));
+    };
+}
+
+mod entry;
+pub mod error;
+mod operations;
+pub mod traits;
+pub mod types;
+
+pub use crate::{
+    entry::{entry, UNIQUE_ID_SIZE},
+    traits::{RegistryItem, RegistryPolicy},
+    types::{Operation, RegistryCell},
+};
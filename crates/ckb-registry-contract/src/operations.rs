@@ -0,0 +1,212 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use ckb_linked_list_tool::{
+    check_linked_list_with_ordered_items, check_linked_list_with_unordered_items, types::ListItem,
+};
+use ckb_std::{ckb_constants::Source, high_level as hl};
+
+use crate::{
+    error::{Error, InternalError, Result},
+    traits::{RegistryItem, RegistryPolicy},
+    types::RegistryCell,
+};
+
+// The items should be ordered when create.
+pub(crate) fn create<P: RegistryPolicy>(
+    mut policy: P,
+    indexes: &[usize],
+) -> core::result::Result<(), P::Error> {
+    debug!("execute create operation: {indexes:?}");
+
+    let items = load_cells::<P::Item>(
+        indexes,
+        Source::Output,
+        InternalError::CreateInvalidOutputData,
+    )?;
+    let summary = check_linked_list_with_ordered_items(items.iter(), parse_linked_list_items)?;
+    if !summary.is_complete() {
+        return Err(Error::from(InternalError::CreateIncompleteList).into());
+    }
+
+    policy.on_create(&items)?;
+    for item in &items {
+        policy.on_insert(item)?;
+    }
+    policy.finish()
+}
+
+// The items could be unordered when destroy, in case users have their own data
+// which required to be ordered.
+pub(crate) fn destroy<P: RegistryPolicy>(
+    mut policy: P,
+    indexes: &[usize],
+) -> core::result::Result<(), P::Error> {
+    debug!("execute destroy operation: {indexes:?}");
+
+    let items = load_cells::<P::Item>(
+        indexes,
+        Source::Input,
+        InternalError::DestroyInvalidInputData,
+    )?;
+    let summary = check_linked_list_with_unordered_items(items.iter(), parse_linked_list_items)?;
+    if !summary.is_complete() {
+        return Err(Error::from(InternalError::DestroyIncompleteList).into());
+    }
+
+    policy.on_destroy(&items)?;
+    for item in &items {
+        policy.on_remove(item)?;
+    }
+    policy.finish()
+}
+
+// The inputs and the outputs should be the same range of the linked list.
+pub(crate) fn update<P: RegistryPolicy>(
+    mut policy: P,
+    inputs_indexes: &[usize],
+    outputs_indexes: &[usize],
+) -> core::result::Result<(), P::Error> {
+    debug!("execute update operation: {inputs_indexes:?} -> {outputs_indexes:?}");
+
+    let inputs = load_cells::<P::Item>(
+        inputs_indexes,
+        Source::Input,
+        InternalError::UpdateInvalidData,
+    )?;
+    let outputs = load_cells::<P::Item>(
+        outputs_indexes,
+        Source::Output,
+        InternalError::UpdateInvalidData,
+    )?;
+    let inputs_summary =
+        check_linked_list_with_unordered_items(inputs.iter(), parse_linked_list_items)?;
+    let outputs_summary =
+        check_linked_list_with_unordered_items(outputs.iter(), parse_linked_list_items)?;
+    if inputs_summary != outputs_summary {
+        return Err(Error::from(InternalError::UpdateMismatchRange).into());
+    }
+
+    let inputs_map = inputs
+        .iter()
+        .map(|cell| (cell.item.key(), cell))
+        .collect::<BTreeMap<_, _>>();
+    let outputs_map = outputs
+        .iter()
+        .map(|cell| (cell.item.key(), cell))
+        .collect::<BTreeMap<_, _>>();
+    for (key, input) in &inputs_map {
+        match outputs_map.get(key) {
+            Some(output) if is_modified(input, output)? => {
+                policy.on_modify(input, output)?;
+            }
+            Some(_) => {}
+            None => policy.on_remove(input)?,
+        }
+    }
+    for (key, output) in &outputs_map {
+        if !inputs_map.contains_key(key) {
+            policy.on_insert(output)?;
+        }
+    }
+
+    policy.on_update(&inputs, &outputs)?;
+    policy.finish()
+}
+
+// The items are moved from the predecessor, only a continuous part of the
+// list is required, and the unique ID is not checked.
+pub(crate) fn move_in<P: RegistryPolicy>(
+    mut policy: P,
+    predecessor_indexes: &[usize],
+    indexes: &[usize],
+) -> core::result::Result<(), P::Error> {
+    debug!("execute move-in operation: {predecessor_indexes:?} -> {indexes:?}");
+
+    let items = load_cells::<P::Item>(
+        indexes,
+        Source::Output,
+        InternalError::CreateInvalidOutputData,
+    )?;
+    check_linked_list_with_unordered_items(items.iter(), parse_linked_list_items)?;
+
+    let moved = load_raw_cells(predecessor_indexes, Source::Input)?;
+    if moved != load_raw_cells(indexes, Source::Output)? {
+        return Err(Error::from(InternalError::CreateMismatchPredecessor).into());
+    }
+
+    policy.on_move_in(&items)?;
+    policy.finish()
+}
+
+// The items are moved to the successor, only a continuous part of the list is
+// required.
+pub(crate) fn move_out<P: RegistryPolicy>(
+    mut policy: P,
+    indexes: &[usize],
+    successor_indexes: &[usize],
+) -> core::result::Result<(), P::Error> {
+    debug!("execute move-out operation: {indexes:?} -> {successor_indexes:?}");
+
+    let items = load_cells::<P::Item>(
+        indexes,
+        Source::Input,
+        InternalError::DestroyInvalidInputData,
+    )?;
+    check_linked_list_with_unordered_items(items.iter(), parse_linked_list_items)?;
+
+    let moved = load_raw_cells(successor_indexes, Source::Output)?;
+    if moved != load_raw_cells(indexes, Source::Input)? {
+        return Err(Error::from(InternalError::DestroyMismatchSuccessor).into());
+    }
+
+    policy.on_move_out(&items)?;
+    policy.finish()
+}
+
+fn load_cells<Item: RegistryItem>(
+    indexes: &[usize],
+    source: Source,
+    invalid_data: InternalError,
+) -> Result<Vec<RegistryCell<Item>>> {
+    let mut cells = Vec::with_capacity(indexes.len());
+    for &index in indexes {
+        debug!("load the data from {source:?}[{index}]");
+        let data = hl::load_cell_data(index, source)?;
+        let item = Item::from_cell_data(&data).ok_or(invalid_data)?;
+        cells.push(RegistryCell {
+            index,
+            source,
+            item,
+        });
+    }
+    Ok(cells)
+}
+
+// An item is modified if its content or the lock of its cell is changed.
+fn is_modified<Item: RegistryItem>(
+    old: &RegistryCell<Item>,
+    new: &RegistryCell<Item>,
+) -> Result<bool> {
+    Ok(new.item.is_modified_from(&old.item) || new.lock_hash()? != old.lock_hash()?)
+}
+
+fn parse_linked_list_items<Item: RegistryItem>(
+    cell: &RegistryCell<Item>,
+) -> Result<ListItem<&[u8]>> {
+    Ok(ListItem::new(cell.item.key(), cell.item.next_key()))
+}
+
+// Load the lock hashes and the data of cells, and sort them, so they could be
+// compared regardless of the order.
+fn load_raw_cells(indexes: &[usize], source: Source) -> Result<Vec<([u8; 32], Vec<u8>)>> {
+    let mut cells = indexes
+        .iter()
+        .map(|&index| {
+            let lock_hash = hl::load_cell_lock_hash(index, source)?;
+            let data = hl::load_cell_data(index, source)?;
+            Ok((lock_hash, data))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    cells.sort_unstable();
+    Ok(cells)
+}
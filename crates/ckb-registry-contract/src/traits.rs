@@ -0,0 +1,145 @@
+//! Traits.
+
+use crate::{
+    error::Error,
+    types::{Operation, RegistryCell},
+};
+
+/// An item of a registry instance, which is parsed from the cell data.
+///
+/// The items are ordered by their keys in bytes.
+pub trait RegistryItem: Sized {
+    /// Parses an item from the cell data, returns `None` if the data is
+    /// invalid.
+    fn from_cell_data(data: &[u8]) -> Option<Self>;
+
+    /// The key of the current item.
+    fn key(&self) -> &[u8];
+
+    /// The key of the next item in the linked list.
+    fn next_key(&self) -> &[u8];
+
+    /// The payload, which is the content of the item except the keys.
+    fn payload(&self) -> &[u8];
+
+    /// Whether the item is changed from an old item with the same key.
+    ///
+    /// The next key is not compared, since it's changed when a neighbour is
+    /// inserted or removed. The default compares the payloads only, an item
+    /// with other fields, such as an owner, should compare them too.
+    fn is_modified_from(&self, old: &Self) -> bool {
+        self.payload() != old.payload()
+    }
+}
+
+/// The rules of a registry instance.
+///
+/// All hooks are called after the linked list is checked.
+///
+/// - Create: [`on_create`], then [`on_insert`] for each item.
+/// - Destroy: [`on_destroy`], then [`on_remove`] for each item.
+/// - Update: [`on_remove`], [`on_insert`] and [`on_modify`] for each changed
+///   item, then [`on_update`].
+///
+/// At last, [`finish`] is called for all operations.
+///
+/// When the items are moved between instances (see [`successor`] and
+/// [`predecessor`]), [`on_move_out`] or [`on_move_in`] is called instead.
+///
+/// [`on_create`]: RegistryPolicy::on_create
+/// [`on_destroy`]: RegistryPolicy::on_destroy
+/// [`on_update`]: RegistryPolicy::on_update
+/// [`on_insert`]: RegistryPolicy::on_insert
+/// [`on_remove`]: RegistryPolicy::on_remove
+/// [`on_modify`]: RegistryPolicy::on_modify
+/// [`on_move_in`]: RegistryPolicy::on_move_in
+/// [`on_move_out`]: RegistryPolicy::on_move_out
+/// [`finish`]: RegistryPolicy::finish
+/// [`successor`]: RegistryPolicy::successor
+/// [`predecessor`]: RegistryPolicy::predecessor
+pub trait RegistryPolicy: Sized {
+    /// The item of the registry instance.
+    type Item: RegistryItem;
+    /// The error of the contract.
+    type Error: From<Error>;
+
+    /// Loads the policy from the script args which follow the unique ID.
+    fn load(operation: Operation, args: &[u8]) -> Result<Self, Self::Error>;
+
+    /// The type hash of the instance which the items could be moved to.
+    ///
+    /// When destroy, if there are cells of the successor in the outputs, the
+    /// inputs are only required to be a continuous part of the list, and all
+    /// of them should be moved to the successor as is.
+    fn successor(&self) -> Option<[u8; 32]> {
+        None
+    }
+
+    /// The type hash of the instance which the items could be moved from.
+    ///
+    /// When create, if there are cells of the predecessor in the inputs, the
+    /// unique ID is not checked, the outputs are only required to be a
+    /// continuous part of the list, and all of them should be moved from the
+    /// predecessor as is.
+    fn predecessor(&self) -> Option<[u8; 32]> {
+        None
+    }
+
+    /// Checks all items of a new instance, they are ordered.
+    fn on_create(&mut self, _items: &[RegistryCell<Self::Item>]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks all items of a destroyed instance.
+    fn on_destroy(&mut self, _items: &[RegistryCell<Self::Item>]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks the items in the inputs and the outputs of an update.
+    fn on_update(
+        &mut self,
+        _inputs: &[RegistryCell<Self::Item>],
+        _outputs: &[RegistryCell<Self::Item>],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks an item which is in the outputs but not in the inputs.
+    fn on_insert(&mut self, _item: &RegistryCell<Self::Item>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks an item which is in the inputs but not in the outputs.
+    fn on_remove(&mut self, _item: &RegistryCell<Self::Item>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks an item which is kept, but its content or its lock is changed.
+    fn on_modify(
+        &mut self,
+        _old: &RegistryCell<Self::Item>,
+        _new: &RegistryCell<Self::Item>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks the items which are moved from the predecessor, they are a
+    /// continuous part of the list, but they are unordered.
+    fn on_move_in(&mut self, _items: &[RegistryCell<Self::Item>]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks the items which are moved to the successor, they are a
+    /// continuous part of the list, but they are unordered.
+    ///
+    /// Anyone could move the items once the successor is set, so a policy
+    /// which requires an authorization to destroy should check it here too.
+    fn on_move_out(&mut self, _items: &[RegistryCell<Self::Item>]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Does the final checks, after all other hooks are passed.
+    fn finish(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+//! Types.
+
+use ckb_std::{ckb_constants::Source, error::SysError, high_level as hl};
+
+/// The operation on a registry instance.
+///
+/// It's inferred from the count of cells which use the current script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// No inputs, but has outputs.
+    Create,
+    /// No outputs, but has inputs.
+    Destroy,
+    /// Both inputs and outputs are not empty.
+    Update,
+}
+
+/// An item of a registry instance, and the cell which stores it.
+pub struct RegistryCell<Item> {
+    /// The index of the cell.
+    pub index: usize,
+    /// The source of the cell, inputs or outputs.
+    pub source: Source,
+    /// The parsed item.
+    pub item: Item,
+}
+
+impl<Item> RegistryCell<Item> {
+    /// Loads the lock hash of the cell.
+    pub fn lock_hash(&self) -> Result<[u8; 32], SysError> {
+        hl::load_cell_lock_hash(self.index, self.source)
+    }
+}
@@ -0,0 +1,30 @@
+//! Errors.
+
+use ckb_types::packed;
+use thiserror::Error;
+
+/// The result of indexing.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The reasons why the indexer could not follow the chain.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("the parent of block {number} is {parent}, but the tip is {tip}")]
+    UnknownParent {
+        number: u64,
+        parent: packed::Byte32,
+        tip: packed::Byte32,
+    },
+    #[error("can't rollback {block_hash}, since the tip is {tip:?}")]
+    RollbackMismatch {
+        block_hash: packed::Byte32,
+        tip: Option<packed::Byte32>,
+    },
+    #[error("can't rollback {block_hash}, its undo data is pruned, the max reorg depth is {max_reorg_depth}")]
+    RollbackTooDeep {
+        block_hash: packed::Byte32,
+        max_reorg_depth: usize,
+    },
+    #[error("the block source is broken: {0}")]
+    Source(String),
+}
@@ -0,0 +1,226 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use ckb_registry_client::LiveCell;
+use ckb_types::{bytes::Bytes, core::BlockView, packed};
+
+use crate::{
+    error::{Error, Result},
+    instance::Instance,
+    source::{BlockSource, ChainEvent},
+};
+
+/// The default count of the latest blocks which could be rolled back.
+pub const DEFAULT_MAX_REORG_DEPTH: usize = 100;
+
+// The changes of a block, to rollback it.
+#[derive(Debug, Clone)]
+struct BlockUndo {
+    number: u64,
+    hash: packed::Byte32,
+    created: Vec<(Bytes, packed::OutPoint)>,
+    consumed: Vec<(Bytes, LiveCell)>,
+}
+
+/// Tracks the live cells of all instances of a registry contract.
+///
+/// The instances are identified by their script args.
+#[derive(Debug, Clone)]
+pub struct Indexer {
+    code_hash: packed::Byte32,
+    hash_type: packed::Byte,
+    instances: BTreeMap<Bytes, Instance>,
+    // The instance of each live cell.
+    locations: HashMap<packed::OutPoint, Bytes>,
+    // The undo data of the latest blocks, at most `max_reorg_depth` ones.
+    blocks: VecDeque<BlockUndo>,
+    max_reorg_depth: usize,
+    // The number and the hash of the latest block whose undo data is pruned.
+    pruned_tip: Option<(u64, packed::Byte32)>,
+}
+
+impl Indexer {
+    /// Creates an indexer for the registry contract.
+    pub fn new(code_hash: packed::Byte32, hash_type: packed::Byte) -> Self {
+        Self {
+            code_hash,
+            hash_type,
+            instances: BTreeMap::new(),
+            locations: HashMap::new(),
+            blocks: VecDeque::new(),
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
+            pruned_tip: None,
+        }
+    }
+
+    /// Sets the count of the latest blocks which could be rolled back, at
+    /// least 1. The undo data of older blocks is dropped, so the memory
+    /// doesn't grow with the chain.
+    pub fn with_max_reorg_depth(mut self, max_reorg_depth: usize) -> Self {
+        self.max_reorg_depth = max_reorg_depth.max(1);
+        self
+    }
+
+    /// The number and the hash of the tip block.
+    pub fn tip(&self) -> Option<(u64, packed::Byte32)> {
+        self.blocks
+            .back()
+            .map(|block| (block.number, block.hash.clone()))
+            .or_else(|| self.pruned_tip.clone())
+    }
+
+    /// Finds an instance by its script args.
+    pub fn instance(&self, args: &[u8]) -> Option<&Instance> {
+        self.instances.get(args)
+    }
+
+    /// All instances which have live cells, sorted by their script args.
+    pub fn instances(&self) -> impl Iterator<Item = (&Bytes, &Instance)> {
+        self.instances.iter()
+    }
+
+    /// The instances which are not complete rings.
+    pub fn inconsistent_instances(&self) -> impl Iterator<Item = (&Bytes, &Instance)> {
+        self.instances
+            .iter()
+            .filter(|(_, instance)| !instance.is_consistent())
+    }
+
+    /// Applies all events from the source, returns the count of the events.
+    pub fn sync<S: BlockSource>(&mut self, source: &mut S) -> Result<usize> {
+        let mut count = 0;
+        while let Some(event) = source.next_event()? {
+            self.apply_event(event)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Applies a chain event.
+    pub fn apply_event(&mut self, event: ChainEvent) -> Result<()> {
+        match event {
+            ChainEvent::Block(block) => self.apply_block(&block),
+            ChainEvent::Rollback(block_hash) => self.rollback(&block_hash),
+        }
+    }
+
+    /// Attaches a block to the tip.
+    ///
+    /// The first block could be at any height, so the indexer could start
+    /// from a recent block.
+    pub fn apply_block(&mut self, block: &BlockView) -> Result<()> {
+        if let Some((_, tip)) = self.tip() {
+            if block.parent_hash() != tip {
+                return Err(Error::UnknownParent {
+                    number: block.number(),
+                    parent: block.parent_hash(),
+                    tip,
+                });
+            }
+        }
+        let mut undo = BlockUndo {
+            number: block.number(),
+            hash: block.hash(),
+            created: Vec::new(),
+            consumed: Vec::new(),
+        };
+        let mut touched = BTreeSet::new();
+        for tx in block.transactions() {
+            for out_point in tx.input_pts_iter() {
+                if let Some((args, cell)) = self.remove_cell(&out_point) {
+                    touched.insert(args.clone());
+                    undo.consumed.push((args, cell));
+                }
+            }
+            for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+                let args = match self.instance_args(&output) {
+                    Some(args) => args,
+                    None => continue,
+                };
+                let out_point = packed::OutPoint::new(tx.hash(), index as u32);
+                let cell = LiveCell {
+                    out_point: out_point.clone(),
+                    output,
+                    data,
+                };
+                self.insert_cell(args.clone(), cell);
+                touched.insert(args.clone());
+                undo.created.push((args, out_point));
+            }
+        }
+        self.check(touched);
+        self.blocks.push_back(undo);
+        while self.blocks.len() > self.max_reorg_depth {
+            let pruned = self.blocks.pop_front().expect("block");
+            self.pruned_tip = Some((pruned.number, pruned.hash));
+        }
+        Ok(())
+    }
+
+    /// Detaches the tip block, which should have the hash.
+    pub fn rollback(&mut self, block_hash: &packed::Byte32) -> Result<()> {
+        let undo = match self.tip() {
+            Some((_, tip)) if &tip == block_hash => match self.blocks.pop_back() {
+                Some(undo) => undo,
+                None => {
+                    return Err(Error::RollbackTooDeep {
+                        block_hash: block_hash.clone(),
+                        max_reorg_depth: self.max_reorg_depth,
+                    })
+                }
+            },
+            tip => {
+                return Err(Error::RollbackMismatch {
+                    block_hash: block_hash.clone(),
+                    tip: tip.map(|(_, hash)| hash),
+                })
+            }
+        };
+        let mut touched = BTreeSet::new();
+        // Restore the consumed cells first, since some of them could be
+        // created in the same block.
+        for (args, cell) in undo.consumed.into_iter().rev() {
+            self.insert_cell(args.clone(), cell);
+            touched.insert(args);
+        }
+        for (args, out_point) in undo.created.into_iter().rev() {
+            self.remove_cell(&out_point);
+            touched.insert(args);
+        }
+        self.check(touched);
+        Ok(())
+    }
+
+    fn instance_args(&self, output: &packed::CellOutput) -> Option<Bytes> {
+        let type_script = output.type_().to_opt()?;
+        (type_script.code_hash() == self.code_hash && type_script.hash_type() == self.hash_type)
+            .then(|| type_script.args().raw_data())
+    }
+
+    fn insert_cell(&mut self, args: Bytes, cell: LiveCell) {
+        self.locations.insert(cell.out_point.clone(), args.clone());
+        let type_script = cell.output.type_().to_opt().expect("type script");
+        self.instances
+            .entry(args)
+            .or_insert_with(|| Instance::new(type_script))
+            .insert(cell);
+    }
+
+    fn remove_cell(&mut self, out_point: &packed::OutPoint) -> Option<(Bytes, LiveCell)> {
+        let args = self.locations.remove(out_point)?;
+        let instance = self.instances.get_mut(&args)?;
+        debug_assert!(instance.contains(out_point));
+        let cell = instance.remove(out_point)?;
+        if instance.is_empty() {
+            self.instances.remove(&args);
+        }
+        Some((args, cell))
+    }
+
+    fn check(&mut self, touched: BTreeSet<Bytes>) {
+        for args in touched {
+            if let Some(instance) = self.instances.get_mut(&args) {
+                instance.check();
+            }
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Bound::{self, Excluded, Unbounded},
+};
+
+use ckb_registry_client::{ItemCell, LiveCell, Registry};
+use ckb_types::{bytes::Bytes, packed, prelude::*};
+
+/// A reason why an instance is not a complete ring.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// The cell data is not a valid item.
+    InvalidData { out_point: packed::OutPoint },
+    /// The key is already used by another live cell.
+    DuplicateKey {
+        key: Bytes,
+        out_point: packed::OutPoint,
+    },
+    /// The next key of the item is not the key of its successor.
+    BrokenLink {
+        key: Bytes,
+        next: Bytes,
+        expected: Bytes,
+    },
+    /// There is only 1 item, which could not be a ring.
+    TooFewItems,
+}
+
+/// The live items of a registry instance, sorted by keys.
+#[derive(Debug, Clone)]
+pub struct Instance {
+    type_script: packed::Script,
+    items: BTreeMap<Bytes, ItemCell>,
+    // The keys of the items, by their out points.
+    keys: HashMap<packed::OutPoint, Bytes>,
+    // The live cells which are not in `items`, since their keys are taken or
+    // their data are invalid, sorted by the bytes of their out points, so the
+    // duplicate which takes a key and the order of inconsistencies don't
+    // depend on the hasher.
+    others: BTreeMap<Bytes, OtherCell>,
+    inconsistencies: Vec<Inconsistency>,
+}
+
+#[derive(Debug, Clone)]
+enum OtherCell {
+    Duplicate(ItemCell),
+    Invalid(LiveCell),
+}
+
+impl Instance {
+    pub(crate) fn new(type_script: packed::Script) -> Self {
+        Self {
+            type_script,
+            items: BTreeMap::new(),
+            keys: HashMap::new(),
+            others: BTreeMap::new(),
+            inconsistencies: Vec::new(),
+        }
+    }
+
+    /// The type script of the instance.
+    pub fn type_script(&self) -> &packed::Script {
+        &self.type_script
+    }
+
+    /// The count of the live cells, including the inconsistent ones.
+    pub fn cells_count(&self) -> usize {
+        self.items.len() + self.others.len()
+    }
+
+    /// The items, sorted by keys.
+    pub fn items(&self) -> impl Iterator<Item = &ItemCell> {
+        self.items.values()
+    }
+
+    /// Finds an item by its key.
+    pub fn get(&self, key: &[u8]) -> Option<&ItemCell> {
+        self.items.get(key)
+    }
+
+    /// The item before the key in the ring, whether the key exists or not.
+    ///
+    /// It's the item to update when the key is inserted or removed.
+    pub fn predecessor(&self, key: &[u8]) -> Option<&ItemCell> {
+        self.items
+            .range::<[u8], _>((Unbounded, Excluded(key)))
+            .next_back()
+            .or_else(|| self.items.iter().next_back())
+            .map(|(_, cell)| cell)
+    }
+
+    /// The item after the key in the ring, whether the key exists or not.
+    pub fn successor(&self, key: &[u8]) -> Option<&ItemCell> {
+        self.items
+            .range::<[u8], _>((Excluded(key), Unbounded))
+            .next()
+            .or_else(|| self.items.iter().next())
+            .map(|(_, cell)| cell)
+    }
+
+    /// The items in a range of keys, without wrapping around.
+    pub fn range<'a>(
+        &'a self,
+        start: Bound<&'a [u8]>,
+        end: Bound<&'a [u8]>,
+    ) -> impl Iterator<Item = &'a ItemCell> {
+        self.items
+            .range::<[u8], _>((start, end))
+            .map(|(_, cell)| cell)
+    }
+
+    /// The inconsistencies found after the last block, the invalid and
+    /// duplicated cells go first in the order of their out points, then the
+    /// broken links in the order of keys.
+    pub fn inconsistencies(&self) -> &[Inconsistency] {
+        &self.inconsistencies
+    }
+
+    /// Whether the items form a complete ring.
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+
+    /// Converts into a client registry, to build transactions.
+    pub fn to_registry(
+        &self,
+        cell_deps: Vec<packed::CellDep>,
+    ) -> ckb_registry_client::Result<Registry> {
+        let cells = self.items.values().map(|cell| cell.cell.clone()).collect();
+        Registry::new(self.type_script.clone(), cell_deps, cells)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty() && self.others.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, cell: LiveCell) {
+        let out_point = cell.out_point.clone();
+        match ItemCell::from_live_cell(cell.clone()) {
+            Ok(cell) if !self.items.contains_key(&cell.item.key) => {
+                self.keys.insert(out_point, cell.item.key.clone());
+                self.items.insert(cell.item.key.clone(), cell);
+            }
+            Ok(cell) => {
+                self.others
+                    .insert(out_point.as_bytes(), OtherCell::Duplicate(cell));
+            }
+            Err(_) => {
+                self.others
+                    .insert(out_point.as_bytes(), OtherCell::Invalid(cell));
+            }
+        }
+    }
+
+    // The first duplicated cell, by out points, takes the key when the item
+    // is removed.
+    pub(crate) fn remove(&mut self, out_point: &packed::OutPoint) -> Option<LiveCell> {
+        match self.others.remove(&out_point.as_bytes()) {
+            Some(OtherCell::Duplicate(cell)) => return Some(cell.cell),
+            Some(OtherCell::Invalid(cell)) => return Some(cell),
+            None => {}
+        }
+        let key = self.keys.remove(out_point)?;
+        let removed = self.items.remove(&key).map(|cell| cell.cell);
+        let duplicate = self
+            .others
+            .iter()
+            .find(|(_, other)| matches!(other, OtherCell::Duplicate(cell) if cell.item.key == key))
+            .map(|(out_point, _)| out_point.clone());
+        if let Some(out_point) = duplicate {
+            if let Some(OtherCell::Duplicate(cell)) = self.others.remove(&out_point) {
+                self.insert(cell.cell);
+            }
+        }
+        removed
+    }
+
+    pub(crate) fn contains(&self, out_point: &packed::OutPoint) -> bool {
+        self.keys.contains_key(out_point) || self.others.contains_key(&out_point.as_bytes())
+    }
+
+    pub(crate) fn check(&mut self) {
+        let mut inconsistencies = Vec::new();
+        for other in self.others.values() {
+            let inconsistency = match other {
+                OtherCell::Duplicate(cell) => Inconsistency::DuplicateKey {
+                    key: cell.item.key.clone(),
+                    out_point: cell.cell.out_point.clone(),
+                },
+                OtherCell::Invalid(cell) => Inconsistency::InvalidData {
+                    out_point: cell.out_point.clone(),
+                },
+            };
+            inconsistencies.push(inconsistency);
+        }
+        if self.items.len() == 1 {
+            inconsistencies.push(Inconsistency::TooFewItems);
+        }
+        let keys = self.items.keys().collect::<Vec<_>>();
+        for (index, cell) in self.items.values().enumerate() {
+            let expected = keys[(index + 1) % keys.len()];
+            if cell.item.next != *expected {
+                inconsistencies.push(Inconsistency::BrokenLink {
+                    key: cell.item.key.clone(),
+                    next: cell.item.next.clone(),
+                    expected: expected.clone(),
+                });
+            }
+        }
+        self.inconsistencies = inconsistencies;
+    }
+}
@@ -0,0 +1,20 @@
+//! Index the live cells of the instances of the [demo registry contract].
+//!
+//! The indexer consumes a stream of blocks and rollbacks from a
+//! [`BlockSource`], and keeps a sorted view of the items of each instance, so
+//! the predecessor of a key could be found in `O(log n)`.
+//!
+//! The instances are checked after each block, and the ones which are not a
+//! complete ring are flagged with their [`Inconsistency`]s.
+//!
+//! [demo registry contract]: ../../contracts/demo-linked-list-type
+
+pub mod error;
+mod indexer;
+mod instance;
+pub mod source;
+
+pub use error::{Error, Result};
+pub use indexer::{Indexer, DEFAULT_MAX_REORG_DEPTH};
+pub use instance::{Inconsistency, Instance};
+pub use source::{BlockSource, ChainEvent, JsonBlockSource};
@@ -0,0 +1,95 @@
+//! The sources of blocks.
+
+use std::collections::VecDeque;
+
+use ckb_jsonrpc_types as rpc;
+use ckb_types::{core::BlockView, packed, prelude::*, H256};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// A change of the chain.
+#[derive(Debug, Clone)]
+pub enum ChainEvent {
+    /// A new block is attached to the tip.
+    Block(BlockView),
+    /// The tip block, with the hash, is detached.
+    Rollback(packed::Byte32),
+}
+
+/// A stream of chain events.
+pub trait BlockSource {
+    /// The next event, or `None` if there are no more events for now.
+    fn next_event(&mut self) -> Result<Option<ChainEvent>>;
+}
+
+/// A chain event in JSON fixtures.
+///
+/// ```json
+/// [
+///   { "type": "block", "block": { "header": { ... }, ... } },
+///   { "type": "rollback", "block_hash": "0x..." }
+/// ]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsonChainEvent {
+    /// A block in the format of the CKB JSON-RPC.
+    Block { block: Box<rpc::BlockView> },
+    /// The hash of the detached block.
+    Rollback { block_hash: H256 },
+}
+
+/// Chain events loaded from JSON fixtures.
+#[derive(Debug, Clone, Default)]
+pub struct JsonBlockSource {
+    events: VecDeque<ChainEvent>,
+}
+
+impl From<ChainEvent> for JsonChainEvent {
+    fn from(event: ChainEvent) -> Self {
+        match event {
+            ChainEvent::Block(block) => Self::Block {
+                block: Box::new(block.into()),
+            },
+            ChainEvent::Rollback(block_hash) => Self::Rollback {
+                block_hash: block_hash.unpack(),
+            },
+        }
+    }
+}
+
+impl From<JsonChainEvent> for ChainEvent {
+    fn from(event: JsonChainEvent) -> Self {
+        match event {
+            JsonChainEvent::Block { block } => Self::Block((*block).into()),
+            JsonChainEvent::Rollback { block_hash } => Self::Rollback(block_hash.pack()),
+        }
+    }
+}
+
+impl JsonBlockSource {
+    /// Loads the events from a JSON array.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let events: Vec<JsonChainEvent> =
+            serde_json::from_str(json).map_err(|err| Error::Source(err.to_string()))?;
+        let events = events.into_iter().map(ChainEvent::from).collect();
+        Ok(Self { events })
+    }
+
+    /// Dumps the events into a JSON array.
+    pub fn to_json(events: &[ChainEvent]) -> String {
+        let events = events
+            .iter()
+            .cloned()
+            .map(JsonChainEvent::from)
+            .collect::<Vec<_>>();
+        serde_json::to_string_pretty(&events).expect("serialize chain events")
+    }
+}
+
+impl BlockSource for JsonBlockSource {
+    fn next_event(&mut self) -> Result<Option<ChainEvent>> {
+        Ok(self.events.pop_front())
+    }
+}
@@ -0,0 +1,260 @@
+//! Show the registry items in a transaction, and where the chain breaks.
+
+use std::fmt;
+
+use ckb_linked_list_tool::{
+    check_linked_list_with_unordered_items,
+    error::Error as ToolError,
+    types::{ListItem, ListItemsSummary},
+};
+use ckb_registry_client::Item;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed};
+
+use crate::codes;
+
+/// The registry cells of an instance, in the inputs or the outputs.
+pub struct Side {
+    /// The absolute indexes of the cells, with their items if they are valid.
+    pub cells: Vec<(usize, Option<Item>)>,
+    /// The breaks of the chain.
+    pub breaks: Vec<Break>,
+    /// The part of the ring covered by the items, the start and the end,
+    /// checked by the linked list tool.
+    pub summary: Option<Result<(Bytes, Bytes), String>>,
+}
+
+/// Where the chain breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Break {
+    /// The cell data is not a valid item.
+    InvalidData { index: usize },
+    /// The key is used by another item.
+    DuplicateKey { index: usize },
+    /// The next key is the key of the item itself.
+    NextIsSelf { index: usize },
+    /// The next key is not the key of any item, and it's not the only end of
+    /// the chain, or the items should be a complete ring.
+    Dangling { index: usize },
+}
+
+/// The registry cells of an instance in a transaction.
+pub struct InstanceCells {
+    /// The type script of the instance.
+    pub type_script: packed::Script,
+    /// The consumed cells.
+    pub inputs: Side,
+    /// The created cells.
+    pub outputs: Side,
+}
+
+impl Break {
+    /// The index of the cell.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::InvalidData { index }
+            | Self::DuplicateKey { index }
+            | Self::NextIsSelf { index }
+            | Self::Dangling { index } => *index,
+        }
+    }
+}
+
+impl fmt::Display for Break {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidData { .. } => write!(f, "the data is not a valid item"),
+            Self::DuplicateKey { .. } => write!(f, "the key is duplicated"),
+            Self::NextIsSelf { .. } => write!(f, "the item points to itself"),
+            Self::Dangling { .. } => write!(f, "the chain breaks after this item"),
+        }
+    }
+}
+
+impl Side {
+    // The items should be a complete ring if the instance is created or
+    // destroyed.
+    fn new(cells: Vec<(usize, Bytes)>, is_ring: bool) -> Self {
+        let cells = cells
+            .into_iter()
+            .map(|(index, data)| (index, Item::from_cell_data(&data).ok()))
+            .collect::<Vec<_>>();
+        let breaks = find_breaks(&cells, is_ring);
+        let summary = (!cells.is_empty()).then(|| summarize(&cells));
+        Self {
+            cells,
+            breaks,
+            summary,
+        }
+    }
+
+    /// Whether the items are a complete ring.
+    pub fn is_complete(&self) -> bool {
+        matches!(&self.summary, Some(Ok((start, end))) if start == end)
+    }
+}
+
+impl InstanceCells {
+    /// Collects the registry cells in a transaction, which are grouped by
+    /// their type scripts.
+    ///
+    /// A type script is treated as a registry instance, if its args has the
+    /// unique ID and any of its cells has a valid item.
+    pub fn collect(
+        inputs: &[(packed::CellOutput, Bytes)],
+        outputs: &[(packed::CellOutput, Bytes)],
+    ) -> Vec<Self> {
+        let mut scripts = Vec::<packed::Script>::new();
+        for (output, data) in inputs.iter().chain(outputs) {
+            if let Some(script) = output.type_().to_opt() {
+                let is_registry =
+                    script.args().raw_data().len() >= 32 && Item::from_cell_data(data).is_ok();
+                if is_registry && !scripts.contains(&script) {
+                    scripts.push(script);
+                }
+            }
+        }
+        scripts
+            .into_iter()
+            .map(|type_script| {
+                let select = |cells: &[(packed::CellOutput, Bytes)]| {
+                    cells
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, (output, _))| {
+                            output.type_().to_opt().as_ref() == Some(&type_script)
+                        })
+                        .map(|(index, (_, data))| (index, data.clone()))
+                        .collect::<Vec<_>>()
+                };
+                let (inputs, outputs) = (select(inputs), select(outputs));
+                let (is_created, is_destroyed) = (inputs.is_empty(), outputs.is_empty());
+                Self {
+                    inputs: Side::new(inputs, is_destroyed),
+                    outputs: Side::new(outputs, is_created),
+                    type_script,
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the outputs cover the same part of the ring as the inputs, or
+    /// a complete ring is created or destroyed.
+    pub fn is_kept(&self) -> bool {
+        match (&self.inputs.summary, &self.outputs.summary) {
+            (Some(Ok(inputs)), Some(Ok(outputs))) => inputs == outputs,
+            (None, Some(_)) => self.outputs.is_complete(),
+            (Some(_), None) => self.inputs.is_complete(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for InstanceCells {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "instance {}",
+            format_bytes(&self.type_script.args().raw_data())
+        )?;
+        for (name, side) in [("inputs", &self.inputs), ("outputs", &self.outputs)] {
+            match &side.summary {
+                None => writeln!(f, "  {name}: none")?,
+                Some(Ok((start, end))) if start == end => {
+                    writeln!(f, "  {name}: a complete ring from {}", display_key(start))?
+                }
+                Some(Ok((start, end))) => writeln!(
+                    f,
+                    "  {name}: from {} to {}",
+                    display_key(start),
+                    display_key(end)
+                )?,
+                Some(Err(err)) => writeln!(f, "  {name}: {err}")?,
+            }
+            for (index, item) in &side.cells {
+                match item {
+                    Some(item) => write!(
+                        f,
+                        "    #{index} {} -> {}  payload: {}  version: {}",
+                        display_key(&item.key),
+                        display_key(&item.next),
+                        format_bytes(&item.payload),
+                        item.version
+                    )?,
+                    None => write!(f, "    #{index} invalid data")?,
+                }
+                for item_break in side.breaks.iter().filter(|b| b.index() == *index) {
+                    write!(f, "  <-- {item_break}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        if !self.is_kept() {
+            writeln!(f, "  the ring is not kept by the transaction")?;
+        }
+        Ok(())
+    }
+}
+
+fn find_breaks(cells: &[(usize, Option<Item>)], is_ring: bool) -> Vec<Break> {
+    let mut breaks = Vec::new();
+    let mut linked = Vec::<(usize, &Item)>::new();
+    for (index, item) in cells {
+        let index = *index;
+        match item {
+            None => breaks.push(Break::InvalidData { index }),
+            Some(item) if linked.iter().any(|(_, other)| other.key == item.key) => {
+                breaks.push(Break::DuplicateKey { index })
+            }
+            Some(item) if item.key == item.next => breaks.push(Break::NextIsSelf { index }),
+            Some(item) => linked.push((index, item)),
+        }
+    }
+    // A part of the ring has only 1 end, and a complete ring has none.
+    let ends = linked
+        .iter()
+        .filter(|(_, item)| !linked.iter().any(|(_, other)| other.key == item.next))
+        .map(|(index, _)| *index)
+        .collect::<Vec<_>>();
+    if ends.len() > 1 || (is_ring && !ends.is_empty()) {
+        breaks.extend(ends.into_iter().map(|index| Break::Dangling { index }));
+    }
+    breaks.sort_by_key(Break::index);
+    breaks
+}
+
+fn summarize(cells: &[(usize, Option<Item>)]) -> Result<(Bytes, Bytes), String> {
+    let mut items = Vec::with_capacity(cells.len());
+    for (index, item) in cells {
+        let item = item
+            .as_ref()
+            .ok_or_else(|| format!("the data of #{index} is invalid"))?;
+        items.push((item.key.clone(), item.next.clone()));
+    }
+    check_linked_list_with_unordered_items(items, parse_item)
+        .map(|summary: ListItemsSummary<Bytes>| (summary.start, summary.end))
+        .map_err(|err: ToolError| {
+            let code = codes::TOOL_ERRORS_START + err as i8;
+            codes::error_name(code).unwrap_or_else(|| format!("error {code}"))
+        })
+}
+
+fn parse_item((curr, next): (Bytes, Bytes)) -> Result<ListItem<Bytes>, ToolError> {
+    Ok(ListItem::new(curr, next))
+}
+
+fn display_key(key: &[u8]) -> String {
+    let is_text = !key.is_empty() && key.iter().all(|byte| byte.is_ascii_graphic());
+    if is_text {
+        String::from_utf8_lossy(key).into_owned()
+    } else {
+        format_bytes(key)
+    }
+}
+
+fn format_bytes(bytes: &[u8]) -> String {
+    let hex = bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    format!("0x{hex}")
+}
@@ -0,0 +1,94 @@
+//! The exit codes of the demo registry contract.
+//!
+//! The contract is built for RISC-V only, so its errors are listed here again.
+//! The test `name_all_contract_errors` parses
+//! `contracts/demo-linked-list-type/src/error.rs` and the errors of the linked
+//! list tool, and pins every code and name here against them.
+
+// The start of the errors from the linked list tool.
+pub(crate) const TOOL_ERRORS_START: i8 = 0x60;
+
+const INTERNAL_ERRORS: &[(i8, &str)] = &[
+    // 0x01 ~ 0x0f: Errors from SDK, or other system errors.
+    (0x01, "IndexOutOfBound"),
+    (0x02, "ItemMissing"),
+    (0x03, "LengthNotEnough"),
+    (0x04, "Encoding"),
+    (0x05, "Unknown"),
+    // 0x10 ~ 0x1f: Errors before doing operations.
+    (0x10, "UnknownEnvironment"),
+    (0x11, "UnknownOperation"),
+    (0x12, "InvalidArgs"),
+    (0x13, "ConfigNotFound"),
+    (0x14, "ConfigInvalidData"),
+    (0x15, "ValidatorInvalidHashType"),
+    (0x16, "ValidatorFailedToExec"),
+    (0x17, "OutputInvalidWitness"),
+    (0x18, "ReservedMissingProof"),
+    (0x19, "ReservedInvalidProof"),
+    (0x1a, "ReservedKeyNotAllowed"),
+    (0x1b, "EventsMissing"),
+    (0x1c, "EventsMismatch"),
+    // 0x20 ~ 0x2f: Errors when create.
+    (0x20, "CreateInvalidArgsLength"),
+    (0x21, "CreateIncorrectUniqueId"),
+    (0x22, "CreateInvalidOutputData"),
+    (0x23, "CreateIncompleteList"),
+    (0x24, "CreateMissingSentinel"),
+    (0x25, "CreateBeyondMaxSentinel"),
+    (0x26, "CreateInvalidKey"),
+    (0x27, "CreateMissingConfig"),
+    (0x28, "CreateMismatchPredecessor"),
+    (0x29, "CreateMissingParent"),
+    (0x2a, "CreateUnauthorizedParent"),
+    (0x2b, "CreateMissingPredecessor"),
+    // 0x30 ~ 0x3f: Errors when destory.
+    (0x30, "DestroyInvalidInputData"),
+    (0x31, "DestroyIncompleteList"),
+    (0x32, "DestroyNotOnlySentinels"),
+    (0x33, "DestroyMismatchSuccessor"),
+    (0x34, "DestroyUnauthorized"),
+    (0x35, "DestroyNotScheduled"),
+    (0x36, "DestroyTimeLocked"),
+    // 0x40 ~ 0x4f: Errors when update.
+    (0x40, "UpdateMismatchRange"),
+    (0x41, "UpdateRemoveSentinel"),
+    (0x42, "UpdateBeyondMaxSentinel"),
+    (0x43, "UpdateInvalidKey"),
+    (0x44, "UpdateInvalidData"),
+    (0x45, "UpdateDowngradeVersion"),
+    (0x46, "UpdateUnauthorizedOwner"),
+    (0x47, "UpdateUnauthorizedRemove"),
+    // 0x50 ~ 0x5f: Errors when verify the declared action.
+    (0x50, "ActionInvalidWitness"),
+    (0x51, "ActionMismatchOperation"),
+    (0x52, "ActionDuplicateKeys"),
+    (0x53, "ActionInvalidInsert"),
+    (0x54, "ActionInvalidRemove"),
+    (0x55, "ActionInvalidModifyPayload"),
+    (0x56, "ActionInvalidTransfer"),
+    (0x57, "ActionInvalidRebalance"),
+    (0x58, "ActionInvalidMigrate"),
+    (0x59, "ActionInvalidTransferOwner"),
+];
+
+const TOOL_ERRORS: &[(i8, &str)] = &[
+    (0x00, "EmptyList"),
+    (0x01, "Discontinuous"),
+    (0x02, "ReachLastTwice"),
+    (0x03, "NextIsSelfItem"),
+];
+
+/// The name of an exit code of the demo registry contract, like
+/// `InternalError::CreateIncompleteList` or `ToolError::Discontinuous`.
+pub fn error_name(code: i8) -> Option<String> {
+    let (errors, kind, code) = if code >= TOOL_ERRORS_START {
+        (TOOL_ERRORS, "ToolError", code - TOOL_ERRORS_START)
+    } else {
+        (INTERNAL_ERRORS, "InternalError", code)
+    };
+    errors
+        .iter()
+        .find(|(value, _)| *value == code)
+        .map(|(_, name)| format!("{kind}::{name}"))
+}
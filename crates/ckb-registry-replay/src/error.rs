@@ -0,0 +1,18 @@
+//! Errors.
+
+use thiserror::Error;
+
+/// The result of loading a dump.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The reasons why a dump could not be loaded.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("invalid mock transaction: {0}")]
+    Json(#[from] serde_json::Error),
+}
@@ -0,0 +1,34 @@
+//! Replay and diagnose the transactions dumped by the tests into
+//! `failed_txs`.
+//!
+//! A dump is a mock transaction, with all its inputs and cell deps, so the
+//! scripts are run again in ckb-vm by [`ckb_testtool`]. If a registry
+//! instance is failed, its exit code is decoded into the name of the error of
+//! the [demo registry contract].
+//!
+//! The items of every registry instance in the inputs and the outputs are
+//! parsed, and the breaks of the chain are highlighted.
+//!
+//! [demo registry contract]: ../../contracts/demo-linked-list-type
+
+pub mod chain;
+pub mod codes;
+pub mod error;
+mod replay;
+
+use std::fs;
+
+use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction};
+
+pub use error::{Error, Result};
+pub use replay::{replay, Failure, Outcome, Report};
+
+/// Loads a dumped mock transaction.
+pub fn load(path: &str) -> Result<MockTransaction> {
+    let json = fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })?;
+    let mock_tx: ReprMockTransaction = serde_json::from_str(&json)?;
+    Ok(mock_tx.into())
+}
@@ -0,0 +1,37 @@
+use std::{env, process};
+
+use ckb_testtool::ckb_types::core::Cycle;
+
+const USAGE: &str = "Usage: ckb-registry-replay [--max-cycles <CYCLES>] <FILE>...";
+
+fn main() {
+    let mut max_cycles = Cycle::MAX;
+    let mut paths = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--max-cycles" {
+            max_cycles = match args.next().and_then(|value| value.parse().ok()) {
+                Some(value) => value,
+                None => exit_with(USAGE),
+            };
+        } else if arg.starts_with('-') {
+            exit_with(USAGE);
+        } else {
+            paths.push(arg);
+        }
+    }
+    if paths.is_empty() {
+        exit_with(USAGE);
+    }
+    for path in paths {
+        match ckb_registry_replay::load(&path) {
+            Ok(mock_tx) => print!("{}", ckb_registry_replay::replay(&mock_tx, max_cycles)),
+            Err(err) => exit_with(&format!("error: {err}")),
+        }
+    }
+}
+
+fn exit_with(msg: &str) -> ! {
+    eprintln!("{msg}");
+    process::exit(1);
+}
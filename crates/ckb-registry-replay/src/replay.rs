@@ -0,0 +1,186 @@
+//! Run a mock transaction in ckb-vm again.
+
+use std::fmt;
+
+use ckb_mock_tx_types::MockTransaction;
+use ckb_testtool::{
+    ckb_types::{bytes::Bytes, core::Cycle, packed},
+    context::Context,
+};
+
+use crate::{chain::InstanceCells, codes};
+
+/// The result of a replay.
+pub struct Report {
+    /// The hash of the transaction.
+    pub tx_hash: packed::Byte32,
+    /// Whether the transaction is passed.
+    pub outcome: Outcome,
+    /// The debug messages printed by the scripts.
+    pub debug_messages: Vec<String>,
+    /// The registry cells in the transaction.
+    pub instances: Vec<InstanceCells>,
+}
+
+/// Whether a transaction is passed.
+pub enum Outcome {
+    /// The transaction is passed with the cycles.
+    Passed { cycles: Cycle },
+    /// The transaction is failed.
+    Failed(Failure),
+}
+
+/// Why a transaction is failed.
+pub struct Failure {
+    /// The script group which is failed, like `Outputs[0].Type`.
+    pub source: Option<String>,
+    /// The exit code of the script.
+    pub code: Option<i8>,
+    /// The name of the exit code, if the failed script is a registry
+    /// instance.
+    pub error_name: Option<String>,
+    /// The error returned by the verifier.
+    pub message: String,
+}
+
+/// Runs the transaction in ckb-vm with its inputs and cell deps.
+pub fn replay(mock_tx: &MockTransaction, max_cycles: Cycle) -> Report {
+    let mut context = Context::default();
+    context.set_capture_debug(true);
+    for header in &mock_tx.mock_info.header_deps {
+        context.insert_header(header.clone());
+    }
+    for dep in &mock_tx.mock_info.cell_deps {
+        context.create_cell_with_out_point(
+            dep.cell_dep.out_point(),
+            dep.output.clone(),
+            dep.data.clone(),
+        );
+    }
+    for input in &mock_tx.mock_info.inputs {
+        let out_point = input.input.previous_output();
+        context.create_cell_with_out_point(
+            out_point.clone(),
+            input.output.clone(),
+            input.data.clone(),
+        );
+        if let Some(block_hash) = &input.header {
+            context.link_cell_with_block(out_point, block_hash.clone(), 0);
+        }
+    }
+
+    let tx = mock_tx.core_transaction();
+    let inputs = mock_tx
+        .mock_info
+        .inputs
+        .iter()
+        .map(|input| (input.output.clone(), input.data.clone()))
+        .collect::<Vec<_>>();
+    let outputs = tx.outputs_with_data_iter().collect::<Vec<_>>();
+    let instances = InstanceCells::collect(&inputs, &outputs);
+
+    let outcome = match context.verify_tx(&tx, max_cycles) {
+        Ok(cycles) => Outcome::Passed { cycles },
+        Err(err) => {
+            let message = err.to_string();
+            let source = parse_source(&message);
+            let code = parse_code(&message);
+            let is_registry = source
+                .as_deref()
+                .and_then(|source| find_script(source, &inputs, &outputs))
+                .map(|script| {
+                    instances
+                        .iter()
+                        .any(|instance| instance.type_script == script)
+                })
+                .unwrap_or_default();
+            let error_name = code.filter(|_| is_registry).and_then(codes::error_name);
+            Outcome::Failed(Failure {
+                source,
+                code,
+                error_name,
+                message,
+            })
+        }
+    };
+    let debug_messages = context
+        .captured_messages()
+        .into_iter()
+        .map(|msg| msg.message)
+        .collect();
+    Report {
+        tx_hash: tx.hash(),
+        outcome,
+        debug_messages,
+        instances,
+    }
+}
+
+impl Report {
+    /// Whether the transaction is passed.
+    pub fn is_passed(&self) -> bool {
+        matches!(self.outcome, Outcome::Passed { .. })
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "transaction 0x{:x}", self.tx_hash)?;
+        match &self.outcome {
+            Outcome::Passed { cycles } => writeln!(f, "  passed with {cycles} cycles")?,
+            Outcome::Failed(failure) => match (&failure.source, failure.code) {
+                (Some(source), Some(code)) => {
+                    write!(f, "  failed: {source} exited with {code}")?;
+                    if let Some(name) = &failure.error_name {
+                        write!(f, ", {name}")?;
+                    }
+                    writeln!(f)?;
+                }
+                _ => writeln!(f, "  failed: {}", failure.message)?,
+            },
+        }
+        for msg in &self.debug_messages {
+            writeln!(f, "  | {msg}")?;
+        }
+        for instance in &self.instances {
+            write!(f, "{instance}")?;
+        }
+        Ok(())
+    }
+}
+
+// The error looks like `TransactionScriptError { source: Outputs[0].Type,
+// cause: ValidationFailure: see error code 35 on page ... }`.
+fn parse_source(message: &str) -> Option<String> {
+    let rest = &message[message.find("source: ")? + "source: ".len()..];
+    let end = rest.find(',')?;
+    Some(rest[..end].trim().to_owned())
+}
+
+fn parse_code(message: &str) -> Option<i8> {
+    let rest = &message[message.find("error code ")? + "error code ".len()..];
+    let end = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == '-'))
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+// The script of a source, like `Inputs[1].Type`.
+fn find_script(
+    source: &str,
+    inputs: &[(packed::CellOutput, Bytes)],
+    outputs: &[(packed::CellOutput, Bytes)],
+) -> Option<packed::Script> {
+    let (cells, rest) = if let Some(rest) = source.strip_prefix("Inputs[") {
+        (inputs, rest)
+    } else {
+        (outputs, source.strip_prefix("Outputs[")?)
+    };
+    let (index, kind) = rest.split_once("].")?;
+    let (output, _) = cells.get(index.parse::<usize>().ok()?)?;
+    match kind {
+        "Type" => output.type_().to_opt(),
+        "Lock" => Some(output.lock()),
+        _ => None,
+    }
+}
@@ -0,0 +1,105 @@
+//! Parse the command-line arguments into the configs to simulate.
+
+use crate::{
+    config::Config,
+    error::{Error, Result},
+};
+
+/// The usage of the command-line tool.
+pub const USAGE: &str = "\
+Usage: ckb-registry-sim [OPTIONS]
+
+Options, the ones marked with * accept comma-separated values, every
+combination of the values is simulated:
+  --seed <SEED>                random seed [default: 0]
+  --clients <N>              * count of clients [default: 100]
+  --inserts <N>                count of inserts of every client [default: 10]
+  --distribution <DIST>      * uniform, sequential, or zipf[:<EXPONENT>[:<REGIONS>]]
+                               [default: uniform]
+  --shards <N>               * count of shards [default: 1]
+  --bucket-size <N>          * max count of keys in a node [default: 1]
+  --initial-nodes <N>          count of nodes in every shard [default: 1000]
+  --block-size <N>           * max count of transactions in a block [default: 1000]
+  --max-blocks <N>             max count of blocks [default: 10000]
+";
+
+/// Parses the arguments, without the program name.
+pub fn parse_args(args: &[String]) -> Result<Vec<Config>> {
+    let base = Config::default();
+    let mut seed = base.seed;
+    let mut clients = vec![base.clients];
+    let mut inserts_per_client = base.inserts_per_client;
+    let mut distributions = vec![base.distribution];
+    let mut shards = vec![base.layout.shards];
+    let mut bucket_sizes = vec![base.layout.bucket_size];
+    let mut initial_nodes = base.initial_nodes;
+    let mut block_sizes = vec![base.block_size];
+    let mut max_blocks = base.max_blocks;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let name = arg
+            .strip_prefix("--")
+            .ok_or_else(|| Error::Usage(format!("unexpected argument {arg}")))?;
+        let value = iter
+            .next()
+            .ok_or_else(|| Error::Usage(format!("no value for --{name}")))?;
+        match name {
+            "seed" => seed = parse_number(name, value)?,
+            "clients" => clients = parse_list(name, value)?,
+            "inserts" => inserts_per_client = parse_number(name, value)?,
+            "distribution" => {
+                distributions = value
+                    .split(',')
+                    .map(str::parse)
+                    .collect::<Result<Vec<_>>>()?;
+            }
+            "shards" => shards = parse_list(name, value)?,
+            "bucket-size" => bucket_sizes = parse_list(name, value)?,
+            "initial-nodes" => initial_nodes = parse_number(name, value)?,
+            "block-size" => block_sizes = parse_list(name, value)?,
+            "max-blocks" => max_blocks = parse_number(name, value)?,
+            _ => return Err(Error::Usage(format!("unknown option --{name}"))),
+        }
+    }
+
+    let mut configs = Vec::new();
+    for &distribution in &distributions {
+        for &shards in &shards {
+            for &bucket_size in &bucket_sizes {
+                for &clients in &clients {
+                    for &block_size in &block_sizes {
+                        let mut config = Config {
+                            seed,
+                            clients,
+                            inserts_per_client,
+                            distribution,
+                            initial_nodes,
+                            block_size,
+                            max_blocks,
+                            ..Config::default()
+                        };
+                        config.layout.shards = shards;
+                        config.layout.bucket_size = bucket_size;
+                        config.check()?;
+                        configs.push(config);
+                    }
+                }
+            }
+        }
+    }
+    Ok(configs)
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| Error::Usage(format!("--{name} should be a number")))
+}
+
+fn parse_list<T: std::str::FromStr>(name: &str, value: &str) -> Result<Vec<T>> {
+    value
+        .split(',')
+        .map(|value| parse_number(name, value))
+        .collect()
+}
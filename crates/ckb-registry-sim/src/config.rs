@@ -0,0 +1,152 @@
+//! The parameters of a simulation.
+
+use std::{fmt, str::FromStr};
+
+use crate::error::{Error, Result};
+
+/// How the clients choose the keys to insert.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distribution {
+    /// The keys are random in the whole key space.
+    Uniform,
+    /// The key space is split into regions, and the regions are chosen by
+    /// Zipf's law, so a few regions are much more popular than the others.
+    Zipf {
+        /// The exponent, the larger, the more skewed.
+        exponent: f64,
+        /// The count of regions.
+        regions: usize,
+    },
+    /// The keys are increasing, like the ones with timestamps or sequence
+    /// numbers.
+    Sequential,
+}
+
+/// How the items are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    /// The count of independent rings, a key goes to the shard chosen by its
+    /// hash.
+    pub shards: usize,
+    /// The max count of keys in a node. A node is a cell in the ring, it's
+    /// split into 2 nodes when it's full.
+    ///
+    /// It's 1 for the single ring, which has a cell for every key.
+    pub bucket_size: usize,
+}
+
+/// The parameters of a simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// The seed of the random numbers, the same config always gives the same
+    /// report.
+    pub seed: u64,
+    /// The count of clients, every client has at most 1 transaction in the
+    /// mempool.
+    pub clients: usize,
+    /// The count of keys inserted by every client.
+    pub inserts_per_client: usize,
+    /// How the keys are chosen.
+    pub distribution: Distribution,
+    /// How the items are stored.
+    pub layout: Layout,
+    /// The count of nodes in every shard before the simulation, the nodes are
+    /// spread evenly in the key space.
+    pub initial_nodes: usize,
+    /// The max count of transactions in a block.
+    pub block_size: usize,
+    /// The simulation stops after this count of blocks, even if there are
+    /// unfinished inserts.
+    pub max_blocks: u64,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            shards: 1,
+            bucket_size: 1,
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            clients: 100,
+            inserts_per_client: 10,
+            distribution: Distribution::Uniform,
+            layout: Layout::default(),
+            initial_nodes: 1_000,
+            block_size: 1_000,
+            max_blocks: 10_000,
+        }
+    }
+}
+
+impl Config {
+    /// Checks that the parameters could be simulated.
+    pub fn check(&self) -> Result<()> {
+        let invalid = |msg: &str| Err(Error::InvalidConfig(msg.to_owned()));
+        if self.layout.shards == 0 {
+            return invalid("at least 1 shard is required");
+        }
+        if self.layout.bucket_size == 0 {
+            return invalid("the bucket size should be at least 1");
+        }
+        if self.initial_nodes < 2 {
+            return invalid("a ring requires at least 2 nodes");
+        }
+        if self.block_size == 0 {
+            return invalid("the block size should be at least 1");
+        }
+        if let Distribution::Zipf { exponent, regions } = self.distribution {
+            if regions == 0 || !exponent.is_finite() || exponent < 0.0 {
+                return invalid("zipf requires some regions and a non-negative exponent");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses `uniform`, `sequential`, `zipf`, `zipf:<EXPONENT>` or
+/// `zipf:<EXPONENT>:<REGIONS>`.
+impl FromStr for Distribution {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let invalid = || Error::Usage(format!("invalid distribution {value}"));
+        let mut parts = value.split(':');
+        let distribution = match parts.next() {
+            Some("uniform") => Self::Uniform,
+            Some("sequential") => Self::Sequential,
+            Some("zipf") => Self::Zipf {
+                exponent: parts
+                    .next()
+                    .map(|exponent| exponent.parse().map_err(|_| invalid()))
+                    .transpose()?
+                    .unwrap_or(1.0),
+                regions: parts
+                    .next()
+                    .map(|regions| regions.parse().map_err(|_| invalid()))
+                    .transpose()?
+                    .unwrap_or(1_000),
+            },
+            _ => return Err(invalid()),
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(distribution)
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Uniform => write!(f, "uniform"),
+            Self::Zipf { exponent, regions } => write!(f, "zipf:{exponent}:{regions}"),
+            Self::Sequential => write!(f, "sequential"),
+        }
+    }
+}
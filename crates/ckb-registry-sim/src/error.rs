@@ -0,0 +1,30 @@
+//! Errors.
+
+use ckb_linked_list_tool::error::Error as ToolError;
+use thiserror::Error;
+
+/// The result of a simulation.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The reasons why a simulation could not be run.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("{0}")]
+    Usage(String),
+    #[error("invalid config: {0}")]
+    InvalidConfig(String),
+    #[error("invalid transaction in block {block}: {reason}")]
+    InvalidTransaction { block: u64, reason: String },
+    #[error("shard {shard} is not a complete ring: {reason}")]
+    IncompleteRing { shard: usize, reason: String },
+}
+
+pub(crate) fn tool_error_name(err: ToolError) -> &'static str {
+    match err {
+        ToolError::EmptyList => "empty list",
+        ToolError::Discontinuous => "discontinuous",
+        ToolError::ReachLastTwice => "reach the last item twice",
+        ToolError::NextIsSelfItem => "the next item is itself",
+        ToolError::Unreachable => "unreachable",
+    }
+}
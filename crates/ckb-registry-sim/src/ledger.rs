@@ -0,0 +1,202 @@
+//! The live nodes of the rings, and the transactions to insert keys.
+
+use std::collections::BTreeMap;
+
+use ckb_linked_list_tool::{
+    check_linked_list_with_ordered_items, check_linked_list_with_unordered_items,
+    error::Error as ToolError,
+    types::{ListItem, ListItemsSummary},
+};
+
+use crate::{config::Layout, error::tool_error_name, rng::mix};
+
+/// A cell in a ring, which holds one or more keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Node {
+    // The unique ID of the cell, like an out point.
+    pub(crate) id: u64,
+    // The first key, which is the key of the node in the linked list.
+    pub(crate) start: u64,
+    pub(crate) next: u64,
+    // The keys in the order of the ring, starting from `start`.
+    pub(crate) keys: Vec<u64>,
+}
+
+/// A transaction which consumes nodes of a shard and creates new ones.
+#[derive(Debug, Clone)]
+pub(crate) struct Tx {
+    pub(crate) shard: usize,
+    pub(crate) inputs: Vec<Node>,
+    pub(crate) outputs: Vec<Node>,
+}
+
+/// The live nodes of all shards.
+#[derive(Debug, Clone)]
+pub(crate) struct Ledger {
+    layout: Layout,
+    shards: Vec<BTreeMap<u64, Node>>,
+    next_id: u64,
+}
+
+impl Ledger {
+    pub(crate) fn new(layout: Layout, initial_nodes: usize) -> Self {
+        let gap = u64::MAX / initial_nodes as u64;
+        let mut next_id = 0;
+        let shards = (0..layout.shards)
+            .map(|_| {
+                (0..initial_nodes as u64)
+                    .map(|index| {
+                        let start = index * gap;
+                        let next = (index + 1) % initial_nodes as u64 * gap;
+                        next_id += 1;
+                        let node = Node {
+                            id: next_id,
+                            start,
+                            next,
+                            keys: vec![start],
+                        };
+                        (start, node)
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            layout,
+            shards,
+            next_id,
+        }
+    }
+
+    /// The shard of a key.
+    pub(crate) fn shard_of(&self, key: u64) -> usize {
+        (mix(key) % self.layout.shards as u64) as usize
+    }
+
+    /// The count of live nodes.
+    pub(crate) fn nodes_count(&self) -> usize {
+        self.shards.iter().map(BTreeMap::len).sum()
+    }
+
+    // The node which covers the key, it's the last node for the keys before
+    // the first node.
+    fn covering(&self, shard: usize, key: u64) -> &Node {
+        let nodes = &self.shards[shard];
+        nodes
+            .range(..=key)
+            .next_back()
+            .or_else(|| nodes.iter().next_back())
+            .map(|(_, node)| node)
+            .expect("a ring has nodes")
+    }
+
+    /// Builds a transaction to insert the key, against the live nodes.
+    ///
+    /// Returns `None` if the key exists.
+    pub(crate) fn build_insert(&mut self, key: u64) -> Option<Tx> {
+        let shard = self.shard_of(key);
+        let node = self.covering(shard, key).clone();
+        if node.keys.contains(&key) {
+            return None;
+        }
+        let mut keys = node.keys.clone();
+        keys.push(key);
+        keys.sort_by_key(|key| (*key < node.start, *key));
+        let outputs = if keys.len() <= self.layout.bucket_size {
+            vec![Node {
+                id: self.new_id(),
+                keys,
+                ..node.clone()
+            }]
+        } else {
+            let upper = keys.split_off(keys.len() / 2);
+            let lower = Node {
+                id: self.new_id(),
+                start: node.start,
+                next: upper[0],
+                keys,
+            };
+            let upper = Node {
+                id: self.new_id(),
+                start: upper[0],
+                next: node.next,
+                keys: upper,
+            };
+            vec![lower, upper]
+        };
+        Some(Tx {
+            shard,
+            inputs: vec![node],
+            outputs,
+        })
+    }
+
+    /// Whether all inputs of the transaction are live.
+    pub(crate) fn is_live(&self, tx: &Tx) -> bool {
+        let nodes = &self.shards[tx.shard];
+        tx.inputs
+            .iter()
+            .all(|input| nodes.get(&input.start).map(|node| node.id) == Some(input.id))
+    }
+
+    /// Checks the transaction by the rules of the registry contract, then
+    /// applies it.
+    pub(crate) fn commit(&mut self, tx: &Tx) -> Result<(), String> {
+        if !self.is_live(tx) {
+            return Err("the inputs are not live".to_owned());
+        }
+        let inputs = summary(&tx.inputs)?;
+        let outputs = summary(&tx.outputs)?;
+        if inputs != outputs {
+            return Err(format!(
+                "the inputs cover {inputs:?}, but the outputs cover {outputs:?}"
+            ));
+        }
+        if tx
+            .outputs
+            .iter()
+            .any(|node| node.keys.len() > self.layout.bucket_size)
+        {
+            return Err("a node holds too many keys".to_owned());
+        }
+        let nodes = &mut self.shards[tx.shard];
+        for input in &tx.inputs {
+            nodes.remove(&input.start);
+        }
+        for output in &tx.outputs {
+            nodes.insert(output.start, output.clone());
+        }
+        Ok(())
+    }
+
+    /// Checks that every shard is a complete ring, returns the first shard
+    /// which is not.
+    pub(crate) fn check(&self) -> Result<(), (usize, String)> {
+        for (shard, nodes) in self.shards.iter().enumerate() {
+            let items = nodes.values().map(|node| (node.start, node.next));
+            let is_complete = check_linked_list_with_ordered_items(items, parse_item)
+                .map_err(|err| (shard, tool_error_name(err).to_owned()))?
+                .is_complete();
+            if !is_complete {
+                return Err((shard, "the ring is not closed".to_owned()));
+            }
+        }
+        Ok(())
+    }
+
+    fn new_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+// The first key and the next key of the last node, of the continuous nodes.
+fn summary(nodes: &[Node]) -> Result<(u64, u64), String> {
+    let items = nodes.iter().map(|node| (node.start, node.next));
+    check_linked_list_with_unordered_items(items, parse_item)
+        .map(|summary: ListItemsSummary<u64>| (summary.start, summary.end))
+        .map_err(|err| tool_error_name(err).to_owned())
+}
+
+fn parse_item((curr, next): (u64, u64)) -> Result<ListItem<u64>, ToolError> {
+    Ok(ListItem::new(curr, next))
+}
@@ -0,0 +1,25 @@
+//! A deterministic simulator of the contention, when many clients insert
+//! keys into global registry instances concurrently.
+//!
+//! The items are stored by a [`Layout`]: a single ring has a cell for every
+//! key, a bucketed ring has several keys in a cell, and the keys could be
+//! split into several independent rings by their hashes. The clients submit
+//! inserts with keys chosen by a [`Distribution`], against a model of the
+//! mempool and the blocks, see [`simulate`].
+//!
+//! Every transaction is checked by [`ckb_linked_list_tool`], as the registry
+//! contract does, and the [`Report`] shows the conflict rate, the latency and
+//! the cells touched.
+
+pub mod args;
+pub mod config;
+pub mod error;
+mod ledger;
+mod report;
+mod rng;
+mod sim;
+
+pub use config::{Config, Distribution, Layout};
+pub use error::{Error, Result};
+pub use report::{Latency, Report};
+pub use sim::simulate;
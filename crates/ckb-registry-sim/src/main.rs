@@ -0,0 +1,56 @@
+use std::{env, process};
+
+use ckb_registry_sim::args::{parse_args, USAGE};
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "--help") {
+        print!("{USAGE}");
+        return;
+    }
+    let configs = match parse_args(&args) {
+        Ok(configs) => configs,
+        Err(err) => exit_with(&format!("error: {err}\n\n{USAGE}")),
+    };
+    println!(
+        "{:<16} {:>6} {:>7} {:>7} {:>6} {:>7} {:>9} {:>9} {:>8} {:>5} {:>8} {:>9}",
+        "distribution",
+        "shards",
+        "bucket",
+        "clients",
+        "block",
+        "blocks",
+        "inserted",
+        "conflicts",
+        "latency",
+        "p99",
+        "cells",
+        "per block"
+    );
+    for config in configs {
+        let report = match ckb_registry_sim::simulate(&config) {
+            Ok(report) => report,
+            Err(err) => exit_with(&format!("error: {err}")),
+        };
+        println!(
+            "{:<16} {:>6} {:>7} {:>7} {:>6} {:>7} {:>9} {:>8.2}% {:>8.2} {:>5} {:>8.2} {:>9.2}",
+            config.distribution.to_string(),
+            config.layout.shards,
+            config.layout.bucket_size,
+            config.clients,
+            config.block_size,
+            report.blocks,
+            report.inserted,
+            report.conflict_rate() * 100.0,
+            report.latency.mean,
+            report.latency.p99,
+            report.cells_touched_per_insert(),
+            report.inserts_per_block()
+        );
+    }
+}
+
+fn exit_with(msg: &str) -> ! {
+    eprintln!("{msg}");
+    process::exit(1);
+}
@@ -0,0 +1,119 @@
+//! The results of a simulation.
+
+use std::fmt;
+
+/// The latencies of the inserts in blocks, from the block when an insert is
+/// submitted for the first time, to the block which commits it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Latency {
+    /// The mean.
+    pub mean: f64,
+    /// The median.
+    pub p50: u64,
+    /// The 99th percentile.
+    pub p99: u64,
+    /// The max.
+    pub max: u64,
+}
+
+/// The results of a simulation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    /// The count of blocks.
+    pub blocks: u64,
+    /// The count of committed inserts.
+    pub inserted: usize,
+    /// The count of keys which are not inserted since they exist already.
+    pub duplicates: usize,
+    /// The count of keys which are not inserted when the simulation stops.
+    pub unfinished: usize,
+    /// The count of transactions submitted to the mempool, including the
+    /// rejected ones.
+    pub submissions: usize,
+    /// The count of transactions rejected by the mempool, since they spend
+    /// the same cells as the transactions in it.
+    pub conflicts: usize,
+    /// The latencies of the committed inserts.
+    pub latency: Latency,
+    /// The count of cells consumed by the committed transactions.
+    pub inputs: usize,
+    /// The count of cells created by the committed transactions.
+    pub outputs: usize,
+    /// The count of live nodes when the simulation stops.
+    pub nodes: usize,
+}
+
+impl Latency {
+    pub(crate) fn new(mut latencies: Vec<u64>) -> Self {
+        if latencies.is_empty() {
+            return Self::default();
+        }
+        latencies.sort_unstable();
+        let percentile = |p: usize| latencies[(latencies.len() - 1) * p / 100];
+        Self {
+            mean: latencies.iter().sum::<u64>() as f64 / latencies.len() as f64,
+            p50: percentile(50),
+            p99: percentile(99),
+            max: latencies[latencies.len() - 1],
+        }
+    }
+}
+
+impl Report {
+    /// The ratio of the rejected submissions.
+    pub fn conflict_rate(&self) -> f64 {
+        ratio(self.conflicts, self.submissions)
+    }
+
+    /// The count of cells consumed and created, for every committed insert.
+    pub fn cells_touched_per_insert(&self) -> f64 {
+        ratio(self.inputs + self.outputs, self.inserted)
+    }
+
+    /// The count of committed inserts in a block.
+    pub fn inserts_per_block(&self) -> f64 {
+        ratio(self.inserted, self.blocks as usize)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "blocks: {}", self.blocks)?;
+        writeln!(
+            f,
+            "inserts: {} committed, {} duplicated, {} unfinished, {:.2} per block",
+            self.inserted,
+            self.duplicates,
+            self.unfinished,
+            self.inserts_per_block()
+        )?;
+        writeln!(
+            f,
+            "submissions: {}, {} conflicts, conflict rate {:.2}%",
+            self.submissions,
+            self.conflicts,
+            self.conflict_rate() * 100.0
+        )?;
+        writeln!(
+            f,
+            "latency in blocks: mean {:.2}, p50 {}, p99 {}, max {}",
+            self.latency.mean, self.latency.p50, self.latency.p99, self.latency.max
+        )?;
+        writeln!(
+            f,
+            "cells touched: {} inputs, {} outputs, {:.2} per insert",
+            self.inputs,
+            self.outputs,
+            self.cells_touched_per_insert()
+        )?;
+        writeln!(f, "live nodes: {}", self.nodes)
+    }
+}
+
+fn ratio(a: usize, b: usize) -> f64 {
+    if b == 0 {
+        0.0
+    } else {
+        a as f64 / b as f64
+    }
+}
@@ -0,0 +1,106 @@
+//! A deterministic random number generator, and the key distributions.
+
+use crate::config::Distribution;
+
+/// A SplitMix64 generator, the same seed always gives the same numbers.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Creates a generator with the seed.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// The next random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        mix(self.0)
+    }
+
+    /// A random number in `[0, n)`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// A random number in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles the values in place.
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for index in (1..values.len()).rev() {
+            let other = self.below(index as u64 + 1) as usize;
+            values.swap(index, other);
+        }
+    }
+}
+
+/// The finalizer of SplitMix64, it's used to hash the keys into shards too.
+pub fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+// The sequential keys are after the middle of the key space.
+const SEQUENTIAL_START: u64 = u64::MAX / 2;
+
+/// Draws the keys to insert.
+#[derive(Debug, Clone)]
+pub(crate) struct KeySource {
+    distribution: Distribution,
+    // The cumulative probabilities of the Zipf ranks.
+    cdf: Vec<f64>,
+    next_sequential: u64,
+}
+
+impl KeySource {
+    pub(crate) fn new(distribution: Distribution) -> Self {
+        let cdf = match distribution {
+            Distribution::Zipf { exponent, regions } => {
+                let mut sum = 0.0;
+                let mut cdf = (1..=regions)
+                    .map(|rank| {
+                        sum += 1.0 / (rank as f64).powf(exponent);
+                        sum
+                    })
+                    .collect::<Vec<_>>();
+                for value in &mut cdf {
+                    *value /= sum;
+                }
+                cdf
+            }
+            Distribution::Uniform | Distribution::Sequential => Vec::new(),
+        };
+        Self {
+            distribution,
+            cdf,
+            next_sequential: SEQUENTIAL_START,
+        }
+    }
+
+    pub(crate) fn next_key(&mut self, rng: &mut Rng) -> u64 {
+        match self.distribution {
+            Distribution::Uniform => rng.next_u64(),
+            // The key space is split into regions, and the popular regions
+            // are scattered in the space. A key is random in its region.
+            Distribution::Zipf { regions, .. } => {
+                let point = rng.next_f64();
+                let rank = self
+                    .cdf
+                    .partition_point(|value| *value < point)
+                    .min(regions - 1);
+                let width = u64::MAX / regions as u64;
+                let region = mix(rank as u64) % regions as u64;
+                region * width + rng.below(width)
+            }
+            Distribution::Sequential => {
+                let key = self.next_sequential;
+                self.next_sequential += 1;
+                key
+            }
+        }
+    }
+}
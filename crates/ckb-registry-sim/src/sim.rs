@@ -0,0 +1,134 @@
+//! The block and mempool model.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    config::Config,
+    error::{Error, Result},
+    ledger::{Ledger, Tx},
+    report::{Latency, Report},
+    rng::{KeySource, Rng},
+};
+
+struct Client {
+    remaining: usize,
+    pending: Option<Pending>,
+}
+
+struct Pending {
+    key: u64,
+    // The block when the key is submitted for the first time.
+    since: u64,
+    in_mempool: bool,
+}
+
+struct MempoolTx {
+    client: usize,
+    tx: Tx,
+}
+
+/// Runs a simulation.
+///
+/// In every block, the clients without a transaction in the mempool build a
+/// transaction against the live cells, and submit it in a random order. The
+/// mempool rejects a transaction if it spends a cell which is spent by
+/// another transaction in the mempool, and the client tries again in the next
+/// block. Then the block commits the transactions in the mempool, in the order
+/// they are accepted, at most `block_size` of them.
+///
+/// Every committed transaction is checked by the same rules as the registry
+/// contract, by [`ckb_linked_list_tool`], and all rings are checked after the
+/// last block.
+pub fn simulate(config: &Config) -> Result<Report> {
+    config.check()?;
+    let mut rng = Rng::new(config.seed);
+    let mut keys = KeySource::new(config.distribution);
+    let mut ledger = Ledger::new(config.layout, config.initial_nodes);
+    let mut clients = (0..config.clients)
+        .map(|_| Client {
+            remaining: config.inserts_per_client,
+            pending: None,
+        })
+        .collect::<Vec<_>>();
+    let mut order = (0..config.clients).collect::<Vec<_>>();
+    let mut mempool = VecDeque::<MempoolTx>::new();
+    let mut spent = HashSet::new();
+    let mut latencies = Vec::new();
+    let mut report = Report::default();
+
+    while report.blocks < config.max_blocks {
+        let block = report.blocks;
+        rng.shuffle(&mut order);
+        for &index in &order {
+            let client = &mut clients[index];
+            let tx = loop {
+                let pending = match &client.pending {
+                    Some(pending) if pending.in_mempool => break None,
+                    Some(pending) => pending,
+                    None if client.remaining == 0 => break None,
+                    None => {
+                        client.remaining -= 1;
+                        client.pending.insert(Pending {
+                            key: keys.next_key(&mut rng),
+                            since: block,
+                            in_mempool: false,
+                        })
+                    }
+                };
+                match ledger.build_insert(pending.key) {
+                    Some(tx) => break Some(tx),
+                    None => {
+                        report.duplicates += 1;
+                        client.pending = None;
+                    }
+                }
+            };
+            let Some(tx) = tx else {
+                continue;
+            };
+            report.submissions += 1;
+            if tx.inputs.iter().any(|input| spent.contains(&input.id)) {
+                report.conflicts += 1;
+                continue;
+            }
+            spent.extend(tx.inputs.iter().map(|input| input.id));
+            if let Some(pending) = &mut client.pending {
+                pending.in_mempool = true;
+            }
+            mempool.push_back(MempoolTx { client: index, tx });
+        }
+
+        if mempool.is_empty() && clients.iter().all(|client| client.pending.is_none()) {
+            break;
+        }
+        for _ in 0..config.block_size {
+            let Some(MempoolTx { client, tx }) = mempool.pop_front() else {
+                break;
+            };
+            ledger
+                .commit(&tx)
+                .map_err(|reason| Error::InvalidTransaction { block, reason })?;
+            for input in &tx.inputs {
+                spent.remove(&input.id);
+            }
+            report.inserted += 1;
+            report.inputs += tx.inputs.len();
+            report.outputs += tx.outputs.len();
+            if let Some(pending) = clients[client].pending.take() {
+                latencies.push(block - pending.since + 1);
+            }
+        }
+        report.blocks += 1;
+    }
+
+    ledger
+        .check()
+        .map_err(|(shard, reason)| Error::IncompleteRing { shard, reason })?;
+    report.unfinished = clients
+        .iter()
+        .map(|client| client.remaining + usize::from(client.pending.is_some()))
+        .sum();
+    report.latency = Latency::new(latencies);
+    report.nodes = ledger.nodes_count();
+    Ok(report)
+}
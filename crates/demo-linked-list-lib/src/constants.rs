@@ -0,0 +1,37 @@
+//! Constants.
+
+/// The flag in `InstanceArgs` to enable the sentinel items.
+///
+/// When it's set, the instance always contains 2 sentinel items, which use
+/// [`SENTINEL_MIN_KEY`] and [`SENTINEL_MAX_KEY`] as their keys, so that the
+/// instance could be created without any real items.
+pub const FLAG_SENTINELS: u8 = 0b0000_0001;
+
+/// The flag in `InstanceArgs` to require the `RegistryEvents` in witnesses.
+///
+/// When it's set, every create or update should list the changed keys in the
+/// `events` of the `RegistryOutput`, which are checked against the actual
+/// changes, so indexers could trust the events without parsing the list.
+pub const FLAG_EVENTS: u8 = 0b0000_0010;
+
+/// The rule in `KeyPolicy` to require keys to be valid UTF-8, NFC-normalized
+/// and case-folded.
+pub const UNICODE_RULE_NFC_CASEFOLD: u8 = 0b0000_0001;
+
+/// The rule in `KeyPolicy` to reject keys which mix letters in different
+/// scripts, see [`crate::unicode::is_single_script`].
+pub const UNICODE_RULE_SINGLE_SCRIPT: u8 = 0b0000_0010;
+
+/// All known rules in `KeyPolicy`, other bits should not be set.
+pub const UNICODE_RULES_ALL: u8 = UNICODE_RULE_NFC_CASEFOLD | UNICODE_RULE_SINGLE_SCRIPT;
+
+/// The key of the minimum sentinel item.
+///
+/// The empty key is the smallest key, so no item could be less than it.
+pub const SENTINEL_MIN_KEY: &[u8] = &[];
+
+/// The key of the maximum sentinel item.
+///
+/// Items larger than it are not allowed, so the maximum sentinel item always
+/// points back to the minimum sentinel item.
+pub const SENTINEL_MAX_KEY: &[u8] = &[0xff; 32];
@@ -0,0 +1,104 @@
+//! The item data in all versions.
+//!
+//! The item data of version 1 is a `DemoData` as is, and since version 2, the
+//! item data is a `VersionedDemoData`.
+
+use molecule::{bytes::Bytes, error::VerificationResult, prelude::*};
+
+use crate::types;
+
+/// The version of `DemoData`.
+pub const VERSION_1: u8 = 1;
+
+/// The version of `DemoDataV2`.
+pub const VERSION_2: u8 = 2;
+
+/// The latest version of the item data.
+pub const LATEST_VERSION: u8 = VERSION_2;
+
+/// A reader of the item data in any version.
+#[derive(Clone, Copy)]
+pub enum DemoItemReader<'r> {
+    /// The item data of version 1.
+    V1(types::DemoDataReader<'r>),
+    /// The item data of version 2.
+    V2(types::DemoDataV2Reader<'r>),
+}
+
+impl<'r> DemoItemReader<'r> {
+    /// Parses the item data in any version.
+    pub fn from_cell_data(data: &'r [u8]) -> VerificationResult<Self> {
+        if let Ok(reader) = types::DemoDataReader::from_slice(data) {
+            return Ok(Self::V1(reader));
+        }
+        let versioned = types::VersionedDemoDataReader::from_slice(data)?;
+        match versioned.to_enum() {
+            types::VersionedDemoDataUnionReader::DemoDataV2(reader) => Ok(Self::V2(reader)),
+        }
+    }
+
+    /// The version of the item data.
+    pub fn version(&self) -> u8 {
+        match self {
+            Self::V1(_) => VERSION_1,
+            Self::V2(_) => VERSION_2,
+        }
+    }
+
+    /// The payload of the item.
+    pub fn demo(&self) -> types::BytesReader<'r> {
+        match self {
+            Self::V1(reader) => reader.demo(),
+            Self::V2(reader) => reader.demo(),
+        }
+    }
+
+    /// The key of the current item.
+    pub fn curr(&self) -> types::BytesReader<'r> {
+        match self {
+            Self::V1(reader) => reader.curr(),
+            Self::V2(reader) => reader.curr(),
+        }
+    }
+
+    /// The key of the next item.
+    pub fn next(&self) -> types::BytesReader<'r> {
+        match self {
+            Self::V1(reader) => reader.next(),
+            Self::V2(reader) => reader.next(),
+        }
+    }
+
+    /// The lock hash of the owner, the item data of version 1 has no owner.
+    pub fn owner(&self) -> Option<types::Byte32Reader<'r>> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(reader) => reader.owner().to_opt(),
+        }
+    }
+}
+
+impl types::DemoDataV2 {
+    pub fn new_from_raw_slices(demo: &[u8], curr: &[u8], next: &[u8]) -> Self {
+        Self::new_builder()
+            .demo(types::Bytes::new_from_raw_slice(demo))
+            .curr(types::Bytes::new_from_raw_slice(curr))
+            .next(types::Bytes::new_from_raw_slice(next))
+            .build()
+    }
+
+    pub fn with_owner(self, owner: [u8; 32]) -> Self {
+        let owner_opt = types::Byte32Opt::new_builder()
+            .set(Some(owner.into()))
+            .build();
+        self.as_builder().owner(owner_opt).build()
+    }
+
+    /// The cell data, which is wrapped in `VersionedDemoData`.
+    pub fn to_cell_data(&self) -> Bytes {
+        types::VersionedDemoData::new_builder()
+            .set(self.clone())
+            .build()
+            .as_bytes()
+    }
+}
@@ -0,0 +1,184 @@
+//! Rules to validate keys of a registry instance.
+//!
+//! The rules are fixed in the `KeyPolicy` when the instance is created, then
+//! every inserted key should be checked against it.
+
+use core::fmt;
+
+use molecule::prelude::*;
+
+use crate::{
+    constants::{UNICODE_RULES_ALL, UNICODE_RULE_NFC_CASEFOLD, UNICODE_RULE_SINGLE_SCRIPT},
+    types, unicode,
+};
+
+/// The allowed bytes of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ByteClass {
+    /// Any bytes.
+    Raw = 0,
+    /// A DNS label: lowercase alphanumerics and hyphens, and hyphens are not
+    /// allowed at the start or the end.
+    DnsLabel = 1,
+    /// Lowercase alphanumerics only.
+    LowerAlphanumeric = 2,
+}
+
+/// The reasons why a key or a policy is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPolicyError {
+    /// The policy itself is invalid.
+    InvalidPolicy,
+    /// The key is shorter than the minimum length.
+    TooShort,
+    /// The key is longer than the maximum length.
+    TooLong,
+    /// The key contains bytes which are not allowed by the byte class.
+    InvalidByte,
+    /// The key starts with a forbidden prefix.
+    ForbiddenPrefix,
+    /// The key is not valid UTF-8.
+    InvalidUtf8,
+    /// The key is not NFC-normalized.
+    NotNormalized,
+    /// The key is not case-folded.
+    NotCaseFolded,
+    /// The key mixes letters in different scripts.
+    MixedScripts,
+}
+
+impl TryFrom<u8> for ByteClass {
+    type Error = KeyPolicyError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Raw),
+            1 => Ok(Self::DnsLabel),
+            2 => Ok(Self::LowerAlphanumeric),
+            _ => Err(KeyPolicyError::InvalidPolicy),
+        }
+    }
+}
+
+impl ByteClass {
+    /// Checks whether all bytes of the key are allowed.
+    pub fn check(self, key: &[u8]) -> bool {
+        match self {
+            Self::Raw => true,
+            Self::DnsLabel => {
+                key.iter().all(|b| is_lower_alphanumeric(*b) || *b == b'-')
+                    && key.first() != Some(&b'-')
+                    && key.last() != Some(&b'-')
+            }
+            Self::LowerAlphanumeric => key.iter().all(|b| is_lower_alphanumeric(*b)),
+        }
+    }
+}
+
+impl fmt::Display for KeyPolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self {
+            Self::InvalidPolicy => "the key policy is invalid",
+            Self::TooShort => "the key is too short",
+            Self::TooLong => "the key is too long",
+            Self::InvalidByte => "the key contains disallowed bytes",
+            Self::ForbiddenPrefix => "the key starts with a forbidden prefix",
+            Self::InvalidUtf8 => "the key is not valid UTF-8",
+            Self::NotNormalized => "the key is not NFC-normalized",
+            Self::NotCaseFolded => "the key is not case-folded",
+            Self::MixedScripts => "the key mixes letters in different scripts",
+        };
+        write!(f, "{msg}")
+    }
+}
+
+impl types::KeyPolicy {
+    /// Creates a new key policy.
+    pub fn new_with_rules(
+        min_length: u32,
+        max_length: u32,
+        byte_class: ByteClass,
+        forbidden_prefixes: &[&[u8]],
+    ) -> Self {
+        Self::new_builder()
+            .min_length(min_length.into())
+            .max_length(max_length.into())
+            .byte_class((byte_class as u8).into())
+            .forbidden_prefixes(types::BytesVec::new_from_raw_slices(forbidden_prefixes))
+            .build()
+    }
+
+    /// Sets the Unicode rules, see `UNICODE_RULE_*` in [`crate::constants`].
+    pub fn with_unicode_rules(self, rules: u8) -> Self {
+        self.as_builder().unicode_rules(rules.into()).build()
+    }
+}
+
+impl types::KeyPolicyReader<'_> {
+    /// Returns the allowed byte class.
+    pub fn byte_class_enum(&self) -> Result<ByteClass, KeyPolicyError> {
+        u8::from(self.byte_class()).try_into()
+    }
+
+    /// Checks whether the policy itself is valid.
+    pub fn verify_rules(&self) -> Result<(), KeyPolicyError> {
+        self.byte_class_enum()?;
+        if self.min_length().to_u32() > self.max_length().to_u32() {
+            return Err(KeyPolicyError::InvalidPolicy);
+        }
+        if u8::from(self.unicode_rules()) & !UNICODE_RULES_ALL != 0 {
+            return Err(KeyPolicyError::InvalidPolicy);
+        }
+        Ok(())
+    }
+
+    /// Checks a key against the policy.
+    pub fn check_key(&self, key: &[u8]) -> Result<(), KeyPolicyError> {
+        let byte_class = self.byte_class_enum()?;
+        let len = key.len();
+        if len < self.min_length().to_u32() as usize {
+            return Err(KeyPolicyError::TooShort);
+        }
+        if len > self.max_length().to_u32() as usize {
+            return Err(KeyPolicyError::TooLong);
+        }
+        if !byte_class.check(key) {
+            return Err(KeyPolicyError::InvalidByte);
+        }
+        if self
+            .forbidden_prefixes()
+            .iter()
+            .any(|prefix| key.starts_with(prefix.raw_data()))
+        {
+            return Err(KeyPolicyError::ForbiddenPrefix);
+        }
+        check_unicode_rules(u8::from(self.unicode_rules()), key)
+    }
+}
+
+/// Checks a key against the Unicode rules.
+///
+/// No checks if no rules are set, otherwise the key should be valid UTF-8.
+pub fn check_unicode_rules(rules: u8, key: &[u8]) -> Result<(), KeyPolicyError> {
+    if rules == 0 {
+        return Ok(());
+    }
+    let key = core::str::from_utf8(key).map_err(|_| KeyPolicyError::InvalidUtf8)?;
+    if rules & UNICODE_RULE_NFC_CASEFOLD != 0 {
+        if !unicode::is_nfc(key) {
+            return Err(KeyPolicyError::NotNormalized);
+        }
+        if !unicode::is_case_folded(key) {
+            return Err(KeyPolicyError::NotCaseFolded);
+        }
+    }
+    if rules & UNICODE_RULE_SINGLE_SCRIPT != 0 && !unicode::is_single_script(key) {
+        return Err(KeyPolicyError::MixedScripts);
+    }
+    Ok(())
+}
+
+fn is_lower_alphanumeric(b: u8) -> bool {
+    b.is_ascii_lowercase() || b.is_ascii_digit()
+}
@@ -1,12 +1,22 @@
 #![no_std]
 
+extern crate alloc;
+
 use core::cmp::{Ord, Ordering};
 
 use molecule::{bytes::Bytes, prelude::*, Number, NUMBER_SIZE};
 
+pub mod constants;
+pub mod item;
+pub mod key_policy;
+pub mod merkle;
 #[allow(warnings)]
 #[allow(clippy::all)]
 pub mod types;
+pub mod unicode;
+#[allow(dead_code)]
+#[rustfmt::skip]
+mod unicode_tables;
 
 impl Ord for types::BytesReader<'_> {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -58,6 +68,71 @@ impl types::Bytes {
     }
 }
 
+impl From<u32> for types::Uint32 {
+    fn from(value: u32) -> Self {
+        let bytes = value.to_le_bytes().map(Into::into);
+        Self::new_builder().set(bytes).build()
+    }
+}
+
+impl types::Uint32Reader<'_> {
+    pub fn to_u32(&self) -> u32 {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(self.raw_data());
+        u32::from_le_bytes(bytes)
+    }
+}
+
+impl From<u64> for types::Uint64 {
+    fn from(value: u64) -> Self {
+        let bytes = value.to_le_bytes().map(Into::into);
+        Self::new_builder().set(bytes).build()
+    }
+}
+
+impl types::Uint64Reader<'_> {
+    pub fn to_u64(&self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.raw_data());
+        u64::from_le_bytes(bytes)
+    }
+}
+
+impl From<[u8; 32]> for types::Byte32 {
+    fn from(value: [u8; 32]) -> Self {
+        Self::new_builder().set(value.map(Into::into)).build()
+    }
+}
+
+impl types::Byte32Reader<'_> {
+    pub fn to_array(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(self.raw_data());
+        bytes
+    }
+}
+
+impl types::BytesVec {
+    pub fn new_from_raw_slices(slices: &[&[u8]]) -> Self {
+        Self::new_builder()
+            .extend(
+                slices
+                    .iter()
+                    .map(|slice| types::Bytes::new_from_raw_slice(slice)),
+            )
+            .build()
+    }
+}
+
+impl types::KeyEvent {
+    pub fn new_from_raw_parts(key: &[u8], index: u32) -> Self {
+        Self::new_builder()
+            .key(types::Bytes::new_from_raw_slice(key))
+            .index(index.into())
+            .build()
+    }
+}
+
 impl types::DemoData {
     pub fn new_from_raw_slices(demo: &[u8], curr: &[u8], next: &[u8]) -> Self {
         Self::new_builder()
@@ -67,3 +142,106 @@ impl types::DemoData {
             .build()
     }
 }
+
+impl types::InstanceArgs {
+    pub fn new_with_flags(flags: u8) -> Self {
+        Self::new_builder().flags(flags.into()).build()
+    }
+
+    pub fn new_with_key_policy(flags: u8, key_policy: types::KeyPolicy) -> Self {
+        Self::new_builder()
+            .flags(flags.into())
+            .key_policy(
+                types::KeyPolicyOpt::new_builder()
+                    .set(Some(key_policy))
+                    .build(),
+            )
+            .build()
+    }
+
+    pub fn with_config_type_hash(self, config_type_hash: [u8; 32]) -> Self {
+        let config_type_hash_opt = types::Byte32Opt::new_builder()
+            .set(Some(config_type_hash.into()))
+            .build();
+        self.as_builder()
+            .config_type_hash(config_type_hash_opt)
+            .build()
+    }
+
+    pub fn with_parent(self, type_hash: [u8; 32], key: &[u8]) -> Self {
+        let parent = types::ParentRef::new_builder()
+            .type_hash(type_hash.into())
+            .key(types::Bytes::new_from_raw_slice(key))
+            .build();
+        let parent_opt = types::ParentRefOpt::new_builder().set(Some(parent)).build();
+        self.as_builder().parent(parent_opt).build()
+    }
+}
+
+impl types::InstanceArgsReader<'_> {
+    pub fn has_sentinels(&self) -> bool {
+        u8::from(self.flags()) & constants::FLAG_SENTINELS != 0
+    }
+
+    pub fn requires_events(&self) -> bool {
+        u8::from(self.flags()) & constants::FLAG_EVENTS != 0
+    }
+}
+
+impl types::RegistryConfigReader<'_> {
+    pub fn is_destroy_scheduled(&self) -> bool {
+        u8::from(self.destroy_scheduled()) != 0
+    }
+}
+
+impl types::RegistryConfig {
+    pub fn new_with_fields(version: u32, name: &[u8], admin_lock_hash: [u8; 32]) -> Self {
+        Self::new_builder()
+            .version(version.into())
+            .name(types::Bytes::new_from_raw_slice(name))
+            .admin_lock_hash(admin_lock_hash.into())
+            .build()
+    }
+
+    pub fn with_validator(self, code_hash: [u8; 32], hash_type: u8) -> Self {
+        let validator = types::ValidatorRef::new_builder()
+            .code_hash(code_hash.into())
+            .hash_type(hash_type.into())
+            .build();
+        let validator_opt = types::ValidatorRefOpt::new_builder()
+            .set(Some(validator))
+            .build();
+        self.as_builder().validator(validator_opt).build()
+    }
+
+    pub fn with_migration(self, predecessor: [u8; 32], successor: [u8; 32]) -> Self {
+        let migration = types::Migration::new_builder()
+            .predecessor(predecessor.into())
+            .successor(successor.into())
+            .build();
+        let migration_opt = types::MigrationOpt::new_builder()
+            .set(Some(migration))
+            .build();
+        self.as_builder().migration(migration_opt).build()
+    }
+
+    pub fn with_destroy_delay(self, epochs: u64) -> Self {
+        let destroy_delay_opt = types::Uint64Opt::new_builder()
+            .set(Some(epochs.into()))
+            .build();
+        self.as_builder().destroy_delay(destroy_delay_opt).build()
+    }
+
+    pub fn with_destroy_scheduled(self, scheduled: bool) -> Self {
+        self.as_builder()
+            .destroy_scheduled(u8::from(scheduled).into())
+            .build()
+    }
+
+    pub fn with_reserved_keys(self, reserved_keys: types::MerkleSet) -> Self {
+        let reserved_keys_opt = types::MerkleSetOpt::new_builder()
+            .set(Some(reserved_keys))
+            .build();
+        self.as_builder().reserved_keys(reserved_keys_opt).build()
+    }
+}
@@ -0,0 +1,243 @@
+//! A Merkle tree of sorted keys, to prove whether a key is in a set or not.
+//!
+//! The leaves are the hashes of the sorted and unique keys. At each level, 2
+//! adjacent nodes are hashed into their parent, and if the count of nodes is
+//! odd, the last node is promoted as is. The count of leaves is stored with
+//! the root in a `MerkleSet`, so the index of a leaf is bound by its path.
+//!
+//! A key is proven to be in the set by the leaf of itself, or not in the set
+//! by the adjacent leaves around it.
+
+use alloc::vec::Vec;
+
+use ckb_hash::new_blake2b;
+use molecule::prelude::*;
+
+use crate::types;
+
+/// The size of a hash.
+pub const HASH_SIZE: usize = 32;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Whether a key is in a set or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Membership {
+    /// The key is in the set.
+    Member,
+    /// The key is not in the set.
+    NonMember,
+}
+
+/// The reasons why a proof is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleError {
+    /// The path of a leaf doesn't match the root.
+    InvalidPath,
+    /// The leaves could not prove whether the key is in the set.
+    InvalidLeaves,
+}
+
+/// The hash of a leaf.
+pub fn leaf_hash(key: &[u8]) -> [u8; HASH_SIZE] {
+    let mut blake2b = new_blake2b();
+    blake2b.update(&[LEAF_PREFIX]);
+    blake2b.update(key);
+    let mut ret = [0; HASH_SIZE];
+    blake2b.finalize(&mut ret);
+    ret
+}
+
+/// The hash of a parent node.
+pub fn node_hash(left: &[u8; HASH_SIZE], right: &[u8; HASH_SIZE]) -> [u8; HASH_SIZE] {
+    let mut blake2b = new_blake2b();
+    blake2b.update(&[NODE_PREFIX]);
+    blake2b.update(left);
+    blake2b.update(right);
+    let mut ret = [0; HASH_SIZE];
+    blake2b.finalize(&mut ret);
+    ret
+}
+
+/// Computes the root from a leaf and the siblings on its path, returns `None`
+/// if the count of siblings doesn't match the index.
+pub fn compute_root<I>(
+    leaf: [u8; HASH_SIZE],
+    index: u32,
+    count: u32,
+    siblings: I,
+) -> Option<[u8; HASH_SIZE]>
+where
+    I: IntoIterator<Item = [u8; HASH_SIZE]>,
+{
+    if index >= count {
+        return None;
+    }
+    let mut siblings = siblings.into_iter();
+    let (mut node, mut index, mut width) = (leaf, index, count);
+    while width > 1 {
+        if index % 2 == 1 {
+            node = node_hash(&siblings.next()?, &node);
+        } else if index + 1 < width {
+            node = node_hash(&node, &siblings.next()?);
+        }
+        index /= 2;
+        width = width.div_ceil(2);
+    }
+    if siblings.next().is_some() {
+        return None;
+    }
+    Some(node)
+}
+
+fn verify_leaf(
+    set: &types::MerkleSetReader<'_>,
+    leaf: &types::MerkleLeafProofReader<'_>,
+) -> Result<(), MerkleError> {
+    let siblings = leaf.siblings();
+    let root = compute_root(
+        leaf_hash(leaf.key().raw_data()),
+        leaf.index().to_u32(),
+        set.count().to_u32(),
+        siblings.iter().map(|sibling| sibling.to_array()),
+    );
+    if root.is_some_and(|root| root[..] == set.root().raw_data()[..]) {
+        Ok(())
+    } else {
+        Err(MerkleError::InvalidPath)
+    }
+}
+
+impl types::MerkleSetReader<'_> {
+    /// Verifies whether the key of the proof is in the set or not.
+    pub fn verify_key(
+        &self,
+        proof: &types::MerkleKeyProofReader<'_>,
+    ) -> Result<Membership, MerkleError> {
+        let count = self.count().to_u32();
+        let key = proof.key().raw_data();
+        let leaves = proof.leaves();
+        for leaf in leaves.iter() {
+            verify_leaf(self, &leaf)?;
+        }
+        match leaves.len() {
+            0 if count == 0 => Ok(Membership::NonMember),
+            1 => {
+                let leaf = leaves.get_unchecked(0);
+                let index = leaf.index().to_u32();
+                let leaf_key = leaf.key().raw_data();
+                if leaf_key == key {
+                    Ok(Membership::Member)
+                } else if (index == 0 && key < leaf_key) || (index + 1 == count && key > leaf_key) {
+                    Ok(Membership::NonMember)
+                } else {
+                    Err(MerkleError::InvalidLeaves)
+                }
+            }
+            2 => {
+                let left = leaves.get_unchecked(0);
+                let right = leaves.get_unchecked(1);
+                if left.index().to_u32().checked_add(1) == Some(right.index().to_u32())
+                    && left.key().raw_data() < key
+                    && key < right.key().raw_data()
+                {
+                    Ok(Membership::NonMember)
+                } else {
+                    Err(MerkleError::InvalidLeaves)
+                }
+            }
+            _ => Err(MerkleError::InvalidLeaves),
+        }
+    }
+}
+
+/// A builder of a `MerkleSet` and the proofs of keys.
+pub struct MerkleTree {
+    keys: Vec<Vec<u8>>,
+    // From the leaves to the root.
+    levels: Vec<Vec<[u8; HASH_SIZE]>>,
+}
+
+impl MerkleTree {
+    /// Builds a tree from keys, the keys will be sorted and deduplicated.
+    pub fn new<K: AsRef<[u8]>>(keys: impl IntoIterator<Item = K>) -> Self {
+        let mut keys = keys
+            .into_iter()
+            .map(|key| key.as_ref().to_vec())
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        let mut levels = Vec::new();
+        let mut level = keys.iter().map(|key| leaf_hash(key)).collect::<Vec<_>>();
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(level);
+            level = next;
+        }
+        levels.push(level);
+        Self { keys, levels }
+    }
+
+    /// The root of the tree, it's all zeros if the tree is empty.
+    pub fn root(&self) -> [u8; HASH_SIZE] {
+        self.levels
+            .last()
+            .and_then(|level| level.first().copied())
+            .unwrap_or_default()
+    }
+
+    /// The `MerkleSet` to be stored.
+    pub fn to_set(&self) -> types::MerkleSet {
+        types::MerkleSet::new_builder()
+            .count((self.keys.len() as u32).into())
+            .root(self.root().into())
+            .build()
+    }
+
+    /// Proves whether the key is in the set or not.
+    pub fn prove(&self, key: &[u8]) -> types::MerkleKeyProof {
+        let indexes = match self.keys.binary_search_by(|k| k[..].cmp(key)) {
+            Ok(index) => [Some(index), None],
+            Err(0) => [self.keys.first().map(|_| 0), None],
+            Err(index) if index == self.keys.len() => [Some(index - 1), None],
+            Err(index) => [Some(index - 1), Some(index)],
+        };
+        let leaves = types::MerkleLeafProofVec::new_builder()
+            .extend(
+                indexes
+                    .into_iter()
+                    .flatten()
+                    .map(|index| self.prove_leaf(index)),
+            )
+            .build();
+        types::MerkleKeyProof::new_builder()
+            .key(types::Bytes::new_from_raw_slice(key))
+            .leaves(leaves)
+            .build()
+    }
+
+    fn prove_leaf(&self, index: usize) -> types::MerkleLeafProof {
+        let mut siblings = Vec::new();
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if let Some(node) = level.get(sibling) {
+                siblings.push(types::Byte32::from(*node));
+            }
+            position /= 2;
+        }
+        types::MerkleLeafProof::new_builder()
+            .key(types::Bytes::new_from_raw_slice(&self.keys[index]))
+            .index((index as u32).into())
+            .siblings(types::Byte32Vec::new_builder().set(siblings).build())
+            .build()
+    }
+}
@@ -0,0 +1,118 @@
+//! Checks for human-readable keys, based on compact embedded Unicode tables.
+//!
+//! The tables are generated by `scripts/gen_unicode_tables.py`.
+
+use core::cmp::Ordering;
+
+pub use crate::unicode_tables::UNICODE_VERSION;
+use crate::unicode_tables::{
+    CHANGES_WHEN_CASEFOLDED, COMBINING_CLASSES, COMPOSITIONS, LETTER_SCRIPTS, NFC_NO,
+    SCRIPT_EAST_ASIAN, SCRIPT_LATIN,
+};
+
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_S_COUNT: u32 = 11172;
+
+/// Checks whether a string is in Normalization Form C.
+pub fn is_nfc(s: &str) -> bool {
+    // The last starter, and the maximum combining class of characters after it.
+    let mut last_starter: Option<u32> = None;
+    let mut max_ccc_after_starter: Option<u8> = None;
+    let mut last_ccc = 0;
+    for ch in s.chars() {
+        let cp = u32::from(ch);
+        if in_ranges(NFC_NO, cp) {
+            return false;
+        }
+        let ccc = combining_class(cp);
+        if ccc != 0 && last_ccc > ccc {
+            return false;
+        }
+        if let Some(starter) = last_starter {
+            let blocked = max_ccc_after_starter.is_some_and(|max| ccc == 0 || max >= ccc);
+            if !blocked && can_compose(starter, cp) {
+                return false;
+            }
+        }
+        if ccc == 0 {
+            last_starter = Some(cp);
+            max_ccc_after_starter = None;
+        } else {
+            max_ccc_after_starter = max_ccc_after_starter.max(Some(ccc));
+        }
+        last_ccc = ccc;
+    }
+    true
+}
+
+/// Checks whether a string is not changed when case folded.
+pub fn is_case_folded(s: &str) -> bool {
+    s.chars()
+        .all(|ch| !in_ranges(CHANGES_WHEN_CASEFOLDED, u32::from(ch)))
+}
+
+/// Checks whether all letters in a string are in a single script.
+///
+/// Latin letters are allowed to be mixed with East Asian scripts (Han,
+/// Hiragana, Katakana, Hangul and Bopomofo), and East Asian scripts are
+/// treated as one script.
+pub fn is_single_script(s: &str) -> bool {
+    let mut scripts = 0u32;
+    for ch in s.chars() {
+        if let Some(script) = letter_script(u32::from(ch)) {
+            scripts |= 1 << script;
+        }
+    }
+    let latin_with_east_asian = (1 << SCRIPT_LATIN) | (1 << SCRIPT_EAST_ASIAN);
+    scripts.count_ones() <= 1 || scripts == latin_with_east_asian
+}
+
+fn in_ranges(ranges: &[(u32, u32)], cp: u32) -> bool {
+    ranges
+        .binary_search_by(|&(start, end)| compare_range(start, end, cp))
+        .is_ok()
+}
+
+fn lookup_valued_ranges(ranges: &[(u32, u32, u8)], cp: u32) -> Option<u8> {
+    ranges
+        .binary_search_by(|&(start, end, _)| compare_range(start, end, cp))
+        .ok()
+        .map(|index| ranges[index].2)
+}
+
+fn compare_range(start: u32, end: u32, cp: u32) -> Ordering {
+    if end < cp {
+        Ordering::Less
+    } else if start > cp {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+fn combining_class(cp: u32) -> u8 {
+    lookup_valued_ranges(COMBINING_CLASSES, cp).unwrap_or(0)
+}
+
+fn letter_script(cp: u32) -> Option<u8> {
+    lookup_valued_ranges(LETTER_SCRIPTS, cp)
+}
+
+fn can_compose(first: u32, second: u32) -> bool {
+    let is_hangul_lv = (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&first)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&second);
+    let is_hangul_lvt = (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&first)
+        && (first - HANGUL_S_BASE) % HANGUL_T_COUNT == 0
+        && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&second);
+    is_hangul_lv
+        || is_hangul_lvt
+        || COMPOSITIONS
+            .binary_search_by(|&(x, y, _)| (x, y).cmp(&(first, second)))
+            .is_ok()
+}
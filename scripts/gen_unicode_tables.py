@@ -0,0 +1,175 @@
+#!/usr/bin/env python3
+#
+# An utility script to generate the compact Unicode tables, which are used by
+# the key policy to check human-readable keys.
+#
+# The Unicode version depends on the `unicodedata` module of the Python, which
+# runs this script.
+#
+# Usage: scripts/gen_unicode_tables.py > crates/demo-linked-list-lib/src/unicode_tables.rs
+
+import unicodedata as ud
+
+MAX_CODE_POINT = 0x110000
+
+# Hangul syllables are composed algorithmically, so they are not in tables.
+HANGUL_S_BASE = 0xAC00
+HANGUL_S_COUNT = 11172
+
+# Scripts to check mixed-script keys.
+#
+# The scripts of letters are derived from their names, letters in other
+# scripts are treated as `OTHER`.
+SCRIPTS = [
+    ("LATIN", ["LATIN"]),
+    ("GREEK", ["GREEK"]),
+    ("CYRILLIC", ["CYRILLIC"]),
+    ("ARMENIAN", ["ARMENIAN"]),
+    ("HEBREW", ["HEBREW"]),
+    ("ARABIC", ["ARABIC"]),
+    ("GEORGIAN", ["GEORGIAN"]),
+    ("CHEROKEE", ["CHEROKEE"]),
+    ("COPTIC", ["COPTIC"]),
+    ("EAST_ASIAN", ["CJK", "HIRAGANA", "KATAKANA", "HANGUL", "BOPOMOFO"]),
+]
+SCRIPT_OTHER = len(SCRIPTS)
+
+
+def is_surrogate(cp):
+    return 0xD800 <= cp <= 0xDFFF
+
+
+def code_points():
+    return (cp for cp in range(MAX_CODE_POINT) if not is_surrogate(cp))
+
+
+def collect_ranges(pred):
+    ranges = []
+    for cp in code_points():
+        if pred(cp):
+            if ranges and ranges[-1][1] == cp - 1:
+                ranges[-1][1] = cp
+            else:
+                ranges.append([cp, cp])
+    return ranges
+
+
+def collect_valued_ranges(func):
+    ranges = []
+    for cp in code_points():
+        value = func(cp)
+        if value is None:
+            continue
+        if ranges and ranges[-1][1] == cp - 1 and ranges[-1][2] == value:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp, value])
+    return ranges
+
+
+def is_nfc_no(cp):
+    ch = chr(cp)
+    return ud.normalize("NFC", ch) != ch
+
+
+def changes_when_casefolded(cp):
+    ch = chr(cp)
+    return ch.casefold() != ch
+
+
+def canonical_combining_class(cp):
+    ccc = ud.combining(chr(cp))
+    return ccc if ccc else None
+
+
+def compositions():
+    pairs = []
+    for cp in code_points():
+        if HANGUL_S_BASE <= cp < HANGUL_S_BASE + HANGUL_S_COUNT:
+            continue
+        decomposition = ud.decomposition(chr(cp))
+        if not decomposition or decomposition.startswith("<"):
+            continue
+        parts = [int(part, 16) for part in decomposition.split()]
+        if len(parts) == 2 and not is_nfc_no(cp):
+            pairs.append((parts[0], parts[1], cp))
+    pairs.sort()
+    return pairs
+
+
+def script_of(cp):
+    ch = chr(cp)
+    if not ud.category(ch).startswith("L"):
+        return None
+    words = ud.name(ch, "").split()[:2]
+    for index, (_, prefixes) in enumerate(SCRIPTS):
+        if any(word in prefixes for word in words):
+            return index
+    return SCRIPT_OTHER
+
+
+def script_ranges():
+    # Merge ranges across unassigned code points.
+    ranges = []
+    for cp in code_points():
+        script = script_of(cp)
+        if script is None:
+            continue
+        if ranges and ranges[-1][2] == script:
+            gap = range(ranges[-1][1] + 1, cp)
+            if all(ud.category(chr(c)) == "Cn" for c in gap if not is_surrogate(c)):
+                ranges[-1][1] = cp
+                continue
+        ranges.append([cp, cp, script])
+    return ranges
+
+
+def print_ranges(name, ranges):
+    print(f"pub(crate) const {name}: &[(u32, u32)] = &[")
+    for start, end in ranges:
+        print(f"    (0x{start:04X}, 0x{end:04X}),")
+    print("];")
+
+
+def print_valued_ranges(name, ranges):
+    print(f"pub(crate) const {name}: &[(u32, u32, u8)] = &[")
+    for start, end, value in ranges:
+        print(f"    (0x{start:04X}, 0x{end:04X}, {value}),")
+    print("];")
+
+
+def main():
+    print("// Generated by `scripts/gen_unicode_tables.py`, DO NOT EDIT.")
+    print(f"//")
+    print(f"// Unicode version: {ud.unidata_version}")
+    print()
+    print("/// The Unicode version of these tables.")
+    print(f'pub const UNICODE_VERSION: &str = "{ud.unidata_version}";')
+    print()
+    for index, (name, _) in enumerate(SCRIPTS):
+        print(f"pub(crate) const SCRIPT_{name}: u8 = {index};")
+    print(f"pub(crate) const SCRIPT_OTHER: u8 = {SCRIPT_OTHER};")
+    print()
+    print("// Code points which are never in NFC (NFC_Quick_Check=No).")
+    print_ranges("NFC_NO", collect_ranges(is_nfc_no))
+    print()
+    print("// Code points which are changed when case folded.")
+    print_ranges("CHANGES_WHEN_CASEFOLDED", collect_ranges(changes_when_casefolded))
+    print()
+    print("// Non-zero canonical combining classes.")
+    print_valued_ranges(
+        "COMBINING_CLASSES", collect_valued_ranges(canonical_combining_class)
+    )
+    print()
+    print("// Primary composites, sorted by (first, second).")
+    print("pub(crate) const COMPOSITIONS: &[(u32, u32, u32)] = &[")
+    for first, second, composite in compositions():
+        print(f"    (0x{first:04X}, 0x{second:04X}, 0x{composite:04X}),")
+    print("];")
+    print()
+    print("// Scripts of letters.")
+    print_valued_ranges("LETTER_SCRIPTS", script_ranges())
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,507 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::*,
+        prelude::*,
+    },
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use crate::{prelude::*, utilities, Loader};
+
+pub(crate) struct ActionCase<'a, 'b> {
+    inputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    outputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    // Indexes of outputs which use another lock script.
+    transferred: &'a [usize],
+    // Indexes of inputs and outputs which are stored in version 2, with owners.
+    inputs_v2: &'a [(usize, Option<[u8; 32]>)],
+    outputs_v2: &'a [(usize, Option<[u8; 32]>)],
+    action: types::RegistryAction,
+    should_passed: bool,
+}
+
+impl ActionCase<'_, '_> {
+    pub(crate) fn inputs_data(&self) -> Vec<Bytes> {
+        to_cells_data(self.inputs_data, self.inputs_v2)
+    }
+
+    pub(crate) fn outputs_data(&self) -> Vec<Bytes> {
+        to_cells_data(self.outputs_data, self.outputs_v2)
+    }
+}
+
+fn to_cells_data(data: &[(&[u8], &[u8], &[u8])], v2: &[(usize, Option<[u8; 32]>)]) -> Vec<Bytes> {
+    data.iter()
+        .enumerate()
+        .map(
+            |(index, (x, y, z))| match v2.iter().find(|(i, _)| *i == index) {
+                Some((_, owner_opt)) => {
+                    let demo_data = types::DemoDataV2::new_from_raw_slices(x, y, z);
+                    match owner_opt {
+                        Some(owner) => demo_data.with_owner(*owner),
+                        None => demo_data,
+                    }
+                    .to_cell_data()
+                }
+                None => {
+                    let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                    Bytes::copy_from_slice(demo_data.as_slice())
+                }
+            },
+        )
+        .collect()
+}
+
+fn insert(keys: &[&[u8]]) -> types::RegistryAction {
+    let keys = types::BytesVec::new_from_raw_slices(keys);
+    let insert = types::Insert::new_builder().keys(keys).build();
+    types::RegistryAction::new_builder().set(insert).build()
+}
+
+fn remove(keys: &[&[u8]]) -> types::RegistryAction {
+    let keys = types::BytesVec::new_from_raw_slices(keys);
+    let remove = types::Remove::new_builder().keys(keys).build();
+    types::RegistryAction::new_builder().set(remove).build()
+}
+
+fn modify_payload(keys: &[&[u8]]) -> types::RegistryAction {
+    let keys = types::BytesVec::new_from_raw_slices(keys);
+    let modify = types::ModifyPayload::new_builder().keys(keys).build();
+    types::RegistryAction::new_builder().set(modify).build()
+}
+
+fn transfer(keys: &[&[u8]]) -> types::RegistryAction {
+    let keys = types::BytesVec::new_from_raw_slices(keys);
+    let transfer = types::Transfer::new_builder().keys(keys).build();
+    types::RegistryAction::new_builder().set(transfer).build()
+}
+
+fn rebalance() -> types::RegistryAction {
+    let rebalance = types::Rebalance::default();
+    types::RegistryAction::new_builder().set(rebalance).build()
+}
+
+#[test]
+fn insert_success() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: insert(&[&[5]]),
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_with_modified_payload() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"new", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: insert(&[&[5]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_undeclared_key() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[
+            (b"1-st", &[1, 1, 1], &[3]),
+            (b"2-nd", &[3], &[5]),
+            (b"3-rd", &[5], &[9, 9, 9]),
+        ],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: insert(&[&[5]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_duplicate_keys() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: insert(&[&[5], &[5]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn remove_success() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: remove(&[&[5]]),
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn remove_but_declared_as_insert() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: insert(&[&[5]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn modify_payload_success() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"new", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: modify_payload(&[&[1, 1, 1]]),
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn modify_payload_of_undeclared_item() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"new", &[1, 1, 1], &[5]), (b"new", &[5], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: modify_payload(&[&[1, 1, 1]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn modify_payload_with_moved_key() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[6]), (b"2-nd", &[6], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: modify_payload(&[&[5]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn modify_payload_with_changed_lock() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"new", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[0],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: modify_payload(&[&[1, 1, 1]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_success() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        transferred: &[1],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: transfer(&[&[5]]),
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_undeclared_item() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        transferred: &[0],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: transfer(&[&[5]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_with_modified_payload() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"new", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[0],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: transfer(&[&[1, 1, 1]]),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+const ACTION_INVALID_MODIFY_PAYLOAD: i8 = 0x55;
+const ACTION_INVALID_TRANSFER: i8 = 0x56;
+
+#[test]
+fn modify_payload_with_upgraded_version() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"new", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[(0, None)],
+        action: modify_payload(&[&[1, 1, 1]]),
+        should_passed: false,
+    };
+    let (context, tx) = build_tx(case);
+    utilities::assert_error_code(
+        context.should_be_failed_without_limit(&tx),
+        ACTION_INVALID_MODIFY_PAYLOAD,
+    );
+}
+
+#[test]
+fn modify_payload_with_changed_owner() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"new", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[(0, None)],
+        outputs_v2: &[(0, Some([7u8; 32]))],
+        action: modify_payload(&[&[1, 1, 1]]),
+        should_passed: false,
+    };
+    let (context, tx) = build_tx(case);
+    utilities::assert_error_code(
+        context.should_be_failed_without_limit(&tx),
+        ACTION_INVALID_MODIFY_PAYLOAD,
+    );
+}
+
+#[test]
+fn transfer_with_upgraded_version() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[0],
+        inputs_v2: &[],
+        outputs_v2: &[(0, None)],
+        action: transfer(&[&[1, 1, 1]]),
+        should_passed: false,
+    };
+    let (context, tx) = build_tx(case);
+    utilities::assert_error_code(
+        context.should_be_failed_without_limit(&tx),
+        ACTION_INVALID_TRANSFER,
+    );
+}
+
+#[test]
+fn transfer_with_changed_owner() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[9, 9, 9])],
+        transferred: &[0],
+        inputs_v2: &[(0, None)],
+        outputs_v2: &[(0, Some([7u8; 32]))],
+        action: transfer(&[&[1, 1, 1]]),
+        should_passed: false,
+    };
+    let (context, tx) = build_tx(case);
+    utilities::assert_error_code(
+        context.should_be_failed_without_limit(&tx),
+        ACTION_INVALID_TRANSFER,
+    );
+}
+
+#[test]
+fn rebalance_success() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"2-nd", &[5], &[9, 9, 9]), (b"1-st", &[1, 1, 1], &[5])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: rebalance(),
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn rebalance_with_modified_payload() {
+    let case = ActionCase {
+        inputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"2-nd", &[5], &[9, 9, 9])],
+        outputs_data: &[(b"1-st", &[1, 1, 1], &[5]), (b"new", &[5], &[9, 9, 9])],
+        transferred: &[],
+        inputs_v2: &[],
+        outputs_v2: &[],
+        action: rebalance(),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_with_action() {
+    utilities::setup();
+
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from([0u8; 32].to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let inputs = [
+        types::DemoData::new_from_raw_slices(b"1-st", &[1, 1, 1], &[5]),
+        types::DemoData::new_from_raw_slices(b"2-nd", &[5], &[1, 1, 1]),
+    ]
+    .into_iter()
+    .map(|demo_data| {
+        let bytes = Bytes::copy_from_slice(demo_data.as_slice());
+        let out_point = context.create_cell(output.clone(), bytes);
+        CellInput::new_builder().previous_output(out_point).build()
+    })
+    .collect::<Vec<_>>();
+
+    let outputs = vec![CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script)
+        .build()];
+    let outputs_data = vec![Bytes::new(); outputs.len()];
+
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(build_witness(&remove(&[&[1, 1, 1], &[5]])).pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let _ = context.should_be_failed_without_limit(&tx);
+}
+
+fn build_witness(action: &types::RegistryAction) -> Bytes {
+    let input_type = Bytes::copy_from_slice(action.as_slice());
+    WitnessArgs::new_builder()
+        .input_type(Some(input_type).pack())
+        .build()
+        .as_bytes()
+}
+
+fn run_test(case: ActionCase) {
+    let should_passed = case.should_passed;
+    let (context, tx) = build_tx(case);
+    if should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn build_tx(case: ActionCase) -> (Context, TransactionView) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let another_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"another".to_vec()))
+        .expect("another lock script");
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from([0u8; 32].to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt.clone())
+        .build();
+    let inputs = case
+        .inputs_data()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let outputs = (0..case.outputs_data.len())
+        .map(|index| {
+            if case.transferred.contains(&index) {
+                output
+                    .clone()
+                    .as_builder()
+                    .lock(another_lock_script.clone())
+                    .build()
+            } else {
+                output.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    let outputs_data = case.outputs_data();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(build_witness(&case.action).pack())
+        .build();
+    let tx = context.complete_tx(tx);
+    (context, tx)
+}
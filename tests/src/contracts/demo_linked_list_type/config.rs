@@ -0,0 +1,218 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use crate::{prelude::*, utilities, Loader};
+
+const DEMO_DATA: &[(&[u8], &[u8], &[u8])] = &[
+    (b"1-st", &[1, 1, 1], &[2, 2, 2]),
+    (b"2-nd", &[2, 2, 2], &[1, 1, 1]),
+];
+
+#[test]
+fn create_with_config() {
+    run_create_test(true, true);
+}
+
+#[test]
+fn create_without_config() {
+    run_create_test(false, false);
+}
+
+#[test]
+fn update_with_config_in_cell_deps() {
+    run_update_test(true, true);
+}
+
+#[test]
+fn update_without_config_in_cell_deps() {
+    run_update_test(false, false);
+}
+
+fn demo_data_list() -> Vec<Bytes> {
+    DEMO_DATA
+        .iter()
+        .map(|(x, y, z)| {
+            let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+            Bytes::copy_from_slice(demo_data.as_slice())
+        })
+        .collect()
+}
+
+fn config_data() -> Bytes {
+    let config = types::RegistryConfig::new_with_fields(0, b"demo", [0u8; 32]);
+    Bytes::copy_from_slice(config.as_slice())
+}
+
+fn build_type_args(unique_id: &[u8], config_type_script: &Script) -> Bytes {
+    let config_type_hash = config_type_script.calc_script_hash().unpack().0;
+    let instance_args = types::InstanceArgs::default().with_config_type_hash(config_type_hash);
+    let mut args = unique_id.to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    Bytes::from(args)
+}
+
+fn run_create_test(with_config: bool, should_passed: bool) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let config_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let config_out_point = context.deploy_cell(config_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+
+    // prepare inputs
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(10000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare type scripts
+    // The config cell is put after the items.
+    let config_index = DEMO_DATA.len();
+    let config_type_id = utilities::calculate_unique_id(input.clone(), config_index);
+    let config_type_script = context
+        .build_script(&config_out_point, Bytes::from(config_type_id.to_vec()))
+        .expect("config type script");
+    let unique_id = utilities::calculate_unique_id(input.clone(), 0);
+    let type_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&unique_id, &config_type_script),
+        )
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let mut outputs = vec![output; DEMO_DATA.len()];
+    let mut outputs_data = demo_data_list();
+    if with_config {
+        let config_type_script_opt = ScriptOpt::new_builder()
+            .set(Some(config_type_script))
+            .build();
+        outputs.push(
+            CellOutput::new_builder()
+                .capacity(500u64.pack())
+                .lock(lock_script.clone())
+                .type_(config_type_script_opt)
+                .build(),
+        );
+        outputs_data.push(config_data());
+    }
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_update_test(with_config: bool, should_passed: bool) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let config_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let config_out_point = context.deploy_cell(config_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let config_type_script = context
+        .build_script(&config_out_point, Bytes::from([1u8; 32].to_vec()))
+        .expect("config type script");
+    let type_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&[0u8; 32], &config_type_script),
+        )
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare cell deps
+    let config_cell_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(
+                ScriptOpt::new_builder()
+                    .set(Some(config_type_script))
+                    .build(),
+            )
+            .build(),
+        config_data(),
+    );
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let inputs = demo_data_list()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let outputs = vec![output; DEMO_DATA.len()];
+    let outputs_data = demo_data_list();
+
+    // build transaction
+    let mut builder = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack());
+    if with_config {
+        builder = builder.cell_dep(
+            CellDep::new_builder()
+                .out_point(config_cell_out_point)
+                .build(),
+        );
+    }
+    let tx = context.complete_tx(builder.build());
+
+    // run
+    if should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
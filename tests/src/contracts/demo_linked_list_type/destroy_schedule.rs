@@ -0,0 +1,272 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use super::epoch_since;
+use crate::{prelude::*, utilities, Loader};
+
+const DEMO_DATA: &[(&[u8], &[u8], &[u8])] = &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])];
+
+// The since of the config cell in inputs.
+#[derive(Clone, Copy)]
+enum ConfigSince {
+    // The config cell is in cell deps.
+    InCellDeps,
+    Relative(u64),
+    Absolute(u64),
+}
+
+pub(crate) struct DestroyScheduleCase {
+    destroy_delay: Option<u64>,
+    scheduled: bool,
+    config_since: ConfigSince,
+    // Put a cell which uses the admin lock in inputs.
+    authorized: bool,
+    should_passed: bool,
+}
+
+#[test]
+fn destroy_by_admin() {
+    let case = DestroyScheduleCase {
+        destroy_delay: None,
+        scheduled: false,
+        config_since: ConfigSince::InCellDeps,
+        authorized: true,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_without_admin() {
+    let case = DestroyScheduleCase {
+        destroy_delay: None,
+        scheduled: false,
+        config_since: ConfigSince::InCellDeps,
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_after_delay() {
+    let case = DestroyScheduleCase {
+        destroy_delay: Some(10),
+        scheduled: true,
+        config_since: ConfigSince::Relative(10),
+        authorized: true,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_after_longer_delay() {
+    let case = DestroyScheduleCase {
+        destroy_delay: Some(10),
+        scheduled: true,
+        config_since: ConfigSince::Relative(12),
+        authorized: true,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_before_delay() {
+    let case = DestroyScheduleCase {
+        destroy_delay: Some(10),
+        scheduled: true,
+        config_since: ConfigSince::Relative(9),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_after_delay_without_admin() {
+    let case = DestroyScheduleCase {
+        destroy_delay: Some(10),
+        scheduled: true,
+        config_since: ConfigSince::Relative(10),
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_without_schedule() {
+    let case = DestroyScheduleCase {
+        destroy_delay: Some(10),
+        scheduled: false,
+        config_since: ConfigSince::Relative(10),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_with_delay_and_config_in_cell_deps() {
+    let case = DestroyScheduleCase {
+        destroy_delay: Some(10),
+        scheduled: true,
+        config_since: ConfigSince::InCellDeps,
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn destroy_with_absolute_since() {
+    let case = DestroyScheduleCase {
+        destroy_delay: Some(10),
+        scheduled: true,
+        config_since: ConfigSince::Absolute(100),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+fn demo_data_list() -> Vec<Bytes> {
+    DEMO_DATA
+        .iter()
+        .map(|(x, y, z)| {
+            let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+            Bytes::copy_from_slice(demo_data.as_slice())
+        })
+        .collect()
+}
+
+fn run_test(case: DestroyScheduleCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let config_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let config_out_point = context.deploy_cell(config_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let admin_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"admin".to_vec()))
+        .expect("admin lock script");
+    let config_type_script = context
+        .build_script(&config_out_point, Bytes::from([1u8; 32].to_vec()))
+        .expect("config type script");
+    let config_type_hash = config_type_script.calc_script_hash().unpack().0;
+    let instance_args = types::InstanceArgs::default().with_config_type_hash(config_type_hash);
+    let mut args = vec![0u8; 32];
+    args.extend_from_slice(instance_args.as_slice());
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from(args))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare the config cell
+    let admin_lock_hash = admin_lock_script.calc_script_hash().unpack().0;
+    let config_data = |version| {
+        let mut config = types::RegistryConfig::new_with_fields(version, b"demo", admin_lock_hash)
+            .with_destroy_scheduled(case.scheduled);
+        if let Some(destroy_delay) = case.destroy_delay {
+            config = config.with_destroy_delay(destroy_delay);
+        }
+        Bytes::copy_from_slice(config.as_slice())
+    };
+    // The config cell doesn't use the admin lock, so it can't authorize the
+    // destroy by itself.
+    let config_output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(
+            ScriptOpt::new_builder()
+                .set(Some(config_type_script))
+                .build(),
+        )
+        .build();
+    let config_cell_out_point = context.create_cell(config_output.clone(), config_data(0));
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let mut inputs = demo_data_list()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+    // The config cell can't be destroyed, so it's updated if it's in inputs.
+    let mut cell_deps = Vec::new();
+    let mut outputs = Vec::new();
+    let config_cell_dep = CellDep::new_builder()
+        .out_point(config_cell_out_point.clone())
+        .build();
+    match case.config_since {
+        ConfigSince::InCellDeps => cell_deps.push(config_cell_dep),
+        ConfigSince::Relative(epochs) | ConfigSince::Absolute(epochs) => {
+            let relative = matches!(case.config_since, ConfigSince::Relative(_));
+            inputs.push(
+                CellInput::new_builder()
+                    .previous_output(config_cell_out_point)
+                    .since(epoch_since(epochs, relative).pack())
+                    .build(),
+            );
+            outputs.push((config_output, config_data(1)));
+        }
+    }
+    if case.authorized {
+        let admin_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(admin_lock_script)
+                .build(),
+            Bytes::new(),
+        );
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(admin_out_point)
+                .build(),
+        );
+    }
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(lock_script)
+        .build();
+    outputs.push((output, Bytes::new()));
+    let (outputs, outputs_data): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
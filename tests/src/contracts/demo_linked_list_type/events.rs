@@ -0,0 +1,448 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::{constants::FLAG_EVENTS, types};
+
+use crate::{prelude::*, utilities, Loader};
+
+type KeyEvents<'a> = &'a [(&'a [u8], u32)];
+
+// Remove `[3]`, insert `[4]`, and modify the payload of `[5]`.
+const INPUTS_DATA: &[(&[u8], &[u8], &[u8])] = &[
+    (b"1-st", &[1], &[3]),
+    (b"3-rd", &[3], &[5]),
+    (b"5-th", &[5], &[9]),
+];
+const OUTPUTS_DATA: &[(&[u8], &[u8], &[u8])] = &[
+    (b"1-st", &[1], &[4]),
+    (b"4-th", &[4], &[5]),
+    (b"new", &[5], &[9]),
+];
+
+const CREATED_DATA: &[(&[u8], &[u8], &[u8])] = &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])];
+
+// The item which is transferred or migrated, only its owner or its version is
+// changed.
+const KEPT_DATA: (&[u8], &[u8], &[u8]) = (b"2-nd", &[2], &[3]);
+
+pub(crate) struct EventsCase<'a> {
+    requires_events: bool,
+    // Inserted, removed and modified keys, with the indexes of their cells.
+    events: Option<(KeyEvents<'a>, KeyEvents<'a>, KeyEvents<'a>)>,
+    should_passed: bool,
+}
+
+#[test]
+fn update_with_events() {
+    let case = EventsCase {
+        requires_events: true,
+        events: Some((&[(&[4], 1)], &[(&[3], 1)], &[(&[5], 2)])),
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_with_optional_events() {
+    let case = EventsCase {
+        requires_events: false,
+        events: Some((&[(&[4], 1)], &[(&[3], 1)], &[(&[5], 2)])),
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_without_optional_events() {
+    let case = EventsCase {
+        requires_events: false,
+        events: None,
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_without_required_events() {
+    let case = EventsCase {
+        requires_events: true,
+        events: None,
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_with_missing_event() {
+    let case = EventsCase {
+        requires_events: false,
+        events: Some((&[(&[4], 1)], &[(&[3], 1)], &[])),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_with_extra_event() {
+    let case = EventsCase {
+        requires_events: false,
+        events: Some((&[(&[4], 1)], &[(&[3], 1)], &[(&[1], 0), (&[5], 2)])),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_with_incorrect_index() {
+    let case = EventsCase {
+        requires_events: false,
+        events: Some((&[(&[4], 1)], &[(&[3], 0)], &[(&[5], 2)])),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_with_swapped_kinds() {
+    let case = EventsCase {
+        requires_events: false,
+        events: Some((&[(&[3], 1)], &[(&[4], 1)], &[(&[5], 2)])),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn transfer_owner_with_events() {
+    let case = EventsCase {
+        requires_events: true,
+        events: Some((&[], &[], &[(&[2], 0)])),
+        should_passed: true,
+    };
+    run_transfer_owner_test(case);
+}
+
+#[test]
+fn transfer_owner_without_modified_event() {
+    let case = EventsCase {
+        requires_events: true,
+        events: Some((&[], &[], &[])),
+        should_passed: false,
+    };
+    run_transfer_owner_test(case);
+}
+
+#[test]
+fn migrate_with_events() {
+    let case = EventsCase {
+        requires_events: true,
+        events: Some((&[], &[], &[(&[2], 0)])),
+        should_passed: true,
+    };
+    run_migrate_test(case);
+}
+
+#[test]
+fn migrate_without_modified_event() {
+    let case = EventsCase {
+        requires_events: true,
+        events: Some((&[], &[], &[])),
+        should_passed: false,
+    };
+    run_migrate_test(case);
+}
+
+#[test]
+fn create_with_events() {
+    let case = EventsCase {
+        requires_events: true,
+        events: Some((&[(&[1], 0), (&[2], 1)], &[], &[])),
+        should_passed: true,
+    };
+    run_create_test(case);
+}
+
+#[test]
+fn create_with_unsorted_events() {
+    let case = EventsCase {
+        requires_events: true,
+        events: Some((&[(&[2], 1), (&[1], 0)], &[], &[])),
+        should_passed: false,
+    };
+    run_create_test(case);
+}
+
+#[test]
+fn destroy_without_required_events() {
+    run_destroy_test(true);
+}
+
+fn demo_data_list(items: &[(&[u8], &[u8], &[u8])]) -> Vec<Bytes> {
+    items
+        .iter()
+        .map(|(x, y, z)| {
+            let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+            Bytes::copy_from_slice(demo_data.as_slice())
+        })
+        .collect()
+}
+
+fn build_key_events(events: KeyEvents) -> types::KeyEventVec {
+    types::KeyEventVec::new_builder()
+        .extend(
+            events
+                .iter()
+                .map(|(key, index)| types::KeyEvent::new_from_raw_parts(key, *index)),
+        )
+        .build()
+}
+
+fn build_witness(case: &EventsCase, action: Option<types::RegistryAction>) -> Option<Bytes> {
+    let (inserted, removed, modified) = case.events?;
+    let events = types::RegistryEvents::new_builder()
+        .inserted(build_key_events(inserted))
+        .removed(build_key_events(removed))
+        .modified(build_key_events(modified))
+        .build();
+    let output = types::RegistryOutput::new_builder()
+        .events(
+            types::RegistryEventsOpt::new_builder()
+                .set(Some(events))
+                .build(),
+        )
+        .build();
+    let input_type = action.map(|action| Bytes::copy_from_slice(action.as_slice()));
+    let output_type = Bytes::copy_from_slice(output.as_slice());
+    let witness = WitnessArgs::new_builder()
+        .input_type(input_type.pack())
+        .output_type(Some(output_type).pack())
+        .build()
+        .as_bytes();
+    Some(witness)
+}
+
+fn build_type_args(unique_id: &[u8], requires_events: bool) -> Bytes {
+    let flags = if requires_events { FLAG_EVENTS } else { 0 };
+    let instance_args = types::InstanceArgs::new_with_flags(flags);
+    let mut args = unique_id.to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    Bytes::from(args)
+}
+
+fn run_update_test(case: EventsCase) {
+    let build_data = |_: [u8; 32]| (demo_data_list(INPUTS_DATA), demo_data_list(OUTPUTS_DATA));
+    run_update_test_with_data(case, build_data, None);
+}
+
+// The owner is transferred from the lock of the item cell, so the transfer is
+// authorized by the item cell itself.
+fn run_transfer_owner_test(case: EventsCase) {
+    let (x, y, z) = KEPT_DATA;
+    let build_data = |owner| {
+        let demo_data = types::DemoDataV2::new_from_raw_slices(x, y, z);
+        let inputs_data = vec![demo_data.clone().with_owner(owner).to_cell_data()];
+        let outputs_data = vec![demo_data.with_owner([7u8; 32]).to_cell_data()];
+        (inputs_data, outputs_data)
+    };
+    let transfer_owner = types::TransferOwner::new_builder()
+        .key(types::Bytes::new_from_raw_slice(y))
+        .build();
+    let action = types::RegistryAction::new_builder()
+        .set(transfer_owner)
+        .build();
+    run_update_test_with_data(case, build_data, Some(action));
+}
+
+fn run_migrate_test(case: EventsCase) {
+    let (x, y, z) = KEPT_DATA;
+    let build_data = |_: [u8; 32]| {
+        let inputs_data = demo_data_list(&[KEPT_DATA]);
+        let outputs_data = vec![types::DemoDataV2::new_from_raw_slices(x, y, z).to_cell_data()];
+        (inputs_data, outputs_data)
+    };
+    let migrate = types::Migrate::new_builder()
+        .keys(types::BytesVec::new_from_raw_slices(&[y]))
+        .build();
+    let action = types::RegistryAction::new_builder().set(migrate).build();
+    run_update_test_with_data(case, build_data, Some(action));
+}
+
+// The data of inputs and outputs are built with the lock hash of the items.
+fn run_update_test_with_data(
+    case: EventsCase,
+    build_data: impl FnOnce([u8; 32]) -> (Vec<Bytes>, Vec<Bytes>),
+    action: Option<types::RegistryAction>,
+) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let type_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&[0u8; 32], case.requires_events),
+        )
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+    let (inputs_data, outputs_data) = build_data(lock_script.calc_script_hash().unpack().0);
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script)
+        .type_(type_script_opt)
+        .build();
+    let inputs = inputs_data
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let outputs = vec![output; outputs_data.len()];
+
+    // build transaction
+    let mut builder = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack());
+    if let Some(witness) = build_witness(&case, action) {
+        builder = builder.witness(witness.pack());
+    }
+    let tx = context.complete_tx(builder.build());
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_create_test(case: EventsCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+
+    // prepare inputs
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(10000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare type scripts
+    let unique_id = utilities::calculate_unique_id(input.clone(), 0);
+    let type_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&unique_id, case.requires_events),
+        )
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script)
+        .type_(type_script_opt)
+        .build();
+    let outputs = vec![output; CREATED_DATA.len()];
+    let outputs_data = demo_data_list(CREATED_DATA);
+
+    // build transaction
+    let mut builder = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack());
+    if let Some(witness) = build_witness(&case, None) {
+        builder = builder.witness(witness.pack());
+    }
+    let tx = context.complete_tx(builder.build());
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_destroy_test(requires_events: bool) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let type_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&[0u8; 32], requires_events),
+        )
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let inputs = demo_data_list(CREATED_DATA)
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .output(output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let _ = context.should_be_passed_without_limit(&tx);
+}
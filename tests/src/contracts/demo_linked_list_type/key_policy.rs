@@ -0,0 +1,336 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::{
+    constants::{
+        FLAG_SENTINELS, SENTINEL_MAX_KEY as MAX, SENTINEL_MIN_KEY as MIN,
+        UNICODE_RULE_NFC_CASEFOLD, UNICODE_RULE_SINGLE_SCRIPT,
+    },
+    key_policy::ByteClass,
+    types,
+};
+
+use super::FullListCase;
+use crate::{prelude::*, utilities, Loader};
+
+pub(crate) struct KeyPolicyUpdateCase<'a, 'b> {
+    inputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    outputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    should_passed: bool,
+}
+
+impl KeyPolicyUpdateCase<'_, '_> {
+    pub(crate) fn inputs_data(&self) -> Vec<Bytes> {
+        self.inputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+
+    pub(crate) fn outputs_data(&self) -> Vec<Bytes> {
+        self.outputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+}
+
+fn dns_label_policy() -> types::KeyPolicy {
+    types::KeyPolicy::new_with_rules(3, 16, ByteClass::DnsLabel, &[b"xn--"])
+}
+
+#[test]
+fn create_with_valid_keys() {
+    let case = FullListCase {
+        demo_data: &[(b"1-st", b"abc", b"d-ef"), (b"2-nd", b"d-ef", b"abc")],
+        should_passed: true,
+    };
+    run_create_test(case, 0, dns_label_policy());
+}
+
+#[test]
+fn create_with_too_short_key() {
+    let case = FullListCase {
+        demo_data: &[(b"1-st", b"ab", b"def"), (b"2-nd", b"def", b"ab")],
+        should_passed: false,
+    };
+    run_create_test(case, 0, dns_label_policy());
+}
+
+#[test]
+fn create_with_too_long_key() {
+    let case = FullListCase {
+        demo_data: &[
+            (b"1-st", b"abc", b"abcdefghijklmnopq"),
+            (b"2-nd", b"abcdefghijklmnopq", b"abc"),
+        ],
+        should_passed: false,
+    };
+    run_create_test(case, 0, dns_label_policy());
+}
+
+#[test]
+fn create_with_uppercase_key() {
+    let case = FullListCase {
+        demo_data: &[(b"1-st", b"ABC", b"def"), (b"2-nd", b"def", b"ABC")],
+        should_passed: false,
+    };
+    run_create_test(case, 0, dns_label_policy());
+}
+
+#[test]
+fn create_with_leading_hyphen() {
+    let case = FullListCase {
+        demo_data: &[(b"1-st", b"-ab", b"def"), (b"2-nd", b"def", b"-ab")],
+        should_passed: false,
+    };
+    run_create_test(case, 0, dns_label_policy());
+}
+
+#[test]
+fn create_with_forbidden_prefix() {
+    let case = FullListCase {
+        demo_data: &[(b"1-st", b"abc", b"xn--abc"), (b"2-nd", b"xn--abc", b"abc")],
+        should_passed: false,
+    };
+    run_create_test(case, 0, dns_label_policy());
+}
+
+#[test]
+fn create_with_invalid_policy() {
+    let case = FullListCase {
+        demo_data: &[(b"1-st", b"abc", b"def"), (b"2-nd", b"def", b"abc")],
+        should_passed: false,
+    };
+    let policy = types::KeyPolicy::new_with_rules(8, 4, ByteClass::Raw, &[]);
+    run_create_test(case, 0, policy);
+}
+
+#[test]
+fn create_with_sentinels() {
+    let case = FullListCase {
+        demo_data: &[(b"min", MIN, MAX), (b"max", MAX, MIN)],
+        should_passed: true,
+    };
+    run_create_test(case, FLAG_SENTINELS, dns_label_policy());
+}
+
+fn unicode_policy() -> types::KeyPolicy {
+    types::KeyPolicy::new_with_rules(1, 64, ByteClass::Raw, &[])
+        .with_unicode_rules(UNICODE_RULE_NFC_CASEFOLD | UNICODE_RULE_SINGLE_SCRIPT)
+}
+
+fn run_unicode_create_test(first: &str, second: &str, should_passed: bool) {
+    let (first, second) = (first.as_bytes(), second.as_bytes());
+    let case = FullListCase {
+        demo_data: &[(b"1-st", first, second), (b"2-nd", second, first)],
+        should_passed,
+    };
+    run_create_test(case, 0, unicode_policy());
+}
+
+#[test]
+fn create_with_normalized_keys() {
+    run_unicode_create_test("caf\u{e9}", "na\u{ef}ve", true);
+}
+
+#[test]
+fn create_with_east_asian_and_latin_keys() {
+    run_unicode_create_test("tokyo", "\u{6771}\u{4eac}tokyo", true);
+}
+
+#[test]
+fn create_with_hangul_syllables() {
+    run_unicode_create_test("seoul", "\u{c11c}\u{c6b8}", true);
+}
+
+#[test]
+fn create_with_decomposed_key() {
+    run_unicode_create_test("cafe\u{301}", "naive", false);
+}
+
+#[test]
+fn create_with_decomposed_hangul() {
+    run_unicode_create_test("seoul", "\u{1109}\u{1165}", false);
+}
+
+#[test]
+fn create_with_uppercase_unicode_key() {
+    run_unicode_create_test("Caf\u{e9}", "naive", false);
+}
+
+#[test]
+fn create_with_mixed_scripts_key() {
+    // The second letter is a Cyrillic letter.
+    run_unicode_create_test("naive", "p\u{430}ypal", false);
+}
+
+#[test]
+fn create_with_invalid_utf8_key() {
+    let case = FullListCase {
+        demo_data: &[
+            (b"1-st", b"abc", &[0xc3, 0x28]),
+            (b"2-nd", &[0xc3, 0x28], b"abc"),
+        ],
+        should_passed: false,
+    };
+    run_create_test(case, 0, unicode_policy());
+}
+
+#[test]
+fn insert_valid_key() {
+    let case = KeyPolicyUpdateCase {
+        inputs_data: &[(b"1-st", b"abc", b"xyz")],
+        outputs_data: &[(b"1-st", b"abc", b"lmn"), (b"2-nd", b"lmn", b"xyz")],
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn insert_invalid_key() {
+    let case = KeyPolicyUpdateCase {
+        inputs_data: &[(b"1-st", b"abc", b"xyz")],
+        outputs_data: &[(b"1-st", b"abc", b"l_n"), (b"2-nd", b"l_n", b"xyz")],
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn modify_existing_item() {
+    let case = KeyPolicyUpdateCase {
+        inputs_data: &[(b"1-st", b"abc", b"xyz")],
+        outputs_data: &[(b"new", b"abc", b"xyz")],
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+fn build_type_args(unique_id: &[u8], flags: u8, policy: types::KeyPolicy) -> Bytes {
+    let instance_args = types::InstanceArgs::new_with_key_policy(flags, policy);
+    let mut args = unique_id.to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    Bytes::from(args)
+}
+
+fn run_create_test(case: FullListCase, flags: u8, policy: types::KeyPolicy) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+
+    // prepare inputs
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(10000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare type scripts
+    let output_index = 0;
+    let unique_id = utilities::calculate_unique_id(input.clone(), output_index);
+    let type_script = context
+        .build_script(&type_out_point, build_type_args(&unique_id, flags, policy))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt.clone())
+        .build();
+    let outputs = vec![output.clone(); case.demo_data.len()];
+    let outputs_data = case.demo_data();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_update_test(case: KeyPolicyUpdateCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let type_args = build_type_args(&[0u8; 32], 0, dns_label_policy());
+    let type_script = context
+        .build_script(&type_out_point, type_args)
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt.clone())
+        .build();
+    let inputs = case
+        .inputs_data()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let outputs = vec![output.clone(); case.outputs_data.len()];
+    let outputs_data = case.outputs_data();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
@@ -0,0 +1,305 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use crate::{prelude::*, utilities, Loader};
+
+// An item in version 1 or version 2.
+#[derive(Clone, Copy)]
+enum Item<'a> {
+    V1(&'a [u8], &'a [u8], &'a [u8]),
+    V2(&'a [u8], &'a [u8], &'a [u8]),
+    V2WithOwner(&'a [u8], &'a [u8], &'a [u8]),
+}
+
+impl Item<'_> {
+    fn to_cell_data(self) -> Bytes {
+        match self {
+            Self::V1(x, y, z) => {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            }
+            Self::V2(x, y, z) => types::DemoDataV2::new_from_raw_slices(x, y, z).to_cell_data(),
+            Self::V2WithOwner(x, y, z) => types::DemoDataV2::new_from_raw_slices(x, y, z)
+                .with_owner([1u8; 32])
+                .to_cell_data(),
+        }
+    }
+}
+
+pub(crate) struct MigrationCase<'a, 'b> {
+    inputs_data: &'a [Item<'b>],
+    outputs_data: &'a [Item<'b>],
+    action: Option<types::RegistryAction>,
+    should_passed: bool,
+}
+
+impl MigrationCase<'_, '_> {
+    pub(crate) fn inputs_data(&self) -> Vec<Bytes> {
+        self.inputs_data.iter().map(|x| x.to_cell_data()).collect()
+    }
+
+    pub(crate) fn outputs_data(&self) -> Vec<Bytes> {
+        self.outputs_data.iter().map(|x| x.to_cell_data()).collect()
+    }
+}
+
+fn migrate(keys: &[&[u8]]) -> Option<types::RegistryAction> {
+    let keys = types::BytesVec::new_from_raw_slices(keys);
+    let migrate = types::Migrate::new_builder().keys(keys).build();
+    Some(types::RegistryAction::new_builder().set(migrate).build())
+}
+
+#[test]
+fn create_with_mixed_versions() {
+    let items = [Item::V1(b"1-st", &[1], &[2]), Item::V2(b"2-nd", &[2], &[1])];
+    run_create_test(&items, true);
+}
+
+#[test]
+fn create_with_latest_version() {
+    let items = [Item::V2(b"1-st", &[1], &[2]), Item::V2(b"2-nd", &[2], &[1])];
+    run_create_test(&items, true);
+}
+
+#[test]
+fn migrate_success() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V1(b"1-st", &[1], &[2])],
+        outputs_data: &[Item::V2(b"1-st", &[1], &[2])],
+        action: migrate(&[&[1]]),
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn migrate_in_mixed_list() {
+    let case = MigrationCase {
+        inputs_data: &[
+            Item::V2(b"1-st", &[1], &[2]),
+            Item::V1(b"2-nd", &[2], &[3]),
+            Item::V1(b"3-rd", &[3], &[4]),
+        ],
+        outputs_data: &[
+            Item::V2(b"1-st", &[1], &[2]),
+            Item::V2(b"2-nd", &[2], &[3]),
+            Item::V1(b"3-rd", &[3], &[4]),
+        ],
+        action: migrate(&[&[2]]),
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn migrate_undeclared_item() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V1(b"1-st", &[1], &[2]), Item::V1(b"2-nd", &[2], &[3])],
+        outputs_data: &[Item::V2(b"1-st", &[1], &[2]), Item::V2(b"2-nd", &[2], &[3])],
+        action: migrate(&[&[1]]),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn migrate_latest_item() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V2(b"1-st", &[1], &[2])],
+        outputs_data: &[Item::V2(b"1-st", &[1], &[2])],
+        action: migrate(&[&[1]]),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn migrate_with_modified_payload() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V1(b"1-st", &[1], &[2])],
+        outputs_data: &[Item::V2(b"new", &[1], &[2])],
+        action: migrate(&[&[1]]),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn migrate_with_owner() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V1(b"1-st", &[1], &[2])],
+        outputs_data: &[Item::V2WithOwner(b"1-st", &[1], &[2])],
+        action: migrate(&[&[1]]),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn migrate_with_inserted_key() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V1(b"1-st", &[1], &[3])],
+        outputs_data: &[Item::V2(b"1-st", &[1], &[2]), Item::V2(b"2-nd", &[2], &[3])],
+        action: migrate(&[&[1]]),
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn insert_into_mixed_list() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V1(b"1-st", &[1], &[3])],
+        outputs_data: &[Item::V1(b"1-st", &[1], &[2]), Item::V2(b"2-nd", &[2], &[3])],
+        action: None,
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn remove_from_mixed_list() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V2(b"1-st", &[1], &[2]), Item::V1(b"2-nd", &[2], &[3])],
+        outputs_data: &[Item::V2(b"1-st", &[1], &[3])],
+        action: None,
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn downgrade_item() {
+    let case = MigrationCase {
+        inputs_data: &[Item::V2(b"1-st", &[1], &[2])],
+        outputs_data: &[Item::V1(b"1-st", &[1], &[2])],
+        action: None,
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+fn build_witness(action: &types::RegistryAction) -> Bytes {
+    let input_type = Bytes::copy_from_slice(action.as_slice());
+    WitnessArgs::new_builder()
+        .input_type(Some(input_type).pack())
+        .build()
+        .as_bytes()
+}
+
+fn run_create_test(items: &[Item], should_passed: bool) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+
+    // prepare inputs
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(10000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare type scripts
+    let unique_id = utilities::calculate_unique_id(input.clone(), 0);
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from(unique_id.to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let outputs = vec![output; items.len()];
+    let outputs_data = items.iter().map(|x| x.to_cell_data()).collect::<Vec<_>>();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_update_test(case: MigrationCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from([0u8; 32].to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let inputs = case
+        .inputs_data()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let outputs = vec![output; case.outputs_data.len()];
+    let outputs_data = case.outputs_data();
+
+    // build transaction
+    let mut builder = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack());
+    if let Some(action) = &case.action {
+        builder = builder.witness(build_witness(action).pack());
+    }
+    let tx = context.complete_tx(builder.build());
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
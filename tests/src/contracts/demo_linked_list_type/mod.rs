@@ -1,9 +1,21 @@
-use ckb_testtool::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_testtool::ckb_types::{bytes::Bytes, core::EpochNumberWithFraction, prelude::*};
 use demo_linked_list_lib::types;
 
+mod action;
+mod config;
 mod create;
 mod destroy;
+mod destroy_schedule;
+mod events;
+mod key_policy;
+mod migration;
+mod move_instance;
+mod reserved_keys;
+mod sentinel;
+mod sub_registry;
+mod transfer_owner;
 mod update;
+mod validator;
 
 pub(crate) struct FullListCase<'a, 'b> {
     demo_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
@@ -21,3 +33,9 @@ impl FullListCase<'_, '_> {
             .collect()
     }
 }
+
+// A `since` in whole epochs.
+fn epoch_since(epochs: u64, relative: bool) -> u64 {
+    let flags = if relative { 0xa0 } else { 0x20 };
+    (flags << 56) | EpochNumberWithFraction::new(epochs, 0, 1).full_value()
+}
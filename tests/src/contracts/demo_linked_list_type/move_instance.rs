@@ -0,0 +1,415 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use super::epoch_since;
+use crate::{prelude::*, utilities, Loader};
+
+// How the migration is set in the config cell.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Migration {
+    Designated,
+    // The successor is another instance.
+    Undesignated,
+    Unset,
+}
+
+// The destroy delay in the config, and how the config cell is used.
+#[derive(Clone, Copy)]
+enum TimeLock {
+    Unset,
+    // The config cell is in cell deps.
+    InCellDeps,
+    // The config cell is consumed with a relative `since` in epochs.
+    Waited(u64),
+}
+
+const DESTROY_DELAY: u64 = 10;
+
+pub(crate) struct MoveCase<'a, 'b> {
+    inputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    outputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    migration: Migration,
+    // The inputs use another type script, which is not the predecessor.
+    fake_predecessor: bool,
+    // Put a cell which uses the admin lock in inputs.
+    authorized: bool,
+    time_lock: TimeLock,
+    should_passed: bool,
+}
+
+impl MoveCase<'_, '_> {
+    pub(crate) fn inputs_data(&self) -> Vec<Bytes> {
+        self.inputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+
+    pub(crate) fn outputs_data(&self) -> Vec<Bytes> {
+        self.outputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn move_complete_list() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_in_batches() {
+    // The first batch.
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[3])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[3])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: true,
+    };
+    run_test(case);
+    // The second batch, only 1 item.
+    let case = MoveCase {
+        inputs_data: &[(b"3-rd", &[3], &[1])],
+        outputs_data: &[(b"3-rd", &[3], &[1])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_with_modified_item() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[3])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"new", &[2], &[3])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_with_lost_item() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[3])],
+        outputs_data: &[(b"1-st", &[1], &[2])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_discontinuous_items() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"3-rd", &[3], &[4])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"3-rd", &[3], &[4])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_to_undesignated_successor() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Undesignated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_without_migration() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Unset,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_without_admin() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: false,
+        time_lock: TimeLock::Unset,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_after_delay() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Waited(DESTROY_DELAY),
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_before_delay() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::Waited(DESTROY_DELAY - 1),
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_with_delay_and_config_in_cell_deps() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Designated,
+        fake_predecessor: false,
+        authorized: true,
+        time_lock: TimeLock::InCellDeps,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn move_from_fake_predecessor() {
+    let case = MoveCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        migration: Migration::Designated,
+        fake_predecessor: true,
+        authorized: true,
+        time_lock: TimeLock::Unset,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+fn build_type_args(unique_id: &[u8], config_type_script: &Script) -> Bytes {
+    let config_type_hash = config_type_script.calc_script_hash().unpack().0;
+    let instance_args = types::InstanceArgs::default().with_config_type_hash(config_type_hash);
+    let mut args = unique_id.to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    Bytes::from(args)
+}
+
+fn run_test(case: MoveCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let config_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let config_out_point = context.deploy_cell(config_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let admin_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"admin".to_vec()))
+        .expect("admin lock script");
+    let config_type_script = context
+        .build_script(&config_out_point, Bytes::from([9u8; 32].to_vec()))
+        .expect("config type script");
+    // In practice, the successor uses a new code hash, but it doesn't matter
+    // here, since only the type hashes are checked.
+    let predecessor_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&[1u8; 32], &config_type_script),
+        )
+        .expect("predecessor script");
+    let successor_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&[2u8; 32], &config_type_script),
+        )
+        .expect("successor script");
+    let another_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&[3u8; 32], &config_type_script),
+        )
+        .expect("another script");
+    let fake_predecessor_script = context
+        .build_script(&lock_out_point, Bytes::from(b"fake".to_vec()))
+        .expect("fake predecessor script");
+
+    // prepare cell deps
+    let predecessor_hash = predecessor_script.calc_script_hash().unpack().0;
+    let successor_hash = successor_script.calc_script_hash().unpack().0;
+    let another_hash = another_script.calc_script_hash().unpack().0;
+    let admin_lock_hash = admin_lock_script.calc_script_hash().unpack().0;
+    let config_data = |version| {
+        let config = types::RegistryConfig::new_with_fields(version, b"demo", admin_lock_hash);
+        let config = match case.migration {
+            Migration::Designated => config.with_migration(predecessor_hash, successor_hash),
+            Migration::Undesignated => config.with_migration(predecessor_hash, another_hash),
+            Migration::Unset => config,
+        };
+        let config = match case.time_lock {
+            TimeLock::Unset => config,
+            TimeLock::InCellDeps | TimeLock::Waited(_) => config
+                .with_destroy_delay(DESTROY_DELAY)
+                .with_destroy_scheduled(true),
+        };
+        Bytes::copy_from_slice(config.as_slice())
+    };
+    let config_output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(
+            ScriptOpt::new_builder()
+                .set(Some(config_type_script))
+                .build(),
+        )
+        .build();
+    let config_cell_out_point = context.create_cell(config_output.clone(), config_data(0));
+    let mut cell_deps = Vec::new();
+
+    // prepare inputs
+    let input_type_script = if case.fake_predecessor {
+        fake_predecessor_script
+    } else {
+        predecessor_script
+    };
+    let input = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(
+            ScriptOpt::new_builder()
+                .set(Some(input_type_script))
+                .build(),
+        )
+        .build();
+    let mut inputs = case
+        .inputs_data()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(input.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+    // The config cell is updated, if it's consumed.
+    let mut config_outputs = Vec::new();
+    match case.time_lock {
+        TimeLock::Unset | TimeLock::InCellDeps => cell_deps.push(
+            CellDep::new_builder()
+                .out_point(config_cell_out_point)
+                .build(),
+        ),
+        TimeLock::Waited(epochs) => {
+            inputs.push(
+                CellInput::new_builder()
+                    .previous_output(config_cell_out_point)
+                    .since(epoch_since(epochs, true).pack())
+                    .build(),
+            );
+            config_outputs.push((config_output, config_data(1)));
+        }
+    }
+    if case.authorized {
+        let admin_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(admin_lock_script)
+                .build(),
+            Bytes::new(),
+        );
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(admin_out_point)
+                .build(),
+        );
+    }
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(ScriptOpt::new_builder().set(Some(successor_script)).build())
+        .build();
+    let mut outputs = vec![output; case.outputs_data.len()];
+    let mut outputs_data = case.outputs_data();
+    for (config_output, data) in config_outputs {
+        outputs.push(config_output);
+        outputs_data.push(data);
+    }
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
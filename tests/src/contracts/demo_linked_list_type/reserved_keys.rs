@@ -0,0 +1,260 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::{merkle::MerkleTree, types};
+
+use crate::{prelude::*, utilities, Loader};
+
+const RESERVED_KEYS: &[&[u8]] = &[b"brand", b"ckb", b"nervos"];
+
+// The proof of the inserted key in the witness.
+#[derive(Clone, Copy)]
+enum Proof {
+    None,
+    Valid,
+    // The proof is built from another set of reserved keys.
+    Forged,
+    // The leaves around the key are not adjacent.
+    NonAdjacent,
+}
+
+pub(crate) struct ReservedKeysCase<'a> {
+    inserted_key: &'a [u8],
+    proof: Proof,
+    // Put a cell which uses the admin lock in inputs.
+    authorized: bool,
+    should_passed: bool,
+}
+
+#[test]
+fn insert_unreserved_key() {
+    let case = ReservedKeysCase {
+        inserted_key: b"dave",
+        proof: Proof::Valid,
+        authorized: false,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_unreserved_key_after_all_reserved_keys() {
+    let case = ReservedKeysCase {
+        inserted_key: b"zed",
+        proof: Proof::Valid,
+        authorized: false,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_unreserved_key_before_all_reserved_keys() {
+    let case = ReservedKeysCase {
+        inserted_key: b"alice",
+        proof: Proof::Valid,
+        authorized: false,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_reserved_key() {
+    let case = ReservedKeysCase {
+        inserted_key: b"ckb",
+        proof: Proof::Valid,
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_reserved_key_by_admin() {
+    let case = ReservedKeysCase {
+        inserted_key: b"ckb",
+        proof: Proof::None,
+        authorized: true,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_key_without_proof() {
+    let case = ReservedKeysCase {
+        inserted_key: b"dave",
+        proof: Proof::None,
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_reserved_key_with_forged_proof() {
+    let case = ReservedKeysCase {
+        inserted_key: b"ckb",
+        proof: Proof::Forged,
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn insert_reserved_key_with_non_adjacent_leaves() {
+    let case = ReservedKeysCase {
+        inserted_key: b"ckb",
+        proof: Proof::NonAdjacent,
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+fn build_proof(proof: Proof, key: &[u8]) -> Option<types::MerkleKeyProof> {
+    let tree = MerkleTree::new(RESERVED_KEYS);
+    match proof {
+        Proof::None => None,
+        Proof::Valid => Some(tree.prove(key)),
+        Proof::Forged => {
+            let keys = RESERVED_KEYS.iter().filter(|k| **k != key);
+            Some(MerkleTree::new(keys).prove(key))
+        }
+        Proof::NonAdjacent => {
+            let leaves = types::MerkleLeafProofVec::new_builder()
+                .push(tree.prove(RESERVED_KEYS[0]).leaves().get(0).unwrap())
+                .push(tree.prove(RESERVED_KEYS[2]).leaves().get(0).unwrap())
+                .build();
+            let proof = types::MerkleKeyProof::new_builder()
+                .key(types::Bytes::new_from_raw_slice(key))
+                .leaves(leaves)
+                .build();
+            Some(proof)
+        }
+    }
+}
+
+fn build_witness(proof: types::MerkleKeyProof) -> Bytes {
+    let output = types::RegistryOutput::new_builder()
+        .reserved_key_proofs(types::MerkleKeyProofVec::new_builder().push(proof).build())
+        .build();
+    let output_type = Bytes::copy_from_slice(output.as_slice());
+    WitnessArgs::new_builder()
+        .output_type(Some(output_type).pack())
+        .build()
+        .as_bytes()
+}
+
+fn run_test(case: ReservedKeysCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let config_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let config_out_point = context.deploy_cell(config_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let admin_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"admin".to_vec()))
+        .expect("admin lock script");
+    let config_type_script = context
+        .build_script(&config_out_point, Bytes::from([1u8; 32].to_vec()))
+        .expect("config type script");
+    let config_type_hash = config_type_script.calc_script_hash().unpack().0;
+    let instance_args = types::InstanceArgs::default().with_config_type_hash(config_type_hash);
+    let mut args = vec![0u8; 32];
+    args.extend_from_slice(instance_args.as_slice());
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from(args))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare cell deps
+    let admin_lock_hash = admin_lock_script.calc_script_hash().unpack().0;
+    let config = types::RegistryConfig::new_with_fields(0, b"demo", admin_lock_hash)
+        .with_reserved_keys(MerkleTree::new(RESERVED_KEYS).to_set());
+    let config_cell_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(admin_lock_script.clone())
+            .type_(
+                ScriptOpt::new_builder()
+                    .set(Some(config_type_script))
+                    .build(),
+            )
+            .build(),
+        Bytes::copy_from_slice(config.as_slice()),
+    );
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script)
+        .type_(type_script_opt)
+        .build();
+    let input_data = types::DemoData::new_from_raw_slices(b"1-st", b"a", b"zzz");
+    let input_out_point = context.create_cell(
+        output.clone(),
+        Bytes::copy_from_slice(input_data.as_slice()),
+    );
+    let mut inputs = vec![CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build()];
+    if case.authorized {
+        let admin_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(admin_lock_script)
+                .build(),
+            Bytes::new(),
+        );
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(admin_out_point)
+                .build(),
+        );
+    }
+
+    // prepare outputs
+    let outputs_data = [
+        types::DemoData::new_from_raw_slices(b"1-st", b"a", case.inserted_key),
+        types::DemoData::new_from_raw_slices(b"2-nd", case.inserted_key, b"zzz"),
+    ]
+    .iter()
+    .map(|data| Bytes::copy_from_slice(data.as_slice()))
+    .collect::<Vec<_>>();
+    let outputs = vec![output; outputs_data.len()];
+
+    // build transaction
+    let mut builder = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(config_cell_out_point)
+                .build(),
+        );
+    if let Some(proof) = build_proof(case.proof, case.inserted_key) {
+        builder = builder.witness(build_witness(proof).pack());
+    }
+    let tx = context.complete_tx(builder.build());
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
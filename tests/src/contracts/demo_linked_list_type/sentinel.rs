@@ -0,0 +1,331 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::{
+    constants::{FLAG_SENTINELS, SENTINEL_MAX_KEY as MAX, SENTINEL_MIN_KEY as MIN},
+    types,
+};
+
+use super::FullListCase;
+use crate::{prelude::*, utilities, Loader};
+
+pub(crate) struct SentinelUpdateCase<'a, 'b> {
+    inputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    outputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    should_passed: bool,
+}
+
+impl SentinelUpdateCase<'_, '_> {
+    pub(crate) fn inputs_data(&self) -> Vec<Bytes> {
+        self.inputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+
+    pub(crate) fn outputs_data(&self) -> Vec<Bytes> {
+        self.outputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn create_only_sentinels() {
+    let case = FullListCase {
+        demo_data: &[(b"min", MIN, MAX), (b"max", MAX, MIN)],
+        should_passed: true,
+    };
+    run_create_test(case);
+}
+
+#[test]
+fn create_with_items() {
+    let case = FullListCase {
+        demo_data: &[
+            (b"min", MIN, &[1, 1, 1]),
+            (b"1-st", &[1, 1, 1], &[2, 2, 2]),
+            (b"2-nd", &[2, 2, 2], MAX),
+            (b"max", MAX, MIN),
+        ],
+        should_passed: true,
+    };
+    run_create_test(case);
+}
+
+#[test]
+fn create_without_max_sentinel() {
+    let case = FullListCase {
+        demo_data: &[(b"min", MIN, &[1, 1, 1]), (b"1-st", &[1, 1, 1], MIN)],
+        should_passed: false,
+    };
+    run_create_test(case);
+}
+
+#[test]
+fn create_beyond_max_sentinel() {
+    let case = FullListCase {
+        demo_data: &[
+            (b"min", MIN, MAX),
+            (b"max", MAX, &[0xff; 33]),
+            (b"1-st", &[0xff; 33], MIN),
+        ],
+        should_passed: false,
+    };
+    run_create_test(case);
+}
+
+#[test]
+fn insert_into_empty() {
+    let case = SentinelUpdateCase {
+        inputs_data: &[(b"min", MIN, MAX)],
+        outputs_data: &[(b"min", MIN, &[1, 1, 1]), (b"1-st", &[1, 1, 1], MAX)],
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn remove_all_items() {
+    let case = SentinelUpdateCase {
+        inputs_data: &[
+            (b"min", MIN, &[1, 1, 1]),
+            (b"1-st", &[1, 1, 1], &[2, 2, 2]),
+            (b"2-nd", &[2, 2, 2], MAX),
+        ],
+        outputs_data: &[(b"min", MIN, MAX)],
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn remove_min_sentinel() {
+    let case = SentinelUpdateCase {
+        inputs_data: &[
+            (b"max", MAX, MIN),
+            (b"min", MIN, &[1, 1, 1]),
+            (b"1-st", &[1, 1, 1], MAX),
+        ],
+        outputs_data: &[(b"max", MAX, &[1, 1, 1]), (b"1-st", &[1, 1, 1], MAX)],
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn insert_beyond_max_sentinel() {
+    let case = SentinelUpdateCase {
+        inputs_data: &[(b"max", MAX, MIN)],
+        outputs_data: &[(b"max", MAX, &[0xff; 33]), (b"1-st", &[0xff; 33], MIN)],
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn destroy_only_sentinels() {
+    let case = FullListCase {
+        demo_data: &[(b"max", MAX, MIN), (b"min", MIN, MAX)],
+        should_passed: true,
+    };
+    run_destroy_test(case);
+}
+
+#[test]
+fn destroy_with_items() {
+    let case = FullListCase {
+        demo_data: &[
+            (b"min", MIN, &[1, 1, 1]),
+            (b"1-st", &[1, 1, 1], MAX),
+            (b"max", MAX, MIN),
+        ],
+        should_passed: false,
+    };
+    run_destroy_test(case);
+}
+
+fn build_type_args(unique_id: &[u8]) -> Bytes {
+    let instance_args = types::InstanceArgs::new_with_flags(FLAG_SENTINELS);
+    let mut args = unique_id.to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    Bytes::from(args)
+}
+
+fn run_create_test(case: FullListCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+
+    // prepare inputs
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(10000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare type scripts
+    let output_index = 0;
+    let unique_id = utilities::calculate_unique_id(input.clone(), output_index);
+    let type_script = context
+        .build_script(&type_out_point, build_type_args(&unique_id))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt.clone())
+        .build();
+    let outputs = vec![output.clone(); case.demo_data.len()];
+    let outputs_data = case.demo_data();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_update_test(case: SentinelUpdateCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let type_script = context
+        .build_script(&type_out_point, build_type_args(&[0u8; 32]))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt.clone())
+        .build();
+    let inputs = case
+        .inputs_data()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let outputs = vec![output.clone(); case.outputs_data.len()];
+    let outputs_data = case.outputs_data();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_destroy_test(case: FullListCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let type_script = context
+        .build_script(&type_out_point, build_type_args(&[0u8; 32]))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let inputs = {
+        let output = CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(type_script_opt)
+            .build();
+        case.demo_data()
+            .into_iter()
+            .map(|bytes| {
+                let out_point = context.create_cell(output.clone(), bytes);
+                CellInput::new_builder().previous_output(out_point).build()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // prepare outputs
+    let outputs = vec![CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .build()];
+    let outputs_data = vec![Bytes::new(); outputs.len()];
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
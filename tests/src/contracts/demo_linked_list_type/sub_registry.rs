@@ -0,0 +1,280 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use crate::{prelude::*, utilities, Loader};
+
+const PARENT_KEY: &[u8] = b"team";
+
+const DEMO_DATA: &[(&[u8], &[u8], &[u8])] = &[(b"1-st", b"api", b"app"), (b"2-nd", b"app", b"api")];
+
+// The parent item in cell deps.
+#[derive(Clone, Copy)]
+struct ParentItem<'a> {
+    key: &'a [u8],
+    // The owner of the item is set, otherwise the item is owned by its lock.
+    owned: bool,
+    // The item uses the type script of the parent registry.
+    genuine: bool,
+}
+
+pub(crate) struct SubRegistryCase<'a> {
+    parent_item: Option<ParentItem<'a>>,
+    // Put a cell which uses the owner lock of the parent item in inputs.
+    authorized: bool,
+    // The parent registry is a child registry too.
+    nested: bool,
+    should_passed: bool,
+}
+
+#[test]
+fn create_child_registry() {
+    let case = SubRegistryCase {
+        parent_item: Some(ParentItem {
+            key: PARENT_KEY,
+            owned: true,
+            genuine: true,
+        }),
+        authorized: true,
+        nested: false,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn create_grandchild_registry() {
+    let case = SubRegistryCase {
+        parent_item: Some(ParentItem {
+            key: PARENT_KEY,
+            owned: true,
+            genuine: true,
+        }),
+        authorized: true,
+        nested: true,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn create_child_registry_of_unowned_parent() {
+    let case = SubRegistryCase {
+        parent_item: Some(ParentItem {
+            key: PARENT_KEY,
+            owned: false,
+            genuine: true,
+        }),
+        authorized: true,
+        nested: false,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn create_child_registry_without_authorization() {
+    let case = SubRegistryCase {
+        parent_item: Some(ParentItem {
+            key: PARENT_KEY,
+            owned: true,
+            genuine: true,
+        }),
+        authorized: false,
+        nested: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn create_child_registry_of_unowned_parent_without_authorization() {
+    let case = SubRegistryCase {
+        parent_item: Some(ParentItem {
+            key: PARENT_KEY,
+            owned: false,
+            genuine: true,
+        }),
+        authorized: false,
+        nested: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn create_child_registry_without_parent() {
+    let case = SubRegistryCase {
+        parent_item: None,
+        authorized: true,
+        nested: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn create_child_registry_with_mismatched_key() {
+    let case = SubRegistryCase {
+        parent_item: Some(ParentItem {
+            key: b"other",
+            owned: true,
+            genuine: true,
+        }),
+        authorized: true,
+        nested: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn create_child_registry_with_fake_parent() {
+    let case = SubRegistryCase {
+        parent_item: Some(ParentItem {
+            key: PARENT_KEY,
+            owned: true,
+            genuine: false,
+        }),
+        authorized: true,
+        nested: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+fn demo_data_list() -> Vec<Bytes> {
+    DEMO_DATA
+        .iter()
+        .map(|(x, y, z)| {
+            let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+            Bytes::copy_from_slice(demo_data.as_slice())
+        })
+        .collect()
+}
+
+fn build_type_args(unique_id: &[u8], parent_type_hash: [u8; 32], parent_key: &[u8]) -> Bytes {
+    let instance_args = types::InstanceArgs::default().with_parent(parent_type_hash, parent_key);
+    let mut args = unique_id.to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    Bytes::from(args)
+}
+
+fn run_test(case: SubRegistryCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let owner_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"owner".to_vec()))
+        .expect("owner lock script");
+    let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().0;
+
+    // prepare the parent registry
+    let parent_args = if case.nested {
+        // The parent registry is the child registry of "org" in the root
+        // registry, it's not checked here.
+        build_type_args(&[5u8; 32], [6u8; 32], b"org")
+    } else {
+        Bytes::from([5u8; 32].to_vec())
+    };
+    let parent_type_script = context
+        .build_script(&type_out_point, parent_args)
+        .expect("parent type script");
+    let parent_type_hash = parent_type_script.calc_script_hash().unpack().0;
+    let fake_parent_type_script = context
+        .build_script(&lock_out_point, Bytes::from(b"fake".to_vec()))
+        .expect("fake parent type script");
+
+    // prepare cell deps
+    let mut cell_deps = Vec::new();
+    if let Some(parent_item) = case.parent_item {
+        let parent_type_script = if parent_item.genuine {
+            parent_type_script
+        } else {
+            fake_parent_type_script
+        };
+        let demo_data = types::DemoDataV2::new_from_raw_slices(b"parent", parent_item.key, b"zzz");
+        let (demo_data, lock) = if parent_item.owned {
+            (demo_data.with_owner(owner_lock_hash), lock_script.clone())
+        } else {
+            (demo_data, owner_lock_script.clone())
+        };
+        let parent_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(500u64.pack())
+                .lock(lock)
+                .type_(
+                    ScriptOpt::new_builder()
+                        .set(Some(parent_type_script))
+                        .build(),
+                )
+                .build(),
+            demo_data.to_cell_data(),
+        );
+        cell_deps.push(CellDep::new_builder().out_point(parent_out_point).build());
+    }
+
+    // prepare inputs
+    let input_lock_script = if case.authorized {
+        owner_lock_script
+    } else {
+        lock_script.clone()
+    };
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(10000u64.pack())
+            .lock(input_lock_script)
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare type scripts
+    let unique_id = utilities::calculate_unique_id(input.clone(), 0);
+    let type_script = context
+        .build_script(
+            &type_out_point,
+            build_type_args(&unique_id, parent_type_hash, PARENT_KEY),
+        )
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script)
+        .type_(type_script_opt)
+        .build();
+    let outputs = vec![output; DEMO_DATA.len()];
+    let outputs_data = demo_data_list();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
@@ -0,0 +1,321 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::*,
+        prelude::*,
+    },
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use crate::{prelude::*, utilities, Loader};
+
+// The owner of an item.
+#[derive(Clone, Copy)]
+enum Owner {
+    None,
+    // The owner lock is used by a cell in inputs.
+    Current,
+    New,
+}
+
+// An item in version 1 or version 2.
+#[derive(Clone, Copy)]
+enum Item<'a> {
+    V1(&'a [u8], &'a [u8], &'a [u8]),
+    V2(&'a [u8], &'a [u8], &'a [u8], Owner),
+}
+
+impl Item<'_> {
+    fn to_cell_data(self, current_owner: [u8; 32]) -> Bytes {
+        match self {
+            Self::V1(x, y, z) => {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            }
+            Self::V2(x, y, z, owner) => {
+                let demo_data = types::DemoDataV2::new_from_raw_slices(x, y, z);
+                match owner {
+                    Owner::None => demo_data,
+                    Owner::Current => demo_data.with_owner(current_owner),
+                    Owner::New => demo_data.with_owner([7u8; 32]),
+                }
+                .to_cell_data()
+            }
+        }
+    }
+}
+
+pub(crate) struct TransferOwnerCase<'a, 'b> {
+    inputs_data: &'a [Item<'b>],
+    outputs_data: &'a [Item<'b>],
+    action: Option<types::RegistryAction>,
+    // Put a cell which uses the current owner lock in inputs.
+    authorized: bool,
+    should_passed: bool,
+}
+
+fn transfer_owner(key: &[u8]) -> Option<types::RegistryAction> {
+    let transfer_owner = types::TransferOwner::new_builder()
+        .key(types::Bytes::new_from_raw_slice(key))
+        .build();
+    Some(
+        types::RegistryAction::new_builder()
+            .set(transfer_owner)
+            .build(),
+    )
+}
+
+#[test]
+fn transfer_owner_success() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::New)],
+        action: transfer_owner(&[2]),
+        authorized: true,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_without_authorization() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::New)],
+        action: transfer_owner(&[2]),
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_of_unowned_item() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::None)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::New)],
+        action: transfer_owner(&[2]),
+        authorized: false,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_from_version_1() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V1(b"2-nd", &[2], &[3])],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::New)],
+        action: transfer_owner(&[2]),
+        authorized: false,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_to_none() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::None)],
+        action: transfer_owner(&[2]),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_to_same_owner() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        action: transfer_owner(&[2]),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_with_modified_payload() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"new", &[2], &[3], Owner::New)],
+        action: transfer_owner(&[2]),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_with_neighbour() {
+    let case = TransferOwnerCase {
+        inputs_data: &[
+            Item::V2(b"2-nd", &[2], &[3], Owner::Current),
+            Item::V2(b"3-rd", &[3], &[4], Owner::None),
+        ],
+        outputs_data: &[
+            Item::V2(b"2-nd", &[2], &[3], Owner::New),
+            Item::V2(b"3-rd", &[3], &[4], Owner::None),
+        ],
+        action: transfer_owner(&[2]),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn transfer_owner_with_mismatched_key() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::New)],
+        action: transfer_owner(&[3]),
+        authorized: true,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+#[test]
+fn change_owner_without_action() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::New)],
+        action: None,
+        authorized: true,
+        should_passed: true,
+    };
+    run_test(case);
+}
+
+#[test]
+fn change_owner_without_action_and_authorization() {
+    let case = TransferOwnerCase {
+        inputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::Current)],
+        outputs_data: &[Item::V2(b"2-nd", &[2], &[3], Owner::None)],
+        action: None,
+        authorized: false,
+        should_passed: false,
+    };
+    run_test(case);
+}
+
+// The exit code of `InternalError::UpdateUnauthorizedRemove`.
+const UPDATE_UNAUTHORIZED_REMOVE: i8 = 0x47;
+
+// Remove an owned item, so it could be inserted again with a new owner.
+fn remove_owned_item(authorized: bool) -> TransferOwnerCase<'static, 'static> {
+    TransferOwnerCase {
+        inputs_data: &[
+            Item::V2(b"1-st", &[1], &[2], Owner::None),
+            Item::V2(b"2-nd", &[2], &[3], Owner::Current),
+        ],
+        outputs_data: &[Item::V2(b"1-st", &[1], &[3], Owner::None)],
+        action: None,
+        authorized,
+        should_passed: authorized,
+    }
+}
+
+#[test]
+fn remove_owned_item_by_owner() {
+    run_test(remove_owned_item(true));
+}
+
+#[test]
+fn remove_owned_item_without_authorization() {
+    let (context, tx) = build_tx(remove_owned_item(false));
+    utilities::assert_error_code(
+        context.should_be_failed_without_limit(&tx),
+        UPDATE_UNAUTHORIZED_REMOVE,
+    );
+}
+
+fn build_witness(action: &types::RegistryAction) -> Bytes {
+    let input_type = Bytes::copy_from_slice(action.as_slice());
+    WitnessArgs::new_builder()
+        .input_type(Some(input_type).pack())
+        .build()
+        .as_bytes()
+}
+
+fn run_test(case: TransferOwnerCase) {
+    let should_passed = case.should_passed;
+    let (context, tx) = build_tx(case);
+    if should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn build_tx(case: TransferOwnerCase) -> (Context, TransactionView) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let owner_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"owner".to_vec()))
+        .expect("owner lock script");
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from([0u8; 32].to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+    let current_owner = owner_lock_script.calc_script_hash().unpack().0;
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let mut inputs = case
+        .inputs_data
+        .iter()
+        .map(|item| {
+            let out_point = context.create_cell(output.clone(), item.to_cell_data(current_owner));
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+    if case.authorized {
+        let owner_cell = CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(owner_lock_script)
+            .build();
+        let out_point = context.create_cell(owner_cell, Bytes::new());
+        inputs.push(CellInput::new_builder().previous_output(out_point).build());
+    }
+
+    // prepare outputs
+    let outputs = vec![output; case.outputs_data.len()];
+    let outputs_data = case
+        .outputs_data
+        .iter()
+        .map(|item| item.to_cell_data(current_owner))
+        .collect::<Vec<_>>();
+
+    // build transaction
+    let mut builder = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack());
+    if let Some(action) = &case.action {
+        builder = builder.witness(build_witness(action).pack());
+    }
+    let tx = context.complete_tx(builder.build());
+    (context, tx)
+}
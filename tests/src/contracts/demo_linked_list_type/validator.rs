@@ -0,0 +1,185 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_hash::blake2b_256,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use crate::{prelude::*, utilities, Loader};
+
+// The hash type `data1`.
+const HASH_TYPE_DATA1: u8 = 2;
+
+pub(crate) struct ValidatorUpdateCase<'a, 'b> {
+    inputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    outputs_data: &'a [(&'b [u8], &'b [u8], &'b [u8])],
+    should_passed: bool,
+}
+
+impl ValidatorUpdateCase<'_, '_> {
+    pub(crate) fn inputs_data(&self) -> Vec<Bytes> {
+        self.inputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+
+    pub(crate) fn outputs_data(&self) -> Vec<Bytes> {
+        self.outputs_data
+            .iter()
+            .map(|(x, y, z)| {
+                let demo_data = types::DemoData::new_from_raw_slices(x, y, z);
+                Bytes::copy_from_slice(demo_data.as_slice())
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn insert_accepted_by_validator() {
+    let case = ValidatorUpdateCase {
+        inputs_data: &[(b"1-st", &[1], &[3])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[3])],
+        should_passed: true,
+    };
+    run_update_test(case, HASH_TYPE_DATA1);
+}
+
+#[test]
+fn insert_rejected_by_validator() {
+    let case = ValidatorUpdateCase {
+        inputs_data: &[(b"1-st", &[1], &[3])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"", &[2], &[3])],
+        should_passed: false,
+    };
+    run_update_test(case, HASH_TYPE_DATA1);
+}
+
+#[test]
+fn modify_rejected_by_validator() {
+    let case = ValidatorUpdateCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[1])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"", &[2], &[1])],
+        should_passed: false,
+    };
+    run_update_test(case, HASH_TYPE_DATA1);
+}
+
+#[test]
+fn remove_accepted_by_validator() {
+    let case = ValidatorUpdateCase {
+        inputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[3])],
+        outputs_data: &[(b"1-st", &[1], &[3])],
+        should_passed: true,
+    };
+    run_update_test(case, HASH_TYPE_DATA1);
+}
+
+#[test]
+fn validator_with_invalid_hash_type() {
+    let case = ValidatorUpdateCase {
+        inputs_data: &[(b"1-st", &[1], &[3])],
+        outputs_data: &[(b"1-st", &[1], &[2]), (b"2-nd", &[2], &[3])],
+        should_passed: false,
+    };
+    run_update_test(case, 3);
+}
+
+fn build_type_args(config_type_script: &Script) -> Bytes {
+    let config_type_hash = config_type_script.calc_script_hash().unpack().0;
+    let instance_args = types::InstanceArgs::default().with_config_type_hash(config_type_hash);
+    let mut args = [0u8; 32].to_vec();
+    args.extend_from_slice(instance_args.as_slice());
+    Bytes::from(args)
+}
+
+fn run_update_test(case: ValidatorUpdateCase, hash_type: u8) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let config_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let config_out_point = context.deploy_cell(config_bin);
+    let validator_bin: Bytes = Loader::default().load_binary("demo-key-validator");
+    let validator_code_hash = blake2b_256(&validator_bin);
+    let validator_out_point = context.deploy_cell(validator_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let config_type_script = context
+        .build_script(&config_out_point, Bytes::from([1u8; 32].to_vec()))
+        .expect("config type script");
+    let type_script = context
+        .build_script(&type_out_point, build_type_args(&config_type_script))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare cell deps
+    let config = types::RegistryConfig::new_with_fields(0, b"demo", [0u8; 32])
+        .with_validator(validator_code_hash, hash_type);
+    let config_cell_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(
+                ScriptOpt::new_builder()
+                    .set(Some(config_type_script))
+                    .build(),
+            )
+            .build(),
+        Bytes::copy_from_slice(config.as_slice()),
+    );
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let inputs = case
+        .inputs_data()
+        .into_iter()
+        .map(|bytes| {
+            let out_point = context.create_cell(output.clone(), bytes);
+            CellInput::new_builder().previous_output(out_point).build()
+        })
+        .collect::<Vec<_>>();
+
+    // prepare outputs
+    let outputs = vec![output; case.outputs_data.len()];
+    let outputs_data = case.outputs_data();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(config_cell_out_point)
+                .build(),
+        )
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(validator_out_point)
+                .build(),
+        )
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
@@ -0,0 +1,374 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::{merkle::MerkleTree, types};
+
+use crate::{prelude::*, utilities, Loader};
+
+struct ConfigUpdateCase {
+    input_version: u32,
+    output_version: u32,
+    authorized: bool,
+    changed_reserved_keys: bool,
+    changed_destroy_delay: bool,
+    scheduled_destroy: bool,
+    should_passed: bool,
+}
+
+#[test]
+fn create_config() {
+    run_create_test(true, true);
+}
+
+#[test]
+fn create_config_with_incorrect_type_id() {
+    run_create_test(false, false);
+}
+
+#[test]
+fn update_config_by_admin() {
+    let case = ConfigUpdateCase {
+        input_version: 0,
+        output_version: 1,
+        authorized: true,
+        changed_reserved_keys: false,
+        changed_destroy_delay: false,
+        scheduled_destroy: false,
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_config_without_admin() {
+    let case = ConfigUpdateCase {
+        input_version: 0,
+        output_version: 1,
+        authorized: false,
+        changed_reserved_keys: false,
+        changed_destroy_delay: false,
+        scheduled_destroy: false,
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_config_with_unchanged_version() {
+    let case = ConfigUpdateCase {
+        input_version: 3,
+        output_version: 3,
+        authorized: true,
+        changed_reserved_keys: false,
+        changed_destroy_delay: false,
+        scheduled_destroy: false,
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_config_with_skipped_version() {
+    let case = ConfigUpdateCase {
+        input_version: 3,
+        output_version: 5,
+        authorized: true,
+        changed_reserved_keys: false,
+        changed_destroy_delay: false,
+        scheduled_destroy: false,
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_config_with_changed_reserved_keys() {
+    let case = ConfigUpdateCase {
+        input_version: 0,
+        output_version: 1,
+        authorized: true,
+        changed_reserved_keys: true,
+        changed_destroy_delay: false,
+        scheduled_destroy: false,
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_config_with_changed_destroy_delay() {
+    let case = ConfigUpdateCase {
+        input_version: 0,
+        output_version: 1,
+        authorized: true,
+        changed_reserved_keys: false,
+        changed_destroy_delay: true,
+        scheduled_destroy: false,
+        should_passed: false,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn update_config_to_schedule_destroy() {
+    let case = ConfigUpdateCase {
+        input_version: 0,
+        output_version: 1,
+        authorized: true,
+        changed_reserved_keys: false,
+        changed_destroy_delay: false,
+        scheduled_destroy: true,
+        should_passed: true,
+    };
+    run_update_test(case);
+}
+
+#[test]
+fn destroy_config_by_admin() {
+    run_destroy_test(true, false);
+}
+
+#[test]
+fn destroy_config_without_admin() {
+    run_destroy_test(false, false);
+}
+
+fn config_data(version: u32, admin_lock: &Script) -> Bytes {
+    let admin_lock_hash = admin_lock.calc_script_hash().unpack().0;
+    let config = types::RegistryConfig::new_with_fields(version, b"demo", admin_lock_hash);
+    Bytes::copy_from_slice(config.as_slice())
+}
+
+fn config_data_with_fixed_fields(
+    version: u32,
+    admin_lock: &Script,
+    keys: &[&[u8]],
+    destroy_delay: u64,
+    scheduled_destroy: bool,
+) -> Bytes {
+    let admin_lock_hash = admin_lock.calc_script_hash().unpack().0;
+    let config = types::RegistryConfig::new_with_fields(version, b"demo", admin_lock_hash)
+        .with_reserved_keys(MerkleTree::new(keys).to_set())
+        .with_destroy_delay(destroy_delay)
+        .with_destroy_scheduled(scheduled_destroy);
+    Bytes::copy_from_slice(config.as_slice())
+}
+
+fn run_create_test(correct_type_id: bool, should_passed: bool) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare lock scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let admin_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"admin".to_vec()))
+        .expect("admin lock script");
+
+    // prepare inputs
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(10000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare type scripts
+    let output_index = if correct_type_id { 0 } else { 1 };
+    let type_id = utilities::calculate_unique_id(input.clone(), output_index);
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from(type_id.to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let output_data = config_data(0, &admin_lock_script);
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .output(output)
+        .output_data(output_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_update_test(case: ConfigUpdateCase) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let admin_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"admin".to_vec()))
+        .expect("admin lock script");
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from([0u8; 32].to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script.clone())
+        .type_(type_script_opt)
+        .build();
+    let config_out_point = context.create_cell(
+        output.clone(),
+        config_data_with_fixed_fields(
+            case.input_version,
+            &admin_lock_script,
+            &[b"brand"],
+            10,
+            false,
+        ),
+    );
+    let mut inputs = vec![CellInput::new_builder()
+        .previous_output(config_out_point)
+        .build()];
+    if case.authorized {
+        let admin_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(admin_lock_script.clone())
+                .build(),
+            Bytes::new(),
+        );
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(admin_out_point)
+                .build(),
+        );
+    }
+
+    // prepare outputs
+    let reserved_keys: &[&[u8]] = if case.changed_reserved_keys {
+        &[b"brand", b"other"]
+    } else {
+        &[b"brand"]
+    };
+    let destroy_delay = if case.changed_destroy_delay { 5 } else { 10 };
+    let output_data = config_data_with_fixed_fields(
+        case.output_version,
+        &admin_lock_script,
+        reserved_keys,
+        destroy_delay,
+        case.scheduled_destroy,
+    );
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .output(output)
+        .output_data(output_data.pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if case.should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
+
+fn run_destroy_test(authorized: bool, should_passed: bool) {
+    utilities::setup();
+
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+    let type_out_point = context.deploy_cell(contract_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let lock_script = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let admin_lock_script = context
+        .build_script(&lock_out_point, Bytes::from(b"admin".to_vec()))
+        .expect("admin lock script");
+    let type_script = context
+        .build_script(&type_out_point, Bytes::from([0u8; 32].to_vec()))
+        .expect("type script");
+    let type_script_opt = ScriptOpt::new_builder().set(Some(type_script)).build();
+
+    // prepare inputs
+    let config_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(lock_script.clone())
+            .type_(type_script_opt)
+            .build(),
+        config_data(0, &admin_lock_script),
+    );
+    let mut inputs = vec![CellInput::new_builder()
+        .previous_output(config_out_point)
+        .build()];
+    if authorized {
+        let admin_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(admin_lock_script.clone())
+                .build(),
+            Bytes::new(),
+        );
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(admin_out_point)
+                .build(),
+        );
+    }
+
+    // prepare outputs
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .lock(lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .output(output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    if should_passed {
+        let _ = context.should_be_passed_without_limit(&tx);
+    } else {
+        let _ = context.should_be_failed_without_limit(&tx);
+    }
+}
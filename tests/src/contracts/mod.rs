@@ -1 +1,2 @@
 mod demo_linked_list_type;
+mod demo_registry_config_type;
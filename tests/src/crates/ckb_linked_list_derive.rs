@@ -0,0 +1,75 @@
+use ckb_linked_list_tool::{
+    check_linked_list_with_ordered_items, check_linked_list_with_unordered_items, error::Error,
+    types::LinkedListItem,
+};
+use demo_linked_list_lib::types;
+
+#[derive(ckb_linked_list_tool::LinkedListItem)]
+#[linked_list(field = "types::Bytes")]
+struct DemoItem(types::DemoData);
+
+// The current data and the next data are swapped, to check the field names.
+#[derive(ckb_linked_list_tool::LinkedListItem)]
+#[linked_list(
+    entity = "types::DemoData",
+    field = "types::Bytes",
+    curr = "next",
+    next = "curr"
+)]
+struct ReversedDemoItem;
+
+fn build_items(items: &[(&[u8], &[u8])]) -> Vec<types::DemoData> {
+    items
+        .iter()
+        .map(|(curr, next)| types::DemoData::new_from_raw_slices(b"demo", curr, next))
+        .collect()
+}
+
+#[test]
+fn parse_entities() {
+    let items = build_items(&[(b"a", b"b"), (b"b", b"c"), (b"c", b"a")])
+        .into_iter()
+        .map(DemoItem);
+    let summary = check_linked_list_with_ordered_items::<_, _, _, Error>(
+        items.map(|item| item.0),
+        DemoItem::parse_entity,
+    )
+    .unwrap_or_else(|_| panic!("should be a valid list"));
+    assert!(summary.is_complete());
+    assert_eq!(&summary.start.raw_data()[..], b"a");
+}
+
+#[test]
+fn parse_readers() {
+    let items = build_items(&[(b"c", b"a"), (b"a", b"b")]);
+    let readers = items.iter().map(|item| item.as_reader());
+    let summary =
+        check_linked_list_with_unordered_items::<_, _, _, Error>(readers, DemoItem::parse_reader)
+            .unwrap_or_else(|_| panic!("should be a valid list"));
+    assert!(!summary.is_complete());
+    assert!(summary.has_last());
+    assert_eq!(summary.start.raw_data(), b"c");
+    assert_eq!(summary.end.raw_data(), b"b");
+}
+
+#[test]
+fn parse_discontinuous_readers() {
+    let items = build_items(&[(b"a", b"b"), (b"c", b"d")]);
+    let readers = items.iter().map(|item| item.as_reader());
+    let result =
+        check_linked_list_with_ordered_items::<_, _, _, Error>(readers, DemoItem::parse_reader);
+    assert!(matches!(result, Err(Error::Discontinuous)));
+}
+
+#[test]
+fn parse_with_custom_field_names() {
+    // Parsed as: (a -> b), (b -> c), (c -> a).
+    let items = build_items(&[(b"b", b"a"), (b"c", b"b"), (b"a", b"c")]);
+    let readers = items.iter().map(|item| item.as_reader());
+    let summary = check_linked_list_with_unordered_items::<_, _, _, Error>(
+        readers,
+        ReversedDemoItem::parse_reader,
+    )
+    .unwrap_or_else(|_| panic!("should be a valid list"));
+    assert!(summary.is_complete());
+}
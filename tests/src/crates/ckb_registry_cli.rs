@@ -0,0 +1,407 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use ckb_mock_tx_types::{MockCellDep, MockTransaction, ReprMockTransaction};
+use ckb_registry_cli::{Deployment, Error, ItemsSnapshot, JsonLiveCell, Snapshot};
+use ckb_registry_client::{Item, LiveCell};
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_jsonrpc_types::JsonBytes,
+    ckb_types::{
+        bytes::Bytes,
+        core::{ScriptHashType, TransactionView},
+        packed::*,
+        prelude::*,
+    },
+    context::Context,
+};
+
+use crate::{prelude::*, utilities, Loader};
+
+const FUNDING_CAPACITY: u64 = 1_000 * 100_000_000;
+
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+struct Env {
+    context: Context,
+    snapshot: Snapshot,
+    dir: PathBuf,
+}
+
+impl Env {
+    fn new(fundings: usize) -> Self {
+        let mut context = Context::default();
+        let registry_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+        let registry_out_point = context.deploy_cell(registry_bin);
+        let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let registry = context
+            .build_script(&registry_out_point, Default::default())
+            .expect("script");
+        let lock = context
+            .build_script(&lock_out_point, Default::default())
+            .expect("lock script");
+        let cell_deps = [registry_out_point, lock_out_point]
+            .into_iter()
+            .map(|out_point| {
+                let (output, data) = context.get_cell(&out_point).expect("cell");
+                let cell_dep = CellDep::new_builder().out_point(out_point).build();
+                MockCellDep {
+                    cell_dep,
+                    output,
+                    data,
+                    header: None,
+                }
+                .into()
+            })
+            .collect();
+        let funding = (0..fundings)
+            .map(|_| {
+                let output = CellOutput::new_builder()
+                    .capacity(FUNDING_CAPACITY.pack())
+                    .lock(lock.clone())
+                    .build();
+                let out_point = context.create_cell(output.clone(), Bytes::new());
+                LiveCell {
+                    out_point,
+                    output,
+                    data: Bytes::new(),
+                }
+                .into()
+            })
+            .collect();
+        let snapshot = Snapshot {
+            deployment: Deployment {
+                code_hash: registry.code_hash().unpack(),
+                hash_type: ScriptHashType::try_from(registry.hash_type())
+                    .expect("hash type")
+                    .into(),
+                cell_deps,
+                lock: lock.into(),
+            },
+            cells: Vec::new(),
+            funding,
+        };
+        let dir = env::temp_dir().join(format!(
+            "ckb-registry-cli-{}-{}",
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(&dir).expect("create dir");
+        Self {
+            context,
+            snapshot,
+            dir,
+        }
+    }
+
+    fn path(&self, name: &str) -> String {
+        self.dir.join(name).to_string_lossy().into_owned()
+    }
+
+    // Runs a command with the current snapshot.
+    fn run(&self, args: &[&str]) -> Result<String, Error> {
+        let snapshot = self.path("snapshot.json");
+        let json = serde_json::to_string(&self.snapshot).expect("json");
+        fs::write(&snapshot, json).expect("write");
+        let mut args = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        args.extend(["--snapshot".to_owned(), snapshot]);
+        ckb_registry_cli::run(&args)
+    }
+
+    // Runs a command which writes a transaction, verifies the transaction, then
+    // applies it to the snapshot.
+    fn run_tx(&mut self, args: &[&str]) -> TransactionView {
+        let output = self.path("tx.json");
+        let mut args = args.to_vec();
+        args.extend(["--output", &output]);
+        self.run(&args).expect("run");
+        let json = fs::read_to_string(&output).expect("read");
+        let mock_tx: ReprMockTransaction = serde_json::from_str(&json).expect("mock tx");
+        let mock_tx: MockTransaction = mock_tx.into();
+        let tx = mock_tx.core_transaction();
+        assert_eq!(mock_tx.mock_info.inputs.len(), tx.inputs().len());
+        for input in &mock_tx.mock_info.inputs {
+            let out_point = input.input.previous_output();
+            let cell = self.context.get_cell(&out_point).expect("live input");
+            assert_eq!(cell, (input.output.clone(), input.data.clone()));
+        }
+        self.context
+            .should_be_passed_without_limit(&tx)
+            .expect("pass verification");
+        self.apply(&tx);
+        tx
+    }
+
+    fn apply(&mut self, tx: &TransactionView) {
+        let consumed = tx.input_pts_iter().collect::<Vec<_>>();
+        let is_live = |cell: &JsonLiveCell| !consumed.contains(&cell.out_point.clone().into());
+        self.snapshot.cells.retain(is_live);
+        self.snapshot.funding.retain(is_live);
+        for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+            let out_point = OutPoint::new(tx.hash(), index as u32);
+            self.context.create_cell_with_out_point(
+                out_point.clone(),
+                output.clone(),
+                data.clone(),
+            );
+            let cell = LiveCell {
+                out_point,
+                output: output.clone(),
+                data,
+            };
+            if output.type_().is_some() {
+                self.snapshot.cells.push(cell.into());
+            } else {
+                self.snapshot.funding.push(cell.into());
+            }
+        }
+    }
+}
+
+impl Drop for Env {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn keys_in(list: &str) -> Vec<&str> {
+    list.lines()
+        .map(|line| line.split(' ').next().expect("key"))
+        .collect()
+}
+
+#[test]
+fn operate_an_instance() {
+    utilities::setup();
+    let mut env = Env::new(3);
+    env.run_tx(&["create", "--items", "b=1,d=0x02"]);
+    let list = env.run(&["list"]).expect("list");
+    assert_eq!(keys_in(&list), vec!["b", "d"]);
+    assert!(list.contains("payload: 0x02"));
+
+    let tx = env.run_tx(&["insert", "--key", "c", "--payload", "3"]);
+    // The predecessor, the new item and the change.
+    assert_eq!(tx.outputs().len(), 3);
+    let list = env.run(&["list"]).expect("list");
+    assert_eq!(keys_in(&list), vec!["b", "c", "d"]);
+
+    let found = env.run(&["lookup", "--key", "c"]).expect("lookup");
+    assert!(found.starts_with("c -> d\tpayload: 3"));
+    let missing = env.run(&["lookup", "--key", "a"]).expect("lookup");
+    assert!(missing.starts_with("not found, covered by:\nd -> b"));
+
+    env.run_tx(&["remove", "--key", "b", "--fee", "2000"]);
+    let list = env.run(&["list"]).expect("list");
+    assert_eq!(keys_in(&list), vec!["c", "d"]);
+    assert!(env
+        .run(&["verify"])
+        .expect("verify")
+        .ends_with("ok, 2 items\n"));
+
+    let tx = env.run_tx(&["destroy"]);
+    assert!(tx
+        .outputs()
+        .into_iter()
+        .all(|output| output.type_().is_none()));
+    assert!(env.snapshot.cells.is_empty());
+}
+
+#[test]
+fn select_instances_by_args() {
+    utilities::setup();
+    let mut env = Env::new(2);
+    env.run_tx(&["create", "--items", "a,b"]);
+    let created = env.run(&["create", "--items", "x,y,z"]).expect("create");
+    assert!(created.starts_with("instance: 0x"));
+    let tx = env.run_tx(&["create", "--items", "x,y,z"]);
+    let args = tx
+        .output(0)
+        .and_then(|output| output.type_().to_opt())
+        .expect("type")
+        .args()
+        .raw_data();
+    let args = format!("0x{}", hex(&args));
+
+    let err = env.run(&["list"]).err();
+    assert!(matches!(err, Some(Error::Usage(msg)) if msg.contains("2 instances")));
+    let list = env.run(&["list", "--args", &args]).expect("list");
+    assert_eq!(keys_in(&list), vec!["x", "y", "z"]);
+    env.run_tx(&["remove", "--args", &args, "--key", "y"]);
+    let verified = env.run(&["verify"]).expect("verify");
+    assert_eq!(verified.lines().count(), 2);
+
+    let err = env.run(&["list", "--args", "0x00"]).err();
+    assert!(matches!(err, Some(Error::InstanceNotFound(_))));
+}
+
+#[test]
+fn report_invalid_rings() {
+    utilities::setup();
+    let mut env = Env::new(1);
+    env.run_tx(&["create", "--items", "a,b,c"]);
+    // Drop an item, so the ring is broken.
+    env.snapshot.cells.pop();
+    let err = env.run(&["verify"]).err();
+    assert!(matches!(err, Some(Error::InvalidInstances(msg)) if msg.contains("not closed")));
+    let err = env.run(&["insert", "--key", "d"]).err();
+    assert!(matches!(
+        err,
+        Some(Error::Client(ckb_registry_client::Error::IncompleteList))
+    ));
+
+    // A duplicated item.
+    let mut duplicated = env.snapshot.cells[0].clone();
+    duplicated.out_point.index = 9.into();
+    env.snapshot.cells.push(duplicated);
+    let err = env.run(&["verify"]).err();
+    assert!(matches!(err, Some(Error::InvalidInstances(_))));
+
+    // An item which points to itself.
+    let mut cell = env.snapshot.cells[0].clone();
+    cell.data = JsonBytes::from_bytes(Item::new(b"a", b"a", b"").to_cell_data());
+    env.snapshot.cells = vec![cell];
+    let err = env.run(&["verify"]).err();
+    assert!(matches!(err, Some(Error::InvalidInstances(msg)) if msg.contains("itself")));
+}
+
+#[test]
+fn report_usage_errors() {
+    utilities::setup();
+    let mut env = Env::new(0);
+    let err = env.run(&["rename"]).err();
+    assert!(matches!(err, Some(Error::Usage(msg)) if msg.contains("unknown command")));
+    let err = env.run(&["list", "--key", "a"]).err();
+    assert!(matches!(err, Some(Error::Usage(msg)) if msg.contains("unknown option --key")));
+    let err = env.run(&["insert"]).err();
+    assert!(matches!(err, Some(Error::Usage(msg)) if msg.contains("--args is required")));
+    let err = ckb_registry_cli::run(&["list".to_owned()]).err();
+    assert!(matches!(err, Some(Error::Usage(msg)) if msg.contains("--snapshot")));
+
+    // No funding cells to pay for the creation.
+    let err = env.run(&["create", "--items", "a,b"]).err();
+    assert!(matches!(
+        err,
+        Some(Error::Client(ckb_registry_client::Error::NoInputs))
+    ));
+    env.snapshot.funding.push(
+        LiveCell {
+            out_point: OutPoint::default(),
+            output: CellOutput::new_builder().capacity(1_000u64.pack()).build(),
+            data: Bytes::new(),
+        }
+        .into(),
+    );
+    let err = env.run(&["create", "--items", "a,b"]).err();
+    assert!(matches!(
+        err,
+        Some(Error::Client(
+            ckb_registry_client::Error::InsufficientCapacity { .. }
+        ))
+    ));
+}
+
+#[test]
+fn export_and_audit_items() {
+    utilities::setup();
+    let mut env = Env::new(2);
+    env.run_tx(&["create", "--items", "b=1,d=0x02,a"]);
+    env.run_tx(&["insert", "--key", "c", "--payload", "3"]);
+    let json_path = env.path("items.json");
+    let csv_path = env.path("items.csv");
+    let exported = env
+        .run(&["export", "--output", &json_path])
+        .expect("export");
+    assert!(exported.starts_with("items: 4\ndigest: 0x"));
+    let digest = exported.lines().nth(1).expect("digest")["digest: ".len()..].to_owned();
+    env.run(&["export", "--format", "csv", "--output", &csv_path])
+        .expect("export");
+
+    // The snapshot doesn't depend on the order of the live cells.
+    env.snapshot.cells.reverse();
+    let json = env.run(&["export"]).expect("export");
+    assert_eq!(json, fs::read_to_string(&json_path).expect("read"));
+    let snapshot: ItemsSnapshot = serde_json::from_str(&json).expect("json");
+    let keys = snapshot
+        .items
+        .iter()
+        .map(|item| item.key.as_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec![&b"a"[..], b"b", b"c", b"d"]);
+    assert_eq!(snapshot.items[2].payload.as_bytes(), b"3");
+    let capacity: u64 = snapshot.items[0].capacity.into();
+    assert!(capacity > 0);
+
+    let csv = fs::read_to_string(&csv_path).expect("read");
+    assert_eq!(csv.lines().count(), 5);
+    assert!(csv
+        .lines()
+        .nth(1)
+        .expect("row")
+        .starts_with("0x61,0x62,0x,0x"));
+
+    // Both formats have the same digest.
+    for path in [&json_path, &csv_path] {
+        let audited = ckb_registry_cli::run(&args(&["audit", "--file", path, "--digest", &digest]))
+            .expect("audit");
+        assert_eq!(
+            audited,
+            format!("ok, 4 items in a complete ring\ndigest: {digest}\n")
+        );
+    }
+}
+
+#[test]
+fn audit_broken_snapshots() {
+    utilities::setup();
+    let mut env = Env::new(1);
+    env.run_tx(&["create", "--items", "a,b,c"]);
+    let csv = env.run(&["export", "--format", "csv"]).expect("export");
+    let json = env.run(&["export"]).expect("export");
+    let audit = |name: &str, content: &str| {
+        let path = env.path(name);
+        fs::write(&path, content).expect("write");
+        ckb_registry_cli::run(&args(&["audit", "--file", &path])).err()
+    };
+    assert!(audit("ok.csv", &csv).is_none());
+
+    // A missing item.
+    let rows = csv.lines().collect::<Vec<_>>();
+    let missing = [rows[0], rows[1], rows[3]].join("\n");
+    assert!(matches!(
+        audit("missing.csv", &missing),
+        Some(Error::InvalidList(_))
+    ));
+    // Two rings.
+    let mut two_rings = csv.clone();
+    two_rings.push_str(&rows[1].replace("0x61,0x62", "0x78,0x79"));
+    two_rings.push('\n');
+    two_rings.push_str(&rows[1].replace("0x61,0x62", "0x79,0x78"));
+    assert!(matches!(
+        audit("two-rings.csv", &two_rings),
+        Some(Error::InvalidList(_))
+    ));
+    // A tampered payload.
+    let tampered = json.replacen("\"payload\": \"0x\"", "\"payload\": \"0x01\"", 1);
+    assert!(matches!(
+        audit("tampered.json", &tampered),
+        Some(Error::DigestMismatch { .. })
+    ));
+    assert!(matches!(
+        audit("invalid.csv", "key,next\n"),
+        Some(Error::InvalidSnapshot(_))
+    ));
+    let path = env.path("ok.csv");
+    let err = ckb_registry_cli::run(&args(&["audit", "--file", &path, "--digest", "0x00"])).err();
+    assert!(matches!(err, Some(Error::Usage(_))));
+}
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
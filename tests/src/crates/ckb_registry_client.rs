@@ -0,0 +1,761 @@
+use std::{collections::HashSet, mem};
+
+use ckb_registry_client::{
+    create, ConfigRequest, Conflict, ContractRef, CreateRequest, Error, Funding, Item, LiveCell,
+    MempoolOverlay, PendingInsert, Registry, Schedule, UnsignedTx,
+};
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::{constants::FLAG_EVENTS, types};
+
+use crate::{prelude::*, utilities, Loader};
+
+const FUNDING_CAPACITY: u64 = 100_000 * 100_000_000;
+const FEE: u64 = 1_000;
+
+struct Env {
+    context: Context,
+    registry: ContractRef,
+    config: ContractRef,
+    lock: Script,
+}
+
+impl Env {
+    fn new() -> Self {
+        let mut context = Context::default();
+        let registry_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+        let registry_out_point = context.deploy_cell(registry_bin);
+        let config_bin: Bytes = Loader::default().load_binary("demo-registry-config-type");
+        let config_out_point = context.deploy_cell(config_bin);
+        let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let lock = context
+            .build_script(&lock_out_point, Default::default())
+            .expect("lock script");
+        let lock_dep = CellDep::new_builder().out_point(lock_out_point).build();
+        let registry = contract_ref(&mut context, registry_out_point, lock_dep.clone());
+        let config = contract_ref(&mut context, config_out_point, lock_dep);
+        Self {
+            context,
+            registry,
+            config,
+            lock,
+        }
+    }
+
+    fn funding(&mut self, capacity: u64) -> Funding {
+        let output = CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(self.lock.clone())
+            .build();
+        let out_point = self.context.create_cell(output.clone(), Bytes::new());
+        Funding {
+            cells: vec![LiveCell {
+                out_point,
+                output,
+                data: Bytes::new(),
+            }],
+            change_lock: self.lock.clone(),
+            fee: FEE,
+        }
+    }
+
+    // Verify the transaction, then make its outputs live.
+    fn commit(&mut self, tx: &UnsignedTx) {
+        self.context
+            .should_be_passed_without_limit(&tx.tx)
+            .expect("pass verification");
+        for cell in tx.live_cells() {
+            self.context
+                .create_cell_with_out_point(cell.out_point, cell.output, cell.data);
+        }
+    }
+
+    fn create_request(&self, keys: &[&[u8]]) -> CreateRequest {
+        CreateRequest {
+            contract: self.registry.clone(),
+            instance_args: types::InstanceArgs::new_with_flags(FLAG_EVENTS),
+            items: keys
+                .iter()
+                .map(|key| (Bytes::copy_from_slice(key), Bytes::from_static(b"payload")))
+                .collect(),
+            lock: self.lock.clone(),
+            config: None,
+        }
+    }
+
+    fn create_registry(&mut self, keys: &[&[u8]]) -> Registry {
+        let funding = self.funding(FUNDING_CAPACITY);
+        let tx = create(self.create_request(keys), &funding).expect("create");
+        self.commit(&tx);
+        let type_script = tx
+            .tx
+            .output(0)
+            .expect("output")
+            .type_()
+            .to_opt()
+            .expect("type");
+        Registry::new(
+            type_script,
+            self.registry.cell_deps.clone(),
+            tx.live_cells(),
+        )
+        .expect("registry")
+    }
+}
+
+// An in-memory stand-in of the tx-pool. A transaction is accepted if its
+// inputs are committed or created by the transactions in the pool, and not
+// spent by others in the pool, unless it pays a higher fee to replace them.
+#[derive(Default)]
+struct TxPool {
+    // The transactions with their fees, a transaction is after its parents.
+    txs: Vec<(UnsignedTx, u64)>,
+    // The committed cells which are spent.
+    dead: HashSet<OutPoint>,
+}
+
+impl TxPool {
+    // Returns the hashes of the replaced transactions and their descendants.
+    fn submit(&mut self, env: &Env, tx: &UnsignedTx, fee: u64) -> Result<Vec<Byte32>, String> {
+        let mut replaced = HashSet::new();
+        for out_point in tx.tx.input_pts_iter() {
+            let is_pending = self
+                .txs
+                .iter()
+                .any(|(pending, _)| pending.tx.hash() == out_point.tx_hash());
+            let is_committed =
+                env.context.get_cell(&out_point).is_some() && !self.dead.contains(&out_point);
+            if !is_pending && !is_committed {
+                return Err(format!("Resolve failed Dead({out_point:?})"));
+            }
+            for (pending, pending_fee) in &self.txs {
+                if pending.tx.input_pts_iter().any(|input| input == out_point) {
+                    if fee <= *pending_fee {
+                        return Err("RBFRejected: the fee is not higher".to_owned());
+                    }
+                    replaced.insert(pending.tx.hash());
+                }
+            }
+        }
+        let evicted = self.evict(&replaced);
+        self.txs.push((tx.clone(), fee));
+        Ok(evicted)
+    }
+
+    fn reject(&mut self, tx_hash: &Byte32) -> Vec<Byte32> {
+        self.evict(&HashSet::from([tx_hash.clone()]))
+    }
+
+    // Commits a transaction sent by others, the conflicting ones are evicted.
+    fn commit_external(&mut self, env: &mut Env, tx: &UnsignedTx) -> Vec<Byte32> {
+        env.commit(tx);
+        let spent = tx.tx.input_pts_iter().collect::<HashSet<_>>();
+        self.dead.extend(spent.iter().cloned());
+        let conflicts = self
+            .txs
+            .iter()
+            .filter(|(pending, _)| pending.tx.input_pts_iter().any(|op| spent.contains(&op)))
+            .map(|(pending, _)| pending.tx.hash())
+            .collect();
+        self.evict(&conflicts)
+    }
+
+    // Commits all transactions in a block.
+    fn commit_block(&mut self, env: &mut Env) -> Vec<UnsignedTx> {
+        let txs = mem::take(&mut self.txs);
+        for (tx, _) in &txs {
+            env.commit(tx);
+            self.dead.extend(tx.tx.input_pts_iter());
+        }
+        txs.into_iter().map(|(tx, _)| tx).collect()
+    }
+
+    // Removes the transactions with their descendants.
+    fn evict(&mut self, tx_hashes: &HashSet<Byte32>) -> Vec<Byte32> {
+        let mut evicted = Vec::new();
+        self.txs.retain(|(tx, _)| {
+            let tx_hash = tx.tx.hash();
+            let is_evicted = tx_hashes.contains(&tx_hash)
+                || tx
+                    .tx
+                    .input_pts_iter()
+                    .any(|out_point| evicted.contains(&out_point.tx_hash()));
+            if is_evicted {
+                evicted.push(tx_hash);
+            }
+            !is_evicted
+        });
+        evicted
+    }
+}
+
+fn contract_ref(context: &mut Context, out_point: OutPoint, lock_dep: CellDep) -> ContractRef {
+    let script = context
+        .build_script(&out_point, Default::default())
+        .expect("script");
+    let cell_dep = CellDep::new_builder().out_point(out_point).build();
+    ContractRef {
+        code_hash: script.code_hash(),
+        hash_type: script.hash_type(),
+        cell_deps: vec![cell_dep, lock_dep],
+    }
+}
+
+fn keys_of(registry: &Registry) -> Vec<Bytes> {
+    registry
+        .items()
+        .iter()
+        .map(|cell| cell.item.key.clone())
+        .collect()
+}
+
+fn capacity_of(tx: &UnsignedTx, index: usize) -> u64 {
+    tx.tx.output(index).expect("output").capacity().unpack()
+}
+
+#[test]
+fn create_update_and_destroy() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut registry = env.create_registry(&[b"b", b"d"]);
+
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry
+        .insert(b"c", b"new", env.lock.clone(), &funding)
+        .expect("insert");
+    env.commit(&tx);
+    registry.apply(&tx).expect("apply");
+    assert_eq!(keys_of(&registry), vec![&b"b"[..], b"c", b"d"]);
+
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry
+        .modify(b"c", b"modified payload", &funding)
+        .expect("modify");
+    env.commit(&tx);
+    registry.apply(&tx).expect("apply");
+    let item = &registry.get(b"c").expect("item").item;
+    assert_eq!(&item.payload[..], b"modified payload");
+
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry.remove(b"d", &funding).expect("remove");
+    env.commit(&tx);
+    registry.apply(&tx).expect("apply");
+    assert_eq!(keys_of(&registry), vec![&b"b"[..], b"c"]);
+
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry.destroy(&funding).expect("destroy");
+    env.commit(&tx);
+}
+
+#[test]
+fn insert_before_the_first_key() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut registry = env.create_registry(&[b"b", b"d"]);
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry
+        .insert(b"a", b"first", env.lock.clone(), &funding)
+        .expect("insert");
+    // The last item is the predecessor of the first key.
+    let input = tx.tx.inputs().get(0).expect("input");
+    assert_eq!(
+        input.previous_output(),
+        registry.get(b"d").expect("item").cell.out_point
+    );
+    env.commit(&tx);
+    registry.apply(&tx).expect("apply");
+    assert_eq!(keys_of(&registry), vec![&b"a"[..], b"b", b"d"]);
+}
+
+#[test]
+fn insert_after_the_last_key() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut registry = env.create_registry(&[b"b", b"d"]);
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry
+        .insert(b"e", b"last", env.lock.clone(), &funding)
+        .expect("insert");
+    env.commit(&tx);
+    registry.apply(&tx).expect("apply");
+    assert_eq!(keys_of(&registry), vec![&b"b"[..], b"d", b"e"]);
+}
+
+#[test]
+fn find_predecessors() {
+    utilities::setup();
+    let mut env = Env::new();
+    let registry = env.create_registry(&[b"b", b"d"]);
+    let predecessor_of = |key: &[u8]| registry.predecessor(key).item.key.clone();
+    assert_eq!(predecessor_of(b"a"), &b"d"[..]);
+    assert_eq!(predecessor_of(b"b"), &b"d"[..]);
+    assert_eq!(predecessor_of(b"c"), &b"b"[..]);
+    assert_eq!(predecessor_of(b"e"), &b"d"[..]);
+}
+
+#[test]
+fn create_with_config() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut request = env.create_request(&[b"d", b"b", b"c"]);
+    request.config = Some(ConfigRequest {
+        contract: env.config.clone(),
+        lock: env.lock.clone(),
+        config: types::RegistryConfig::new_with_fields(0, b"demo", [0u8; 32]),
+    });
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = create(request, &funding).expect("create");
+    // 3 items, the config cell and the change cell.
+    assert_eq!(tx.tx.outputs().len(), 5);
+    env.commit(&tx);
+}
+
+#[test]
+fn return_the_change() {
+    utilities::setup();
+    let mut env = Env::new();
+    let registry = env.create_registry(&[b"b", b"d"]);
+    let funding = env.funding(FUNDING_CAPACITY);
+    let err = registry.remove(b"b", &funding).err();
+    assert_eq!(err, Some(Error::TooFewItems));
+
+    let tx = registry
+        .insert(b"c", b"new", env.lock.clone(), &funding)
+        .expect("insert");
+    let prev_capacity = registry.get(b"b").expect("item").cell.capacity();
+    let outputs_capacity = capacity_of(&tx, 0) + capacity_of(&tx, 1);
+    assert_eq!(tx.tx.outputs().len(), 3);
+    assert_eq!(capacity_of(&tx, 2), tx.change);
+    assert_eq!(
+        tx.change,
+        prev_capacity + FUNDING_CAPACITY - outputs_capacity - FEE
+    );
+    env.commit(&tx);
+}
+
+#[test]
+fn reject_invalid_requests() {
+    utilities::setup();
+    let mut env = Env::new();
+    let registry = env.create_registry(&[b"b", b"d"]);
+    let funding = env.funding(FUNDING_CAPACITY);
+    let err = registry
+        .insert(b"b", b"duplicated", env.lock.clone(), &funding)
+        .err();
+    assert_eq!(err, Some(Error::KeyExists));
+    let err = registry.modify(b"c", b"absent", &funding).err();
+    assert_eq!(err, Some(Error::KeyNotFound));
+    let err = create(env.create_request(&[b"b"]), &funding).err();
+    assert_eq!(err, Some(Error::TooFewItems));
+
+    let no_funding = Funding {
+        cells: Vec::new(),
+        ..funding.clone()
+    };
+    let err = create(env.create_request(&[b"b", b"d"]), &no_funding).err();
+    assert_eq!(err, Some(Error::NoInputs));
+
+    let poor_funding = env.funding(1_000);
+    let err = registry
+        .insert(b"c", b"new", env.lock.clone(), &poor_funding)
+        .err();
+    assert!(matches!(err, Some(Error::InsufficientCapacity { .. })));
+}
+
+#[test]
+fn rebase_conflicting_insert() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut registry = env.create_registry(&[b"b", b"d"]);
+    // Both users insert after `b`, from the same view of the items.
+    let alice = registry
+        .insert(
+            b"c",
+            b"alice",
+            env.lock.clone(),
+            &env.funding(FUNDING_CAPACITY),
+        )
+        .expect("insert");
+    let bob_funding = env.funding(FUNDING_CAPACITY);
+    let bob_lock = Script::new_builder()
+        .args(Bytes::from_static(b"bob").pack())
+        .build();
+    let bob = registry
+        .insert(b"cc", b"bob", bob_lock.clone(), &bob_funding)
+        .expect("insert");
+    let stale_predecessor = registry.get(b"b").expect("item").cell.out_point.clone();
+
+    env.commit(&alice);
+    registry.apply(&alice).expect("apply");
+
+    let pending = PendingInsert::from_tx(bob.clone()).expect("pending");
+    assert_eq!(
+        pending.items,
+        vec![(Bytes::from_static(b"cc"), Bytes::from_static(b"bob"))]
+    );
+    assert_eq!(pending.lock, bob_lock);
+    assert_eq!(
+        pending.conflict(&registry, &bob_funding),
+        Some(Conflict::DeadInput(stale_predecessor.clone()))
+    );
+    // The same conflict is reported by the tx-pool.
+    let rejection = format!(
+        "TransactionFailedToResolve: Resolve failed Dead({:?})",
+        stale_predecessor
+    );
+    assert_eq!(
+        Conflict::from_rejection(&rejection),
+        Some(Conflict::DeadInput(stale_predecessor))
+    );
+
+    let rebased = pending.rebase(&registry, &bob_funding).expect("rebase");
+    // Only the predecessor is changed, from `b` to `c`.
+    assert_eq!(rebased.changed_inputs, vec![0]);
+    assert_eq!(
+        rebased
+            .tx
+            .tx
+            .inputs()
+            .get(0)
+            .expect("input")
+            .previous_output(),
+        registry.get(b"c").expect("item").cell.out_point
+    );
+    assert_eq!(rebased.tx.tx.inputs().get(1), bob.tx.inputs().get(1));
+    let item = Item::from_cell_data(
+        &rebased
+            .tx
+            .tx
+            .outputs_data()
+            .get(1)
+            .expect("data")
+            .raw_data(),
+    )
+    .expect("item");
+    assert_eq!(&item.payload[..], b"bob");
+    assert_eq!(rebased.tx.tx.output(1).expect("output").lock(), bob_lock);
+
+    let pending = PendingInsert::from_tx(rebased.tx.clone()).expect("pending");
+    assert_eq!(pending.conflict(&registry, &bob_funding), None);
+    env.commit(&rebased.tx);
+    registry.apply(&rebased.tx).expect("apply");
+    assert_eq!(keys_of(&registry), vec![&b"b"[..], b"c", b"cc", b"d"]);
+}
+
+#[test]
+fn rebase_conflicting_batch() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut registry = env.create_registry(&[b"b", b"d"]);
+    let alice = registry
+        .insert(
+            b"c",
+            b"alice",
+            env.lock.clone(),
+            &env.funding(FUNDING_CAPACITY),
+        )
+        .expect("insert");
+    let bob_funding = env.funding(FUNDING_CAPACITY);
+    let bob = registry
+        .insert_batch(&items_of(&[b"cc", b"ca"]), env.lock.clone(), &bob_funding)
+        .expect("insert");
+    let pending = PendingInsert::from_tx(bob).expect("pending");
+    assert_eq!(pending.items, items_of(&[b"ca", b"cc"]));
+    // The predecessor is live, but the funding cells are not the same.
+    let other_funding = env.funding(FUNDING_CAPACITY);
+    let funding_input = pending.tx.tx.inputs().get(1).expect("input");
+    assert_eq!(
+        pending.conflict(&registry, &other_funding),
+        Some(Conflict::DeadInput(funding_input.previous_output()))
+    );
+    assert_eq!(pending.conflict(&registry, &bob_funding), None);
+
+    env.commit(&alice);
+    registry.apply(&alice).expect("apply");
+    assert!(matches!(
+        pending.conflict(&registry, &bob_funding),
+        Some(Conflict::DeadInput(_))
+    ));
+    let rebased = pending.rebase(&registry, &bob_funding).expect("rebase");
+    assert_eq!(rebased.changed_inputs, vec![0]);
+    env.commit(&rebased.tx);
+    registry.apply(&rebased.tx).expect("apply");
+    assert_eq!(
+        keys_of(&registry),
+        vec![&b"b"[..], b"c", b"ca", b"cc", b"d"]
+    );
+}
+
+#[test]
+fn rebase_only_inserts() {
+    utilities::setup();
+    let mut env = Env::new();
+    let registry = env.create_registry(&[b"b", b"c", b"d"]);
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry.remove(b"c", &funding).expect("remove");
+    assert_eq!(PendingInsert::from_tx(tx).err(), Some(Error::NotInsert));
+    let tx = registry.modify(b"c", b"new", &funding).expect("modify");
+    assert_eq!(PendingInsert::from_tx(tx).err(), Some(Error::NotInsert));
+
+    assert_eq!(Conflict::from_rejection("PoolIsFull"), None);
+    let out_point = OutPoint::new(Byte32::new([1u8; 32]), 2);
+    let rejection = format!("Resolve failed Unknown({:?})", out_point);
+    assert_eq!(
+        Conflict::from_rejection(&rejection),
+        Some(Conflict::UnknownInput(out_point))
+    );
+
+    // Neither multi-byte chars nor signs are hex digits.
+    let rejection = format!("Dead(OutPoint(0xa{}a))", "\u{e9}".repeat(35));
+    assert_eq!(Conflict::from_rejection(&rejection), None);
+    let rejection = format!("Dead(OutPoint(0x{}))", "+f".repeat(36));
+    assert_eq!(Conflict::from_rejection(&rejection), None);
+}
+
+fn items_of(keys: &[&[u8]]) -> Vec<(Bytes, Bytes)> {
+    keys.iter()
+        .map(|key| (Bytes::copy_from_slice(key), Bytes::from_static(b"batch")))
+        .collect()
+}
+
+#[test]
+fn insert_batch_after_one_predecessor() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut registry = env.create_registry(&[b"b", b"f"]);
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry
+        .insert_batch(&items_of(&[b"e", b"c", b"d"]), env.lock.clone(), &funding)
+        .expect("insert");
+    // The predecessor, 3 items and the change.
+    assert_eq!(tx.tx.outputs().len(), 5);
+    env.commit(&tx);
+    registry.apply(&tx).expect("apply");
+    assert_eq!(keys_of(&registry), vec![&b"b"[..], b"c", b"d", b"e", b"f"]);
+
+    // The last item is the predecessor of the keys before the first key and
+    // after the last key.
+    let funding = env.funding(FUNDING_CAPACITY);
+    let tx = registry
+        .insert_batch(&items_of(&[b"a", b"g"]), env.lock.clone(), &funding)
+        .expect("insert");
+    env.commit(&tx);
+    registry.apply(&tx).expect("apply");
+    assert_eq!(registry.get(b"f").expect("item").item.next, &b"g"[..]);
+    assert_eq!(registry.get(b"g").expect("item").item.next, &b"a"[..]);
+
+    let err = registry
+        .insert_batch(&items_of(&[b"bb", b"ee"]), env.lock.clone(), &funding)
+        .err();
+    assert_eq!(err, Some(Error::MixedPredecessors));
+    let err = registry
+        .insert_batch(&items_of(&[b"bb", b"bb"]), env.lock.clone(), &funding)
+        .err();
+    assert_eq!(err, Some(Error::KeyExists));
+    let err = registry.insert_batch(&[], env.lock.clone(), &funding).err();
+    assert_eq!(err, Some(Error::EmptyBatch));
+}
+
+#[test]
+fn schedule_disjoint_transactions() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut registry = env.create_registry(&[b"b", b"f", b"m"]);
+    let requests = items_of(&[b"d", b"z", b"c", b"g", b"b", b"e", b"a", b"c"]);
+    let schedule = Schedule::new(&registry, requests, 2);
+    assert_eq!(schedule.skipped, vec![&b"b"[..], b"c"]);
+    assert_eq!(schedule.keys_count(), 6);
+    let plan = schedule
+        .rounds
+        .iter()
+        .map(|round| {
+            round
+                .iter()
+                .map(|batch| {
+                    let keys = batch.items.iter().map(|(key, _)| key.clone());
+                    (batch.predecessor.clone(), keys.collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let key = |key: &'static [u8]| Bytes::from_static(key);
+    assert_eq!(
+        plan,
+        vec![
+            vec![
+                (key(b"b"), vec![key(b"c"), key(b"d")]),
+                (key(b"f"), vec![key(b"g")]),
+                (key(b"m"), vec![key(b"z"), key(b"a")]),
+            ],
+            vec![(key(b"d"), vec![key(b"e")])],
+        ]
+    );
+
+    for round in &schedule.rounds {
+        let txs = round
+            .iter()
+            .map(|batch| {
+                let funding = env.funding(FUNDING_CAPACITY);
+                registry
+                    .insert_batch(&batch.items, env.lock.clone(), &funding)
+                    .expect("insert")
+            })
+            .collect::<Vec<_>>();
+        // The transactions in a round don't share any inputs.
+        let mut inputs = txs
+            .iter()
+            .flat_map(|tx| tx.tx.input_pts_iter())
+            .collect::<Vec<_>>();
+        let count = inputs.len();
+        inputs.sort_by_key(|out_point| out_point.as_bytes());
+        inputs.dedup();
+        assert_eq!(inputs.len(), count);
+        for tx in &txs {
+            env.commit(tx);
+        }
+        for tx in &txs {
+            registry.apply(tx).expect("apply");
+        }
+    }
+    assert_eq!(
+        keys_of(&registry),
+        vec![&b"a"[..], b"b", b"c", b"d", b"e", b"f", b"g", b"m", b"z"]
+    );
+}
+
+fn hashes_of(txs: &[UnsignedTx]) -> Vec<Byte32> {
+    txs.iter().map(|tx| tx.tx.hash()).collect()
+}
+
+#[test]
+fn chain_inserts_on_pending_outputs() {
+    utilities::setup();
+    let mut env = Env::new();
+    let mut overlay = MempoolOverlay::new(env.create_registry(&[b"b", b"f"]));
+    let mut pool = TxPool::default();
+    let lock = env.lock.clone();
+    let chained = |tx: &UnsignedTx, fee: u64| Funding {
+        cells: vec![tx.change_cell().expect("change")],
+        change_lock: lock.clone(),
+        fee,
+    };
+
+    // A inserts `c`, then B inserts `d` after `c`, paid by the change of A,
+    // then C inserts `e` after `d`.
+    let funding = env.funding(FUNDING_CAPACITY);
+    let a = overlay
+        .view()
+        .insert(b"c", b"a", lock.clone(), &funding)
+        .expect("insert");
+    pool.submit(&env, &a, FEE).expect("accept");
+    overlay.add(a.clone()).expect("add");
+    let after_a = overlay.view().clone();
+    let b = after_a
+        .insert(b"d", b"b", lock.clone(), &chained(&a, FEE))
+        .expect("insert");
+    assert!(b
+        .tx
+        .input_pts_iter()
+        .all(|out_point| out_point.tx_hash() == a.tx.hash()));
+    pool.submit(&env, &b, FEE).expect("accept");
+    overlay.add(b.clone()).expect("add");
+    let c = overlay
+        .view()
+        .insert(b"e", b"c", lock.clone(), &chained(&b, FEE))
+        .expect("insert");
+    pool.submit(&env, &c, FEE).expect("accept");
+    overlay.add(c.clone()).expect("add");
+    // An independent insert, which wraps around.
+    let d = overlay
+        .view()
+        .insert(b"a", b"d", lock.clone(), &env.funding(FUNDING_CAPACITY))
+        .expect("insert");
+    pool.submit(&env, &d, FEE).expect("accept");
+    overlay.add(d.clone()).expect("add");
+    assert_eq!(
+        keys_of(overlay.view()),
+        vec![&b"a"[..], b"b", b"c", b"d", b"e", b"f"]
+    );
+    assert_eq!(keys_of(overlay.committed()), vec![&b"b"[..], b"f"]);
+
+    // The committed predecessor of `c` is spent by A already.
+    let stale = overlay
+        .committed()
+        .insert(b"cc", b"", lock.clone(), &env.funding(FUNDING_CAPACITY))
+        .expect("insert");
+    assert!(pool.submit(&env, &stale, FEE).is_err());
+    let predecessor = overlay
+        .committed()
+        .get(b"b")
+        .expect("item")
+        .cell
+        .out_point
+        .clone();
+    assert_eq!(
+        overlay.add(stale).err(),
+        Some(Error::SpentByPending(predecessor))
+    );
+
+    // B is replaced with a higher fee, so C is evicted.
+    let b2 = after_a
+        .insert(b"d", b"b", lock.clone(), &chained(&a, FEE * 2))
+        .expect("insert");
+    let replaced = pool.submit(&env, &b2, FEE * 2).expect("replace");
+    assert_eq!(replaced, hashes_of(&[b.clone(), c.clone()]));
+    let evicted = overlay.replace(&b.tx.hash(), b2.clone()).expect("replace");
+    assert_eq!(hashes_of(&evicted), hashes_of(&[c]));
+    assert_eq!(
+        keys_of(overlay.view()),
+        vec![&b"a"[..], b"b", b"c", b"d", b"f"]
+    );
+
+    // A is rejected, so B is evicted too.
+    assert_eq!(
+        pool.reject(&a.tx.hash()),
+        hashes_of(&[a.clone(), b2.clone()])
+    );
+    let evicted = overlay.reject(&a.tx.hash()).expect("reject");
+    assert_eq!(hashes_of(&evicted), hashes_of(&[a.clone(), b2]));
+    assert_eq!(hashes_of(overlay.pending()), vec![d.tx.hash()]);
+    assert_eq!(keys_of(overlay.view()), vec![&b"a"[..], b"b", b"f"]);
+    assert_eq!(
+        overlay.reject(&a.tx.hash()).err(),
+        Some(Error::PendingTxNotFound)
+    );
+
+    // A is sent again with a higher fee.
+    let funding = Funding {
+        fee: FEE * 2,
+        ..funding
+    };
+    let a2 = overlay
+        .view()
+        .insert(b"c", b"a", lock.clone(), &funding)
+        .expect("insert");
+    pool.submit(&env, &a2, FEE * 2).expect("accept");
+    overlay.add(a2.clone()).expect("add");
+
+    // Another insert after `f` is committed first, so D conflicts with it.
+    let external = overlay
+        .committed()
+        .insert(b"g", b"", lock.clone(), &env.funding(FUNDING_CAPACITY))
+        .expect("insert");
+    assert_eq!(pool.commit_external(&mut env, &external), vec![d.tx.hash()]);
+    let evicted = overlay.commit(&external).expect("commit");
+    assert_eq!(hashes_of(&evicted), hashes_of(&[d]));
+
+    let committed = pool.commit_block(&mut env);
+    assert_eq!(hashes_of(&committed), hashes_of(&[a2]));
+    for tx in &committed {
+        assert!(overlay.commit(tx).expect("commit").is_empty());
+    }
+    assert!(overlay.pending().is_empty());
+    assert_eq!(
+        keys_of(overlay.committed()),
+        vec![&b"b"[..], b"c", b"f", b"g"]
+    );
+    assert_eq!(keys_of(overlay.view()), keys_of(overlay.committed()));
+}
@@ -0,0 +1,295 @@
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use ckb_registry_client::{rpc::IndexerRpcClient, Error};
+use ckb_testtool::{
+    ckb_jsonrpc_types as rpc,
+    ckb_types::{packed, packed::Script},
+};
+use serde_json::{json, Value};
+
+const GET_CELLS_FIXTURES: &str = include_str!("../../fixtures/indexer_get_cells.json");
+
+#[derive(Clone, Copy)]
+enum ResponseMode {
+    Sized,
+    Chunked,
+    Failed,
+    // Read the request, but never respond.
+    Stalled,
+    // Respond a byte at a time, slowly.
+    Trickling,
+    // Respond a chunk whose size overflows.
+    Overflowed,
+}
+
+// An in-process JSON-RPC server, which serves the recorded responses of the
+// requests with the same method and params.
+struct MockServer {
+    url: String,
+    requests: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    fn start(fixtures: &str, mode: ResponseMode) -> Self {
+        let fixtures: Vec<Value> = serde_json::from_str(fixtures).expect("fixtures");
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}", listener.local_addr().expect("addr"));
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = stream.expect("stream");
+                counter.fetch_add(1, Ordering::SeqCst);
+                serve(stream, &fixtures, mode);
+            }
+        });
+        Self { url, requests }
+    }
+
+    fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+fn serve(mut stream: TcpStream, fixtures: &[Value], mode: ResponseMode) {
+    let request = read_request(&mut stream);
+    if let ResponseMode::Stalled = mode {
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(60));
+            drop(stream);
+        });
+        return;
+    }
+    let fixture = fixtures.iter().find(|fixture| {
+        fixture["method"] == request["method"] && fixture["params"] == request["params"]
+    });
+    let response = match fixture {
+        Some(fixture) => {
+            json!({"id": request["id"], "jsonrpc": "2.0", "result": fixture["result"]})
+        }
+        None => json!({
+            "id": request["id"],
+            "jsonrpc": "2.0",
+            "error": {"code": -32000, "message": "fixture not found"},
+        }),
+    };
+    let body = response.to_string();
+    let http = match mode {
+        ResponseMode::Sized => format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        ),
+        ResponseMode::Chunked => {
+            let (first, second) = body.split_at(body.len() / 2);
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n\
+                 {:x}\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+                first.len(),
+                first,
+                second.len(),
+                second
+            )
+        }
+        ResponseMode::Failed => {
+            "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\n\r\n".to_owned()
+        }
+        ResponseMode::Trickling => {
+            let http = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            thread::spawn(move || {
+                for byte in http.as_bytes() {
+                    thread::sleep(Duration::from_millis(50));
+                    if stream.write_all(&[*byte]).is_err() {
+                        break;
+                    }
+                }
+            });
+            return;
+        }
+        ResponseMode::Overflowed => "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+                                     ffffffffffffffff\r\nx\r\n0\r\n\r\n"
+            .to_owned(),
+        ResponseMode::Stalled => unreachable!(),
+    };
+    stream.write_all(http.as_bytes()).expect("write");
+}
+
+fn read_request(stream: &mut TcpStream) -> Value {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+    let (head_len, content_length) = loop {
+        let n = stream.read(&mut chunk).expect("read");
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|window| window == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&buf[..pos]).to_lowercase();
+            let content_length = head
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|len| len.trim().parse::<usize>().expect("length"))
+                .unwrap_or_default();
+            break (pos + 4, content_length);
+        }
+    };
+    while buf.len() < head_len + content_length {
+        let n = stream.read(&mut chunk).expect("read");
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    serde_json::from_slice(&buf[head_len..head_len + content_length]).expect("json")
+}
+
+fn registry_script() -> packed::Script {
+    let fixtures: Value = serde_json::from_str(GET_CELLS_FIXTURES).expect("fixtures");
+    let script: rpc::Script =
+        serde_json::from_value(fixtures[0]["params"][0]["script"].clone()).expect("script");
+    script.into()
+}
+
+#[test]
+fn fetch_cells_by_pages() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Sized);
+    let client = IndexerRpcClient::new(&server.url)
+        .expect("client")
+        .with_page_size(2)
+        .expect("page size");
+    let script = registry_script();
+    let cells = client
+        .get_registry_cells(
+            &script.code_hash(),
+            script.hash_type(),
+            &script.args().raw_data(),
+        )
+        .expect("cells");
+    assert_eq!(server.requests(), 2);
+    assert_eq!(cells.len(), 3);
+    assert!(cells.iter().all(|cell| cell.block_number == 1_000));
+    let tx_indexes = cells.iter().map(|cell| cell.tx_index).collect::<Vec<_>>();
+    assert_eq!(tx_indexes, vec![1, 2, 3]);
+}
+
+#[test]
+fn fetch_list_items() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Sized);
+    let client = IndexerRpcClient::new(&server.url)
+        .expect("client")
+        .with_page_size(2)
+        .expect("page size");
+    let items = client
+        .get_list_items(&registry_script())
+        .expect("list items");
+    let keys = items
+        .iter()
+        .map(|(item, _)| (item.curr.clone(), item.next.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        vec![
+            (b"alice".to_vec().into(), b"bob".to_vec().into()),
+            (b"bob".to_vec().into(), b"carol".to_vec().into()),
+            (b"carol".to_vec().into(), b"alice".to_vec().into()),
+        ]
+    );
+}
+
+#[test]
+fn fetch_free_cells() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Sized);
+    let client = IndexerRpcClient::new(&server.url)
+        .expect("client")
+        .with_page_size(2)
+        .expect("page size");
+    let cells = client.get_free_cells(&Script::default()).expect("cells");
+    assert_eq!(server.requests(), 1);
+    assert_eq!(cells.len(), 1);
+    assert_eq!(cells[0].cell.capacity(), 100_000_000_000);
+    assert!(cells[0].cell.output.type_().is_none());
+}
+
+#[test]
+fn fetch_with_chunked_responses() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Chunked);
+    let client = IndexerRpcClient::new(&format!("{}/", server.url))
+        .expect("client")
+        .with_page_size(2)
+        .expect("page size");
+    let items = client
+        .get_list_items(&registry_script())
+        .expect("list items");
+    assert_eq!(items.len(), 3);
+}
+
+#[test]
+fn report_rpc_errors() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Sized);
+    // The page size is a part of the params, so there is no fixture for it.
+    let client = IndexerRpcClient::new(&server.url)
+        .expect("client")
+        .with_page_size(3)
+        .expect("page size");
+    let err = client.get_list_items(&registry_script()).err();
+    assert_eq!(
+        err,
+        Some(Error::Rpc("fixture not found (-32000)".to_owned()))
+    );
+}
+
+#[test]
+fn report_http_errors() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Failed);
+    let client = IndexerRpcClient::new(&server.url).expect("client");
+    let err = client.get_list_items(&registry_script()).err();
+    assert!(matches!(err, Some(Error::Rpc(msg)) if msg.contains("500")));
+
+    let err = IndexerRpcClient::new("https://127.0.0.1:8114").err();
+    assert!(matches!(err, Some(Error::Rpc(_))));
+}
+
+#[test]
+fn report_timeouts() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Stalled);
+    let client = IndexerRpcClient::new(&server.url)
+        .expect("client")
+        .with_timeout(Duration::from_millis(200));
+    let err = client.get_list_items(&registry_script()).err();
+    assert!(matches!(err, Some(Error::Rpc(_))));
+    assert_eq!(server.requests(), 1);
+}
+
+#[test]
+fn report_timeouts_of_trickling_responses() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Trickling);
+    let client = IndexerRpcClient::new(&server.url)
+        .expect("client")
+        .with_timeout(Duration::from_millis(300));
+    let start = Instant::now();
+    let err = client.get_list_items(&registry_script()).err();
+    assert_eq!(err, Some(Error::Rpc("request timed out".to_owned())));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn report_overflowed_chunks() {
+    let server = MockServer::start(GET_CELLS_FIXTURES, ResponseMode::Overflowed);
+    let client = IndexerRpcClient::new(&server.url).expect("client");
+    let err = client.get_list_items(&registry_script()).err();
+    assert_eq!(err, Some(Error::Rpc("invalid chunked body".to_owned())));
+}
+
+#[test]
+fn reject_empty_pages() {
+    let client = IndexerRpcClient::new("http://127.0.0.1:8114").expect("client");
+    let err = client.with_page_size(0).err();
+    assert!(matches!(err, Some(Error::Rpc(_))));
+}
@@ -0,0 +1,437 @@
+use std::ops::Bound::{Excluded, Included};
+
+use ckb_registry_client::{
+    create, Conflict, ContractRef, CreateRequest, Funding, Item, LiveCell, PendingInsert,
+};
+use ckb_registry_indexer::{ChainEvent, Error, Inconsistency, Indexer, Instance, JsonBlockSource};
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{
+        BlockBuilder, BlockView, EpochNumberWithFraction, ScriptHashType, TransactionBuilder,
+        TransactionView,
+    },
+    packed::*,
+    prelude::*,
+};
+use demo_linked_list_lib::types;
+
+const CODE_HASH: [u8; 32] = [1u8; 32];
+
+#[derive(Default)]
+struct Chain {
+    blocks: Vec<BlockView>,
+    fundings: u32,
+}
+
+impl Chain {
+    fn contract() -> ContractRef {
+        ContractRef {
+            code_hash: CODE_HASH.pack(),
+            hash_type: ScriptHashType::Data1.into(),
+            cell_deps: Vec::new(),
+        }
+    }
+
+    fn indexer() -> Indexer {
+        Indexer::new(CODE_HASH.pack(), ScriptHashType::Data1.into())
+    }
+
+    // A unique funding cell for each transaction.
+    fn funding(&mut self) -> Funding {
+        self.fundings += 1;
+        let tx_hash = [&self.fundings.to_le_bytes()[..], &[0xff; 28]].concat();
+        let out_point = OutPoint::new(Byte32::from_slice(&tx_hash).expect("hash"), 0);
+        let output = CellOutput::new_builder()
+            .capacity(100_000_000_000_000u64.pack())
+            .build();
+        Funding {
+            cells: vec![LiveCell {
+                out_point,
+                output,
+                data: Bytes::new(),
+            }],
+            change_lock: Script::default(),
+            fee: 1_000,
+        }
+    }
+
+    fn create(&mut self, keys: &[&[u8]]) -> TransactionView {
+        let request = CreateRequest {
+            contract: Self::contract(),
+            instance_args: types::InstanceArgs::default(),
+            items: keys
+                .iter()
+                .map(|key| (Bytes::copy_from_slice(key), Bytes::new()))
+                .collect(),
+            lock: Script::default(),
+            config: None,
+        };
+        create(request, &self.funding()).expect("create").tx
+    }
+
+    fn block(&mut self, txs: Vec<TransactionView>) -> BlockView {
+        let parent_hash = self
+            .blocks
+            .last()
+            .map(|block| block.hash())
+            .unwrap_or_default();
+        let number = self.blocks.len() as u64;
+        let block = BlockBuilder::default()
+            .number(number.pack())
+            .epoch(EpochNumberWithFraction::new(0, number, 1_800).pack())
+            .parent_hash(parent_hash)
+            .transactions(txs)
+            .build();
+        self.blocks.push(block.clone());
+        block
+    }
+
+    fn rollback(&mut self) -> ChainEvent {
+        let block = self.blocks.pop().expect("tip");
+        ChainEvent::Rollback(block.hash())
+    }
+}
+
+fn args_of(tx: &TransactionView) -> Bytes {
+    let output = tx.output(0).expect("output");
+    output.type_().to_opt().expect("type").args().raw_data()
+}
+
+fn keys_of(instance: &Instance) -> Vec<Bytes> {
+    instance.items().map(|cell| cell.item.key.clone()).collect()
+}
+
+fn key_of(cell: Option<&ckb_registry_client::ItemCell>) -> Bytes {
+    cell.expect("item").item.key.clone()
+}
+
+#[test]
+fn index_multiple_instances() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer();
+    let first = chain.create(&[b"b", b"d", b"f"]);
+    let second = chain.create(&[b"x", b"y"]);
+    let block = chain.block(vec![first.clone(), second.clone()]);
+    indexer.apply_block(&block).expect("apply");
+
+    assert_eq!(indexer.instances().count(), 2);
+    assert_eq!(indexer.tip(), Some((0, block.hash())));
+    let instance = indexer.instance(&args_of(&second)).expect("instance");
+    assert_eq!(keys_of(instance), vec![&b"x"[..], b"y"]);
+
+    let instance = indexer.instance(&args_of(&first)).expect("instance");
+    assert!(instance.is_consistent());
+    assert_eq!(key_of(instance.predecessor(b"c")), &b"b"[..]);
+    assert_eq!(key_of(instance.predecessor(b"d")), &b"b"[..]);
+    assert_eq!(key_of(instance.predecessor(b"a")), &b"f"[..]);
+    assert_eq!(key_of(instance.successor(b"c")), &b"d"[..]);
+    assert_eq!(key_of(instance.successor(b"f")), &b"b"[..]);
+    let range = instance
+        .range(Included(&b"b"[..]), Excluded(&b"f"[..]))
+        .map(|cell| cell.item.key.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(range, vec![&b"b"[..], b"d"]);
+}
+
+#[test]
+fn follow_updates_and_rollbacks() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer();
+    let tx = chain.create(&[b"b", b"d", b"f"]);
+    let args = args_of(&tx);
+    indexer.apply_block(&chain.block(vec![tx])).expect("apply");
+
+    let registry = indexer
+        .instance(&args)
+        .expect("instance")
+        .to_registry(Vec::new())
+        .expect("registry");
+    let insert = registry
+        .insert(b"c", b"", Script::default(), &chain.funding())
+        .expect("insert");
+    indexer
+        .apply_block(&chain.block(vec![insert.tx]))
+        .expect("apply");
+    let registry = indexer
+        .instance(&args)
+        .expect("instance")
+        .to_registry(Vec::new())
+        .expect("registry");
+    let remove = registry.remove(b"d", &chain.funding()).expect("remove");
+    indexer
+        .apply_block(&chain.block(vec![remove.tx]))
+        .expect("apply");
+    let instance = indexer.instance(&args).expect("instance");
+    assert_eq!(keys_of(instance), vec![&b"b"[..], b"c", b"f"]);
+    assert!(instance.is_consistent());
+
+    let err = indexer.rollback(&Byte32::default()).err();
+    assert!(matches!(err, Some(Error::RollbackMismatch { .. })));
+
+    indexer.apply_event(chain.rollback()).expect("rollback");
+    let instance = indexer.instance(&args).expect("instance");
+    assert_eq!(keys_of(instance), vec![&b"b"[..], b"c", b"d", b"f"]);
+    indexer.apply_event(chain.rollback()).expect("rollback");
+    let instance = indexer.instance(&args).expect("instance");
+    assert_eq!(keys_of(instance), vec![&b"b"[..], b"d", b"f"]);
+    assert!(instance.is_consistent());
+    indexer.apply_event(chain.rollback()).expect("rollback");
+    assert!(indexer.instance(&args).is_none());
+    assert_eq!(indexer.tip(), None);
+}
+
+#[test]
+fn prune_undo_data() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer().with_max_reorg_depth(2);
+    let tx = chain.create(&[b"b", b"d"]);
+    let args = args_of(&tx);
+    let first = chain.block(vec![tx]);
+    indexer.apply_block(&first).expect("apply");
+    for _ in 0..2 {
+        indexer
+            .apply_block(&chain.block(Vec::new()))
+            .expect("apply");
+    }
+
+    indexer.apply_event(chain.rollback()).expect("rollback");
+    indexer.apply_event(chain.rollback()).expect("rollback");
+    assert_eq!(indexer.tip(), Some((0, first.hash())));
+    // The undo data of the first block is pruned.
+    let err = indexer.rollback(&first.hash()).err();
+    assert!(matches!(err, Some(Error::RollbackTooDeep { .. })));
+    assert!(indexer.instance(&args).is_some());
+
+    // The chain still grows from the pruned tip.
+    let err = indexer
+        .apply_block(&Chain::default().block(Vec::new()))
+        .err();
+    assert!(matches!(err, Some(Error::UnknownParent { .. })));
+    indexer
+        .apply_block(&chain.block(Vec::new()))
+        .expect("apply");
+    assert_eq!(indexer.tip().map(|(number, _)| number), Some(1));
+}
+
+#[test]
+fn reject_unknown_parent() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer();
+    let first = chain.block(Vec::new());
+    let _ = chain.block(Vec::new());
+    let third = chain.block(Vec::new());
+    indexer.apply_block(&first).expect("apply");
+    let err = indexer.apply_block(&third).err();
+    assert!(matches!(err, Some(Error::UnknownParent { number: 2, .. })));
+}
+
+#[test]
+fn remove_destroyed_instance() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer();
+    let tx = chain.create(&[b"b", b"d"]);
+    let args = args_of(&tx);
+    indexer.apply_block(&chain.block(vec![tx])).expect("apply");
+    let registry = indexer
+        .instance(&args)
+        .expect("instance")
+        .to_registry(Vec::new())
+        .expect("registry");
+    let destroy = registry.destroy(&chain.funding()).expect("destroy");
+    indexer
+        .apply_block(&chain.block(vec![destroy.tx]))
+        .expect("apply");
+    assert!(indexer.instance(&args).is_none());
+}
+
+#[test]
+fn flag_inconsistent_rings() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer();
+    let type_script = Script::new_builder()
+        .code_hash(CODE_HASH.pack())
+        .hash_type(ScriptHashType::Data1.into())
+        .args(Bytes::from(vec![0u8; 32]).pack())
+        .build();
+    let output = CellOutput::new_builder()
+        .type_(Some(type_script.clone()).pack())
+        .build();
+    let items = [
+        Item::new(b"a", b"b", b"").to_cell_data(),
+        Item::new(b"b", b"c", b"").to_cell_data(),
+        Item::new(b"a", b"b", b"duplicated").to_cell_data(),
+        Bytes::from_static(b"invalid"),
+    ];
+    let tx = TransactionBuilder::default()
+        .outputs(vec![output; items.len()])
+        .outputs_data(items.iter().map(|data| data.pack()))
+        .build();
+    indexer
+        .apply_block(&chain.block(vec![tx.clone()]))
+        .expect("apply");
+
+    assert_eq!(indexer.inconsistent_instances().count(), 1);
+    let instance = indexer.instance(&args_of(&tx)).expect("instance");
+    assert_eq!(instance.cells_count(), 4);
+    assert_eq!(keys_of(instance), vec![&b"a"[..], b"b"]);
+    assert_eq!(
+        instance.inconsistencies(),
+        &[
+            Inconsistency::DuplicateKey {
+                key: Bytes::from_static(b"a"),
+                out_point: OutPoint::new(tx.hash(), 2),
+            },
+            Inconsistency::InvalidData {
+                out_point: OutPoint::new(tx.hash(), 3),
+            },
+            Inconsistency::BrokenLink {
+                key: Bytes::from_static(b"b"),
+                next: Bytes::from_static(b"c"),
+                expected: Bytes::from_static(b"a"),
+            },
+        ]
+    );
+
+    // Fix the ring by consuming the broken cells.
+    let fix = TransactionBuilder::default()
+        .inputs((1..4).map(|index| {
+            CellInput::new_builder()
+                .previous_output(OutPoint::new(tx.hash(), index))
+                .build()
+        }))
+        .output(
+            CellOutput::new_builder()
+                .type_(Some(type_script).pack())
+                .build(),
+        )
+        .output_data(Item::new(b"b", b"a", b"").to_cell_data().pack())
+        .build();
+    indexer.apply_block(&chain.block(vec![fix])).expect("apply");
+    assert_eq!(indexer.inconsistent_instances().count(), 0);
+
+    // The inconsistencies come back after the rollback.
+    indexer.apply_event(chain.rollback()).expect("rollback");
+    let instance = indexer.instance(&args_of(&tx)).expect("instance");
+    assert_eq!(instance.cells_count(), 4);
+    assert_eq!(instance.inconsistencies().len(), 3);
+}
+
+#[test]
+fn take_over_duplicated_keys() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer();
+    let type_script = Script::new_builder()
+        .code_hash(CODE_HASH.pack())
+        .hash_type(ScriptHashType::Data1.into())
+        .args(Bytes::from(vec![0u8; 32]).pack())
+        .build();
+    let output = CellOutput::new_builder()
+        .type_(Some(type_script).pack())
+        .build();
+    let items = [
+        Item::new(b"a", b"b", b"").to_cell_data(),
+        Item::new(b"b", b"a", b"").to_cell_data(),
+        Item::new(b"a", b"b", b"second").to_cell_data(),
+        Item::new(b"a", b"b", b"third").to_cell_data(),
+    ];
+    let tx = TransactionBuilder::default()
+        .outputs(vec![output; items.len()])
+        .outputs_data(items.iter().map(|data| data.pack()))
+        .build();
+    indexer
+        .apply_block(&chain.block(vec![tx.clone()]))
+        .expect("apply");
+
+    // The duplicate with the lowest out point takes the key.
+    let remove = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(OutPoint::new(tx.hash(), 0))
+                .build(),
+        )
+        .build();
+    indexer
+        .apply_block(&chain.block(vec![remove]))
+        .expect("apply");
+    let instance = indexer.instance(&args_of(&tx)).expect("instance");
+    let cell = instance.get(b"a").expect("item");
+    assert_eq!(cell.cell.out_point, OutPoint::new(tx.hash(), 2));
+    assert_eq!(
+        instance.inconsistencies(),
+        &[Inconsistency::DuplicateKey {
+            key: Bytes::from_static(b"a"),
+            out_point: OutPoint::new(tx.hash(), 3),
+        }]
+    );
+}
+
+#[test]
+fn sync_from_json_fixtures() {
+    let mut chain = Chain::default();
+    let tx = chain.create(&[b"b", b"d"]);
+    let args = args_of(&tx);
+    let other = chain.create(&[b"x", b"y"]);
+    let mut events = vec![
+        ChainEvent::Block(chain.block(vec![tx])),
+        ChainEvent::Block(chain.block(vec![other])),
+    ];
+    events.push(chain.rollback());
+    let json = JsonBlockSource::to_json(&events);
+
+    let mut source = JsonBlockSource::from_json(&json).expect("parse");
+    let mut indexer = Chain::indexer();
+    assert_eq!(indexer.sync(&mut source).expect("sync"), 3);
+    assert_eq!(indexer.instances().count(), 1);
+    let instance = indexer.instance(&args).expect("instance");
+    assert_eq!(keys_of(instance), vec![&b"b"[..], b"d"]);
+    assert_eq!(indexer.tip(), Some((0, chain.blocks[0].hash())));
+
+    let err = JsonBlockSource::from_json("[{\"type\": \"unknown\"}]").err();
+    assert!(matches!(err, Some(Error::Source(_))));
+}
+
+#[test]
+fn rebase_after_conflicting_block() {
+    let mut chain = Chain::default();
+    let mut indexer = Chain::indexer();
+    let tx = chain.create(&[b"b", b"f"]);
+    let args = args_of(&tx);
+    indexer.apply_block(&chain.block(vec![tx])).expect("apply");
+    let registry = indexer
+        .instance(&args)
+        .expect("instance")
+        .to_registry(Vec::new())
+        .expect("registry");
+    let first = registry
+        .insert(b"d", b"first", Script::default(), &chain.funding())
+        .expect("insert");
+    let second = registry
+        .insert(b"c", b"second", Script::default(), &chain.funding())
+        .expect("insert");
+    indexer
+        .apply_block(&chain.block(vec![first.tx]))
+        .expect("apply");
+
+    // The indexer update shows that the predecessor is spent.
+    let registry = indexer
+        .instance(&args)
+        .expect("instance")
+        .to_registry(Vec::new())
+        .expect("registry");
+    let pending = PendingInsert::from_tx(second).expect("pending");
+    assert!(matches!(
+        pending.conflict(&registry, &chain.funding()),
+        Some(Conflict::DeadInput(_))
+    ));
+    let rebased = pending.rebase(&registry, &chain.funding()).expect("rebase");
+    // Both the predecessor and the funding cell are changed.
+    assert_eq!(rebased.changed_inputs, vec![0, 1]);
+    indexer
+        .apply_block(&chain.block(vec![rebased.tx.tx]))
+        .expect("apply");
+    let instance = indexer.instance(&args).expect("instance");
+    assert_eq!(keys_of(instance), vec![&b"b"[..], b"c", b"d", b"f"]);
+    assert!(instance.is_consistent());
+    let payload = &instance.get(b"c").expect("item").item.payload;
+    assert_eq!(&payload[..], b"second");
+}
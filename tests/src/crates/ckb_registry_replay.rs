@@ -0,0 +1,212 @@
+use std::{env, fs};
+
+use ckb_registry_client::{create, ContractRef, CreateRequest, Funding, Item, LiveCell};
+use ckb_registry_replay::{chain::Break, codes, Outcome, Report};
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionView, packed::*, prelude::*},
+    context::Context,
+};
+use demo_linked_list_lib::types;
+
+use crate::{utilities, Loader};
+
+const CONTRACT_ERRORS: &str = include_str!("../../../contracts/demo-linked-list-type/src/error.rs");
+const TOOL_ERRORS: &str = include_str!("../../../crates/ckb-linked-list-tool/src/error.rs");
+// The tool errors follow the internal errors in the exit codes.
+const TOOL_ERRORS_START: i8 = 0x60;
+
+// Builds a transaction to create an instance, all its inputs and cell deps are
+// in the context.
+fn create_tx(context: &mut Context, keys: &[&[u8]]) -> TransactionView {
+    let registry_bin: Bytes = Loader::default().load_binary("demo-linked-list-type");
+    let registry_out_point = context.deploy_cell(registry_bin);
+    let lock_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let registry = context
+        .build_script(&registry_out_point, Default::default())
+        .expect("script");
+    let lock = context
+        .build_script(&lock_out_point, Default::default())
+        .expect("lock script");
+    let output = CellOutput::new_builder()
+        .capacity(1_000_000_000_000u64.pack())
+        .lock(lock.clone())
+        .build();
+    let out_point = context.create_cell(output.clone(), Bytes::new());
+    let funding = Funding {
+        cells: vec![LiveCell {
+            out_point,
+            output,
+            data: Bytes::new(),
+        }],
+        change_lock: lock.clone(),
+        fee: 1_000,
+    };
+    let request = CreateRequest {
+        contract: ContractRef {
+            code_hash: registry.code_hash(),
+            hash_type: registry.hash_type(),
+            cell_deps: [registry_out_point, lock_out_point]
+                .into_iter()
+                .map(|out_point| CellDep::new_builder().out_point(out_point).build())
+                .collect(),
+        },
+        instance_args: types::InstanceArgs::default(),
+        items: keys
+            .iter()
+            .map(|key| (Bytes::copy_from_slice(key), Bytes::new()))
+            .collect(),
+        lock,
+        config: None,
+    };
+    create(request, &funding).expect("create").tx
+}
+
+// Dumps the transaction as the tests do, then replays the dump.
+fn dump_and_replay(context: &Context, tx: &TransactionView) -> Report {
+    let mock_tx = context.dump_tx(tx).expect("dump");
+    let path = env::temp_dir().join(format!("ckb-registry-replay-{:x}.json", tx.hash()));
+    let json = serde_json::to_string_pretty(&mock_tx).expect("json");
+    fs::write(&path, json).expect("write");
+    let loaded = ckb_registry_replay::load(&path.to_string_lossy());
+    let _ = fs::remove_file(&path);
+    ckb_registry_replay::replay(&loaded.expect("load"), u64::MAX)
+}
+
+#[test]
+fn replay_passed_transaction() {
+    utilities::setup();
+    let mut context = Context::default();
+    let tx = create_tx(&mut context, &[b"a", b"b", b"c"]);
+    let report = dump_and_replay(&context, &tx);
+    assert!(report.is_passed());
+    assert_eq!(report.tx_hash, tx.hash());
+    assert_eq!(report.instances.len(), 1);
+    let instance = &report.instances[0];
+    assert!(instance.inputs.cells.is_empty());
+    assert_eq!(instance.outputs.cells.len(), 3);
+    assert!(instance.outputs.is_complete());
+    assert!(instance.is_kept());
+    assert!(report
+        .to_string()
+        .contains("outputs: a complete ring from a"));
+}
+
+#[test]
+fn diagnose_broken_chain() {
+    utilities::setup();
+    let mut context = Context::default();
+    let tx = create_tx(&mut context, &[b"a", b"b", b"c"]);
+    // Point `b` to `d`, which doesn't exist.
+    let mut outputs_data = tx.outputs_data().into_iter().collect::<Vec<_>>();
+    outputs_data[1] = Item::new(b"b", b"d", b"").to_cell_data().pack();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data)
+        .build();
+    let report = dump_and_replay(&context, &tx);
+
+    let failure = match &report.outcome {
+        Outcome::Failed(failure) => failure,
+        Outcome::Passed { .. } => panic!("should be failed"),
+    };
+    assert_eq!(failure.source.as_deref(), Some("Outputs[0].Type"));
+    assert_eq!(failure.code, Some(0x61));
+    assert_eq!(
+        failure.error_name.as_deref(),
+        Some("ToolError::Discontinuous")
+    );
+
+    let instance = &report.instances[0];
+    assert!(!instance.is_kept());
+    assert_eq!(instance.outputs.breaks, vec![Break::Dangling { index: 1 }]);
+    let report = report.to_string();
+    assert!(report.contains("#1 b -> d  payload: 0x  version: 2  <-- the chain breaks"));
+    assert!(report.contains("the ring is not kept by the transaction"));
+}
+
+#[test]
+fn diagnose_invalid_data() {
+    utilities::setup();
+    let mut context = Context::default();
+    let tx = create_tx(&mut context, &[b"a", b"b", b"c"]);
+    let mut outputs_data = tx.outputs_data().into_iter().collect::<Vec<_>>();
+    outputs_data[0] = Bytes::from_static(b"invalid").pack();
+    outputs_data[2] = Item::new(b"b", b"a", b"").to_cell_data().pack();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data)
+        .build();
+    let report = dump_and_replay(&context, &tx);
+    assert!(!report.is_passed());
+    let outputs = &report.instances[0].outputs;
+    assert_eq!(
+        outputs.breaks,
+        vec![
+            Break::InvalidData { index: 0 },
+            Break::Dangling { index: 1 },
+            Break::DuplicateKey { index: 2 }
+        ]
+    );
+    assert_eq!(
+        outputs.summary,
+        Some(Err("the data of #0 is invalid".to_owned()))
+    );
+}
+
+#[test]
+fn name_all_contract_errors() {
+    let internal_errors = parse_error_codes(CONTRACT_ERRORS, "pub enum InternalError {");
+    assert!(internal_errors.len() > 50);
+    for (code, name) in &internal_errors {
+        assert_eq!(
+            codes::error_name(*code),
+            Some(format!("InternalError::{name}"))
+        );
+    }
+    let tool_errors = parse_error_codes(TOOL_ERRORS, "pub enum Error {");
+    for (code, name) in &tool_errors {
+        assert_eq!(
+            codes::error_name(TOOL_ERRORS_START + code),
+            Some(format!("ToolError::{name}"))
+        );
+    }
+    // No stale names are left for the removed errors.
+    let named = (1..=i8::MAX)
+        .filter(|code| codes::error_name(*code).is_some())
+        .count();
+    assert_eq!(named, internal_errors.len() + tool_errors.len());
+}
+
+// Parse the variants of an error enum, the values are either explicit or the
+// previous value plus 1. `Unreachable` is skipped, it's not an error.
+fn parse_error_codes(source: &str, header: &str) -> Vec<(i8, String)> {
+    let body = source
+        .split(header)
+        .nth(1)
+        .and_then(|rest| rest.split('}').next())
+        .expect("enum");
+    let mut codes = Vec::new();
+    let mut code = 0i8;
+    for line in body.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let line = line.trim_end_matches(',');
+        let name = match line.split_once('=') {
+            Some((name, value)) => {
+                let value = value.trim().trim_start_matches("0x");
+                code = i8::from_str_radix(value, 16).expect("value");
+                name.trim()
+            }
+            None => {
+                code += 1;
+                line
+            }
+        };
+        if name != "Unreachable" {
+            codes.push((code, name.to_owned()));
+        }
+    }
+    codes
+}
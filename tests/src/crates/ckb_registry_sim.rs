@@ -0,0 +1,129 @@
+use ckb_registry_sim::{args::parse_args, simulate, Config, Distribution, Error, Layout};
+
+fn config(distribution: Distribution, shards: usize, bucket_size: usize) -> Config {
+    Config {
+        seed: 7,
+        clients: 50,
+        inserts_per_client: 10,
+        distribution,
+        layout: Layout {
+            shards,
+            bucket_size,
+        },
+        initial_nodes: 200,
+        ..Config::default()
+    }
+}
+
+#[test]
+fn same_seed_same_report() {
+    let config = config(
+        Distribution::Zipf {
+            exponent: 1.1,
+            regions: 100,
+        },
+        2,
+        4,
+    );
+    let report = simulate(&config).expect("simulate");
+    assert_eq!(simulate(&config).expect("simulate"), report);
+    assert_eq!(report.inserted + report.duplicates, 500);
+    assert_eq!(report.unfinished, 0);
+
+    let other = Config { seed: 8, ..config };
+    assert_ne!(simulate(&other).expect("simulate"), report);
+}
+
+#[test]
+fn count_cells_touched() {
+    // An insert into a single ring consumes the predecessor, and creates it
+    // again with the new item.
+    let report = simulate(&config(Distribution::Uniform, 1, 1)).expect("simulate");
+    assert_eq!(report.inserted, 500);
+    assert_eq!(report.inputs, 500);
+    assert_eq!(report.outputs, 1_000);
+    assert_eq!(report.cells_touched_per_insert(), 3.0);
+    assert_eq!(report.nodes, 700);
+
+    // A node is split only when it's full.
+    let report = simulate(&config(Distribution::Uniform, 1, 8)).expect("simulate");
+    assert_eq!(report.inputs, report.inserted);
+    assert!(report.outputs < report.inserted * 2);
+    assert_eq!(report.nodes, 200 + report.outputs - report.inputs);
+}
+
+#[test]
+fn shards_reduce_conflicts() {
+    let single = simulate(&config(Distribution::Sequential, 1, 1)).expect("simulate");
+    let sharded = simulate(&config(Distribution::Sequential, 8, 1)).expect("simulate");
+    let uniform = simulate(&config(Distribution::Uniform, 1, 1)).expect("simulate");
+    assert!(single.conflict_rate() > 0.5);
+    assert!(sharded.conflict_rate() < single.conflict_rate());
+    assert!(uniform.conflict_rate() < sharded.conflict_rate());
+    assert!(single.latency.mean > uniform.latency.mean);
+    assert!(single.blocks > sharded.blocks);
+    // Every rejected submission is submitted again.
+    assert_eq!(single.submissions, single.inserted + single.conflicts);
+}
+
+#[test]
+fn limit_blocks() {
+    let mut config = config(Distribution::Uniform, 1, 1);
+    config.block_size = 10;
+    let report = simulate(&config).expect("simulate");
+    assert!(report.blocks >= 50);
+    assert!(report.latency.max > 1);
+
+    config.max_blocks = 20;
+    let report = simulate(&config).expect("simulate");
+    assert_eq!(report.blocks, 20);
+    assert_eq!(report.inserted, 200);
+    assert_eq!(report.unfinished, 300);
+}
+
+#[test]
+fn parse_sweeps() {
+    let args = [
+        "--distribution",
+        "uniform,zipf:1.5",
+        "--shards",
+        "1,4",
+        "--bucket-size",
+        "1,8,16",
+        "--seed",
+        "3",
+    ]
+    .map(str::to_owned);
+    let configs = parse_args(&args).expect("parse");
+    assert_eq!(configs.len(), 12);
+    assert!(configs.iter().all(|config| config.seed == 3));
+    assert_eq!(
+        configs[6].distribution,
+        Distribution::Zipf {
+            exponent: 1.5,
+            regions: 1_000
+        }
+    );
+    assert_eq!(
+        configs[11].layout,
+        Layout {
+            shards: 4,
+            bucket_size: 16
+        }
+    );
+
+    let parse = |args: &[&str]| {
+        let args = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        parse_args(&args).err()
+    };
+    assert!(matches!(
+        parse(&["--distribution", "normal"]),
+        Some(Error::Usage(msg)) if msg.contains("normal")
+    ));
+    assert!(matches!(parse(&["--clients"]), Some(Error::Usage(_))));
+    assert!(matches!(
+        parse(&["--shards", "0"]),
+        Some(Error::InvalidConfig(_))
+    ));
+    assert!(matches!(parse(&["--ring", "1"]), Some(Error::Usage(_))));
+}
@@ -0,0 +1,91 @@
+use ckb_testtool::ckb_types::prelude::*;
+use demo_linked_list_lib::{
+    merkle::{Membership, MerkleError, MerkleTree},
+    types,
+};
+
+const KEYS: &[&[u8]] = &[b"b", b"d", b"f", b"h", b"j", b"l", b"n"];
+
+fn verify(tree: &MerkleTree, proof: &types::MerkleKeyProof) -> Result<Membership, MerkleError> {
+    tree.to_set().as_reader().verify_key(&proof.as_reader())
+}
+
+#[test]
+fn prove_all_keys() {
+    for count in 0..=KEYS.len() {
+        let tree = MerkleTree::new(&KEYS[..count]);
+        for key in &KEYS[..count] {
+            let proof = tree.prove(key);
+            assert_eq!(verify(&tree, &proof), Ok(Membership::Member));
+        }
+    }
+}
+
+#[test]
+fn prove_absent_keys() {
+    let absent: &[&[u8]] = &[b"a", b"c", b"e", b"g", b"i", b"k", b"m", b"o", b""];
+    for count in 0..=KEYS.len() {
+        let tree = MerkleTree::new(&KEYS[..count]);
+        for key in absent {
+            let proof = tree.prove(key);
+            assert_eq!(verify(&tree, &proof), Ok(Membership::NonMember));
+        }
+    }
+}
+
+#[test]
+fn build_with_unsorted_keys() {
+    let tree = MerkleTree::new([b"n", b"b", b"h", b"b"]);
+    assert_eq!(tree.to_set().as_reader().count().to_u32(), 3);
+    assert_eq!(tree.root(), MerkleTree::new([b"b", b"h", b"n"]).root());
+}
+
+#[test]
+fn reject_proof_of_another_tree() {
+    let tree = MerkleTree::new(KEYS);
+    let another = MerkleTree::new(&KEYS[1..]);
+    let proof = another.prove(b"d");
+    assert_eq!(verify(&tree, &proof), Err(MerkleError::InvalidPath));
+}
+
+#[test]
+fn reject_mismatched_key() {
+    let tree = MerkleTree::new(KEYS);
+    // A membership proof of "d" could not prove "c" is in the set.
+    let proof = tree
+        .prove(b"d")
+        .as_builder()
+        .key(types::Bytes::new_from_raw_slice(b"c"))
+        .build();
+    assert_eq!(verify(&tree, &proof), Err(MerkleError::InvalidLeaves));
+}
+
+#[test]
+fn reject_non_adjacent_leaves() {
+    let tree = MerkleTree::new(KEYS);
+    // Skip the leaf "f" between "d" and "h".
+    let left = tree.prove(b"d").leaves().get(0).unwrap();
+    let right = tree.prove(b"h").leaves().get(0).unwrap();
+    let leaves = types::MerkleLeafProofVec::new_builder()
+        .push(left)
+        .push(right)
+        .build();
+    let proof = types::MerkleKeyProof::new_builder()
+        .key(types::Bytes::new_from_raw_slice(b"f"))
+        .leaves(leaves)
+        .build();
+    assert_eq!(verify(&tree, &proof), Err(MerkleError::InvalidLeaves));
+}
+
+#[test]
+fn reject_tampered_index() {
+    let tree = MerkleTree::new(KEYS);
+    // Claim the first leaf is the last one, to prove a larger key is absent.
+    let leaf = tree.prove(b"b").leaves().get(0).unwrap();
+    let leaf = leaf.as_builder().index(6u32.into()).build();
+    let proof = types::MerkleKeyProof::new_builder()
+        .key(types::Bytes::new_from_raw_slice(b"c"))
+        .leaves(types::MerkleLeafProofVec::new_builder().push(leaf).build())
+        .build();
+    assert_eq!(verify(&tree, &proof), Err(MerkleError::InvalidPath));
+}
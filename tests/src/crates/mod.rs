@@ -0,0 +1,8 @@
+mod ckb_linked_list_derive;
+mod ckb_registry_cli;
+mod ckb_registry_client;
+mod ckb_registry_client_rpc;
+mod ckb_registry_indexer;
+mod ckb_registry_replay;
+mod ckb_registry_sim;
+mod demo_linked_list_lib_merkle;
@@ -7,6 +7,8 @@ use std::str::FromStr;
 #[cfg(test)]
 mod contracts;
 #[cfg(test)]
+mod crates;
+#[cfg(test)]
 pub(crate) mod prelude;
 #[cfg(test)]
 pub(crate) mod utilities;
@@ -7,7 +7,8 @@ use ckb_testtool::{
 };
 
 // This helper method runs Context::verify_tx, but in case error happens,
-// it also dumps current transaction to failed_txs folder.
+// it also dumps current transaction to failed_txs folder, which could be
+// replayed by `ckb-registry-replay`.
 pub trait ContextExt {
     fn should_be_passed(&self, tx: &TransactionView, max_cycles: Cycle) -> Result<Cycle, Error>;
     fn should_be_failed(&self, tx: &TransactionView, max_cycles: Cycle) -> Result<Cycle, Error>;
@@ -1,8 +1,9 @@
 //! Utilities for tests only.
 
 use ckb_testtool::{
+    ckb_error::Error,
     ckb_hash::{new_blake2b, BLAKE2B_LEN},
-    ckb_types::{packed, prelude::*},
+    ckb_types::{core::Cycle, packed, prelude::*},
 };
 use env_logger::{Builder, Target};
 use log::LevelFilter;
@@ -17,6 +18,15 @@ pub(crate) fn setup() {
     println!();
 }
 
+// Check the exit code of a failed transaction.
+pub(crate) fn assert_error_code(result: Result<Cycle, Error>, code: i8) {
+    let err = result.expect_err("should be failed").to_string();
+    assert!(
+        err.contains(&format!("see the error code {code} ")),
+        "{err}"
+    );
+}
+
 pub(crate) fn calculate_unique_id(
     input: packed::CellInput,
     output_index: usize,